@@ -44,6 +44,10 @@ pub struct IanaIdentifierNormalizer<'data> {
     /// A "links" table mapping non-canonical IDs to their canonical IDs
     #[cfg_attr(feature = "datagen", serde(borrow))]
     pub non_canonical_identifiers: ZeroAsciiIgnoreCaseTrie<ZeroVec<'data, u8>>,
+    /// A table of deprecated link IDs (links from the `backward` file) mapping
+    /// to their canonical IDs
+    #[cfg_attr(feature = "datagen", serde(borrow))]
+    pub deprecated_identifiers: ZeroAsciiIgnoreCaseTrie<ZeroVec<'data, u8>>,
 
     /// The normalized IANA identifier
     #[cfg_attr(feature = "datagen", serde(borrow))]
@@ -143,6 +147,7 @@ impl IanaIdentifierNormalizer<'_> {
             .collect();
 
         let mut primary_id_map: BTreeMap<Vec<u8>, usize> = BTreeMap::new();
+        let mut deprecated_id_map: BTreeMap<Vec<u8>, usize> = BTreeMap::new();
         // ECMAScript implementations must support an available named time zone with the identifier "UTC", which must be
         // the primary time zone identifier for the UTC time zone. In addition, implementations may support any number of other available named time zones.
         let utc_index = norm_vec.binary_search(&"UTC").unwrap();
@@ -159,6 +164,9 @@ impl IanaIdentifierNormalizer<'_> {
                 norm_vec.binary_search(&&**link_to).unwrap()
             };
             primary_id_map.insert(link_from.to_ascii_lowercase().as_bytes().to_vec(), index);
+            if provider.data.backward_links().contains(link_from) {
+                deprecated_id_map.insert(link_from.to_ascii_lowercase().as_bytes().to_vec(), index);
+            }
         }
 
         Ok(IanaIdentifierNormalizer {
@@ -169,6 +177,9 @@ impl IanaIdentifierNormalizer<'_> {
             non_canonical_identifiers: ZeroAsciiIgnoreCaseTrie::try_from(&primary_id_map)
                 .map_err(IanaDataError::Build)?
                 .convert_store(),
+            deprecated_identifiers: ZeroAsciiIgnoreCaseTrie::try_from(&deprecated_id_map)
+                .map_err(IanaDataError::Build)?
+                .convert_store(),
             normalized_identifiers: norm_zerovec,
         })
     }
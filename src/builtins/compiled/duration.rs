@@ -2,6 +2,7 @@ use crate::{
     builtins::TZ_PROVIDER,
     options::{RelativeTo, RoundingOptions, Unit},
     primitive::FiniteF64,
+    relative_time::{RelativeTime, RelativeTimeOptions},
     Duration, TemporalResult,
 };
 
@@ -38,4 +39,15 @@ impl Duration {
     pub fn total(&self, unit: Unit, relative_to: Option<RelativeTo>) -> TemporalResult<FiniteF64> {
         self.total_with_provider(unit, relative_to, &*TZ_PROVIDER)
     }
+
+    /// Returns this duration as a [`RelativeTime`] of a single unit.
+    ///
+    /// Enable with the `compiled_data` feature flag.
+    pub fn to_relative_time(
+        &self,
+        relative_to: Option<RelativeTo>,
+        options: RelativeTimeOptions,
+    ) -> TemporalResult<RelativeTime> {
+        self.to_relative_time_with_provider(relative_to, options, &*TZ_PROVIDER)
+    }
 }
@@ -0,0 +1,30 @@
+use crate::{
+    builtins::TZ_PROVIDER, icalendar::Period, Instant, TemporalResult, TimeZone, ZonedDateTime,
+};
+use alloc::string::String;
+
+impl Period {
+    /// Parses an iCalendar `PERIOD` value.
+    ///
+    /// Enable with the `compiled_data` feature flag.
+    pub fn from_icalendar(value: &[u8], tzid: Option<&str>) -> TemporalResult<Self> {
+        Self::from_icalendar_with_provider(value, tzid, &*TZ_PROVIDER)
+    }
+
+    /// Returns the end of the period.
+    ///
+    /// Enable with the `compiled_data` feature flag.
+    pub fn end(&self) -> TemporalResult<ZonedDateTime> {
+        self.end_with_provider(&*TZ_PROVIDER)
+    }
+}
+
+impl TimeZone {
+    /// Generates an iCalendar `VTIMEZONE` component describing this time zone
+    /// between `start` and `end`.
+    ///
+    /// Enable with the `compiled_data` feature flag.
+    pub fn to_vtimezone(&self, start: &Instant, end: &Instant) -> TemporalResult<String> {
+        self.to_vtimezone_with_provider(start, end, &*TZ_PROVIDER)
+    }
+}
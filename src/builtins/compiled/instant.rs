@@ -29,6 +29,32 @@ impl Instant {
         self.to_ixdtf_writeable_with_provider(timezone, options, &*TZ_PROVIDER)
     }
 
+    /// Returns this instant as an RFC 3339 date-time, in UTC with a `Z` when
+    /// `timezone` is `None`.
+    ///
+    /// Enable with the `compiled_data` feature flag.
+    pub fn to_rfc3339_string(
+        &self,
+        timezone: Option<&TimeZone>,
+        options: ToStringRoundingOptions,
+    ) -> TemporalResult<String> {
+        self.to_rfc3339_string_with_provider(timezone, options, &*TZ_PROVIDER)
+    }
+
+    /// Formats this instant for `locale` in `time_zone`, or in UTC when
+    /// `time_zone` is `None`.
+    ///
+    /// Enable with the `compiled_data` and `locale` feature flags.
+    #[cfg(feature = "locale")]
+    pub fn to_locale_string(
+        &self,
+        locale: &icu_locale::Locale,
+        time_zone: Option<&TimeZone>,
+        options: crate::locale::LocaleFormatOptions,
+    ) -> TemporalResult<String> {
+        self.to_locale_string_with_provider(locale, time_zone, options, &*TZ_PROVIDER)
+    }
+
     pub fn to_zoned_date_time_iso(&self, time_zone: TimeZone) -> TemporalResult<ZonedDateTime> {
         self.to_zoned_date_time_iso_with_provider(time_zone, &*TZ_PROVIDER)
     }
@@ -2,6 +2,7 @@
 
 mod date;
 mod duration;
+mod icalendar;
 mod instant;
 mod now;
 mod plain_date_time;
@@ -16,5 +17,9 @@ mod options {
         pub fn try_from_str(source: &str) -> TemporalResult<Self> {
             Self::try_from_str_with_provider(source, &*TZ_PROVIDER)
         }
+
+        pub fn try_from_utf16(source: &[u16]) -> TemporalResult<Self> {
+            Self::try_from_utf16_with_provider(source, &*TZ_PROVIDER)
+        }
     }
 }
@@ -2,35 +2,50 @@ use crate::builtins::zoneddatetime::ZonedDateTimeFields;
 use crate::builtins::TZ_PROVIDER;
 use crate::partial::PartialZonedDateTime;
 use crate::provider::TransitionDirection;
+use crate::relative_time::{RelativeTime, RelativeTimeOptions};
 use crate::ZonedDateTime;
 use crate::{
     options::{
         ArithmeticOverflow, DifferenceSettings, Disambiguation, DisplayCalendar, DisplayOffset,
         DisplayTimeZone, OffsetDisambiguation, RoundingOptions, ToStringRoundingOptions,
     },
-    Calendar, Duration, PlainTime, TemporalResult, TimeZone,
+    Calendar, Duration, PlainTime, TemporalResult, TimeZone, TimeZoneTransition,
 };
 use alloc::string::String;
+use writeable::{impl_display_with_writeable, LengthHint, Writeable};
 
-impl core::fmt::Display for ZonedDateTime {
-    /// The [`core::fmt::Display`] implementation for `ZonedDateTime`.
+impl Writeable for ZonedDateTime {
+    /// The [`Writeable`] implementation for `ZonedDateTime`.
     ///
     /// Enable with the `compiled_data` feature flag.
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let string = self.to_ixdtf_string(
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        let writeable = self.to_ixdtf_writeable(
             DisplayOffset::Auto,
             DisplayTimeZone::Auto,
             DisplayCalendar::Auto,
             ToStringRoundingOptions::default(),
         );
         debug_assert!(
-            string.is_ok(),
+            writeable.is_ok(),
             "A valid ZonedDateTime string with default options."
         );
-        f.write_str(&string.map_err(|_| Default::default())?)
+        writeable.map_err(|_| core::fmt::Error)?.write_to(sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        self.to_ixdtf_writeable(
+            DisplayOffset::Auto,
+            DisplayTimeZone::Auto,
+            DisplayCalendar::Auto,
+            ToStringRoundingOptions::default(),
+        )
+        .map(|x| x.writeable_length_hint())
+        .unwrap_or(LengthHint::undefined())
     }
 }
 
+impl_display_with_writeable!(ZonedDateTime);
+
 // ==== Experimental TZ_PROVIDER calendar method implementations ====
 
 /// Calendar method implementations for `ZonedDateTime`.
@@ -46,6 +61,16 @@ impl ZonedDateTime {
         self.get_time_zone_transition_with_provider(direction, &*TZ_PROVIDER)
     }
 
+    /// Returns the next or previous time zone transition as a [`TimeZoneTransition`] record.
+    ///
+    /// Enable with the `compiled_data` feature flag.
+    pub fn get_time_zone_transition_info(
+        &self,
+        direction: TransitionDirection,
+    ) -> TemporalResult<Option<TimeZoneTransition>> {
+        self.get_time_zone_transition_info_with_provider(direction, &*TZ_PROVIDER)
+    }
+
     /// Returns the hours in the day.
     ///
     /// Enable with the `compiled_data` feature flag.
@@ -166,6 +191,14 @@ impl ZonedDateTime {
         self.round_with_provider(options, &*TZ_PROVIDER)
     }
 
+    /// Returns this date-time as an RFC 3339 date-time with its local time
+    /// and offset.
+    ///
+    /// Enable with the `compiled_data` feature flag.
+    pub fn to_rfc3339_string(&self, options: ToStringRoundingOptions) -> TemporalResult<String> {
+        self.to_rfc3339_string_with_provider(options, &*TZ_PROVIDER)
+    }
+
     /// Returns a RFC9557 (IXDTF) string with the provided options.
     ///
     /// Enable with the `compiled_data` feature flag.
@@ -185,6 +218,25 @@ impl ZonedDateTime {
         )
     }
 
+    /// Returns a RFC9557 (IXDTF) [`Writeable`] with the provided options.
+    ///
+    /// Enable with the `compiled_data` feature flag.
+    pub fn to_ixdtf_writeable(
+        &self,
+        display_offset: DisplayOffset,
+        display_timezone: DisplayTimeZone,
+        display_calendar: DisplayCalendar,
+        options: ToStringRoundingOptions,
+    ) -> TemporalResult<impl Writeable + '_> {
+        self.to_ixdtf_writeable_with_provider(
+            display_offset,
+            display_timezone,
+            display_calendar,
+            options,
+            &*TZ_PROVIDER,
+        )
+    }
+
     /// Attempts to parse and create a `ZonedDateTime` from an IXDTF formatted [`&str`].
     ///
     /// Enable with the `compiled_data` feature flag.
@@ -195,6 +247,22 @@ impl ZonedDateTime {
     ) -> TemporalResult<Self> {
         ZonedDateTime::from_utf8_with_provider(source, disambiguation, offset_option, &*TZ_PROVIDER)
     }
+
+    /// Attempts to parse and create a `ZonedDateTime` from a UTF-16 encoded IXDTF string.
+    ///
+    /// Enable with the `compiled_data` feature flag.
+    pub fn from_utf16(
+        source: &[u16],
+        disambiguation: Disambiguation,
+        offset_option: OffsetDisambiguation,
+    ) -> TemporalResult<Self> {
+        ZonedDateTime::from_utf16_with_provider(
+            source,
+            disambiguation,
+            offset_option,
+            &*TZ_PROVIDER,
+        )
+    }
     /// Attempts to parse and create a `ZonedDateTime` from an IXDTF formatted [`&str`].
     ///
     /// Enable with the `compiled_data` feature flag.
@@ -210,6 +278,34 @@ impl ZonedDateTime {
             &*TZ_PROVIDER,
         )
     }
+
+    /// Returns the time from this date-time until `other` as a [`RelativeTime`].
+    ///
+    /// Enable with the `compiled_data` feature flag.
+    pub fn relative_time_until(
+        &self,
+        other: &Self,
+        options: RelativeTimeOptions,
+    ) -> TemporalResult<RelativeTime> {
+        self.relative_time_until_with_provider(other, options, &*TZ_PROVIDER)
+    }
+
+    /// Parses an iCalendar `DATE-TIME` value in UTC, or a local `DATE-TIME`
+    /// value with the `TZID` parameter `tzid`.
+    ///
+    /// Enable with the `compiled_data` feature flag.
+    pub fn from_icalendar(value: &[u8], tzid: Option<&str>) -> TemporalResult<Self> {
+        Self::from_icalendar_with_provider(value, tzid, &*TZ_PROVIDER)
+    }
+}
+
+impl PartialZonedDateTime {
+    /// Parses `source` with a strftime-style pattern into ISO 8601 fields.
+    ///
+    /// Enable with the `compiled_data` feature flag.
+    pub fn from_strptime(source: &str, pattern: &str) -> TemporalResult<Self> {
+        Self::from_strptime_with_provider(source, pattern, &*TZ_PROVIDER)
+    }
 }
 
 #[cfg(test)]
@@ -224,7 +320,7 @@ mod tests {
     use crate::Duration;
     use crate::TemporalResult;
     use crate::TimeZone;
-    use alloc::string::ToString;
+    use crate::{UtcOffset, WallClockShift};
 
     #[cfg(not(target_os = "windows"))]
     #[test]
@@ -439,6 +535,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn time_zone_transition_info() {
+        let zdt = parse_zdt_with_reject(BEFORE_DST_2025_01_31).unwrap();
+        let transition = zdt
+            .get_time_zone_transition_info(TransitionDirection::Next)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            transition.instant,
+            parse_zdt_with_reject(DST_2025_03_09).unwrap().to_instant()
+        );
+        assert_eq!(transition.offset_before.to_string(), "-08:00");
+        assert_eq!(transition.offset_after.to_string(), "-07:00");
+        assert!(!transition.is_dst_before);
+        assert!(transition.is_dst_after);
+        assert_eq!(transition.abbreviation_before.unwrap(), "PST");
+        assert_eq!(transition.abbreviation_after.unwrap(), "PDT");
+        assert_eq!(
+            transition.shift,
+            WallClockShift::Gap(Duration::new(0, 0, 0, 0, 1, 0, 0, 0, 0, 0).unwrap())
+        );
+
+        // A POSIX tz string transition
+        let zdt = parse_zdt_with_reject(IN_DST_2025_07_31).unwrap();
+        let transition = zdt
+            .get_time_zone_transition_info(TransitionDirection::Next)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            transition.instant,
+            parse_zdt_with_reject(STD_2025_11_02).unwrap().to_instant()
+        );
+        assert!(transition.is_dst_before);
+        assert!(!transition.is_dst_after);
+        assert_eq!(transition.abbreviation_before.unwrap(), "PDT");
+        assert_eq!(transition.abbreviation_after.unwrap(), "PST");
+        assert_eq!(
+            transition.shift,
+            WallClockShift::Overlap(Duration::new(0, 0, 0, 0, 1, 0, 0, 0, 0, 0).unwrap())
+        );
+
+        // Samoa skipped an entire day
+        let zdt = parse_zdt_with_reject(SAMOA_IDL_CHANGE_MINUS_ONE).unwrap();
+        let transition = zdt
+            .get_time_zone_transition_info(TransitionDirection::Next)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            transition.shift,
+            WallClockShift::Gap(Duration::new(0, 0, 0, 0, 24, 0, 0, 0, 0, 0).unwrap())
+        );
+
+        let zdt = ZonedDateTime::try_new(
+            0,
+            Calendar::default(),
+            TimeZone::UtcOffset(UtcOffset::from_minutes(60)),
+        )
+        .unwrap();
+        assert!(zdt
+            .get_time_zone_transition_info(TransitionDirection::Next)
+            .unwrap()
+            .is_none());
+    }
+
     // Modern dates
 
     // Transitions
@@ -681,6 +841,9 @@ mod tests {
 
     #[test]
     fn test_to_string_roundtrip() {
+        use alloc::format;
+        use writeable::assert_writeable_eq;
+
         for (test, is_unambiguous) in TO_STRING_TESTCASES {
             let zdt = parse_zdt_with_reject(test).expect(test);
             let string = zdt.to_string();
@@ -689,6 +852,7 @@ mod tests {
                 *test, &*string,
                 "ZonedDateTime {test} round trips on ToString"
             );
+            assert_writeable_eq!(zdt, *test);
             let without_offset = zdt
                 .to_ixdtf_string(
                     DisplayOffset::Never,
@@ -0,0 +1,620 @@
+//! Business-day arithmetic on `PlainDate`.
+//!
+//! A [`BusinessCalendar`] combines a [`Weekend`] with a [`HolidayCalendar`]
+//! to decide which days are business days.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use icu_calendar::types::Weekday;
+
+use crate::{
+    builtins::core::PlainDate, iso::IsoDate, options::ArithmeticOverflow, TemporalError,
+    TemporalResult,
+};
+
+/// The days of the week that are not business days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Weekend(u8);
+
+impl Default for Weekend {
+    fn default() -> Self {
+        Self::SATURDAY_SUNDAY
+    }
+}
+
+impl Weekend {
+    /// A weekend of Saturday and Sunday.
+    pub const SATURDAY_SUNDAY: Self = Self::from_bits(0b110_0000);
+    /// A weekend of Friday and Saturday.
+    pub const FRIDAY_SATURDAY: Self = Self::from_bits(0b011_0000);
+    /// No weekend days.
+    pub const NONE: Self = Self::from_bits(0);
+
+    const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    /// Creates a weekend from a list of days.
+    pub fn new(days: &[Weekday]) -> Self {
+        days.iter()
+            .fold(Self::NONE, |weekend, day| weekend.with(*day))
+    }
+
+    /// Returns this weekend with `day` added.
+    pub const fn with(self, day: Weekday) -> Self {
+        Self(self.0 | 1 << (day as u8 - 1))
+    }
+
+    /// Returns whether `day` is a weekend day.
+    pub const fn contains(&self, day: Weekday) -> bool {
+        self.0 & 1 << (day as u8 - 1) != 0
+    }
+
+    /// Returns whether the day `epoch_days` days after 1970-01-01 is a weekend day.
+    const fn contains_epoch_day(&self, epoch_days: i64) -> bool {
+        // 1970-01-01 was a Thursday.
+        self.0 & 1 << (epoch_days + 3).rem_euclid(7) != 0
+    }
+
+    /// Returns whether every day of the week is a weekend day.
+    fn is_full(&self) -> bool {
+        self.0 == 0b111_1111
+    }
+}
+
+/// A source of holidays for a [`BusinessCalendar`].
+pub trait HolidayCalendar {
+    /// Returns whether `date` is a holiday.
+    fn is_holiday(&self, date: &PlainDate) -> bool;
+
+    /// Returns the holidays from `start` to `end` inclusive, in any order and
+    /// possibly with duplicates, or `None` if they cannot be listed.
+    ///
+    /// [`BusinessCalendar::business_days_between`] checks every day of the
+    /// range with [`HolidayCalendar::is_holiday`] if this returns `None`.
+    fn holidays_between(&self, _start: &PlainDate, _end: &PlainDate) -> Option<Vec<PlainDate>> {
+        None
+    }
+}
+
+/// A rule that defines a holiday in terms of ISO 8601 dates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HolidayRule {
+    /// A single date, matched by its ISO date in any calendar.
+    Date(PlainDate),
+    /// The same month and day every year, e.g. December 25.
+    Annual { month: u8, day: u8 },
+    /// The `n`th weekday of a month every year, e.g. the first Monday of May.
+    ///
+    /// A negative `n` counts from the end of the month, so `-1` is the last
+    /// weekday of the month.
+    NthWeekday { month: u8, weekday: Weekday, n: i8 },
+    /// A number of days relative to Western (Gregorian) Easter Sunday, e.g.
+    /// `1` for Easter Monday or `-2` for Good Friday.
+    Easter { offset: i16 },
+}
+
+impl HolidayRule {
+    /// Returns whether this rule falls on `iso`.
+    fn matches(&self, iso: &IsoDate) -> bool {
+        match *self {
+            Self::Date(ref date) => date.iso == *iso,
+            Self::Annual { month, day } => iso.month == month && iso.day == day,
+            Self::NthWeekday { month, weekday, n } => {
+                if iso.month != month || iso_weekday(iso) != weekday {
+                    return false;
+                }
+                let week = if n >= 0 {
+                    (i16::from(iso.day) - 1) / 7 + 1
+                } else {
+                    let days_in_month = i16::from(iso.to_icu4x().days_in_month());
+                    -((days_in_month - i16::from(iso.day)) / 7 + 1)
+                };
+                week == i16::from(n)
+            }
+            Self::Easter { offset } => {
+                let easter = add_days(iso, -i32::from(offset));
+                easter == easter_sunday(easter.year)
+            }
+        }
+    }
+
+    /// Returns the dates from `start` to `end` inclusive that this rule falls on.
+    fn dates_between(&self, start: &IsoDate, end: &IsoDate) -> Vec<IsoDate> {
+        let dates = match *self {
+            Self::Date(ref date) => Vec::from([date.iso]),
+            Self::Annual { month, day } => (start.year..=end.year)
+                .filter_map(|year| {
+                    IsoDate::regulate(year, month, day, ArithmeticOverflow::Reject).ok()
+                })
+                .collect(),
+            Self::NthWeekday { month, .. } => (start.year..=end.year)
+                .filter_map(|year| {
+                    IsoDate::regulate(year, month, 1, ArithmeticOverflow::Reject).ok()
+                })
+                .flat_map(|first| (0..31).map(move |i| add_days(&first, i)))
+                .filter(|iso| self.matches(iso))
+                .collect(),
+            Self::Easter { offset } => {
+                let offset = i32::from(offset);
+                let first = add_days(start, -offset).year;
+                let last = add_days(end, -offset).year;
+                (first..=last)
+                    .map(|year| add_days(&easter_sunday(year), offset))
+                    .collect()
+            }
+        };
+        dates
+            .into_iter()
+            .filter(|iso| start <= iso && iso <= end)
+            .collect()
+    }
+}
+
+impl HolidayCalendar for HolidayRule {
+    fn is_holiday(&self, date: &PlainDate) -> bool {
+        self.matches(&date.iso)
+    }
+
+    fn holidays_between(&self, start: &PlainDate, end: &PlainDate) -> Option<Vec<PlainDate>> {
+        let dates = self.dates_between(&start.iso, &end.iso);
+        Some(
+            dates
+                .into_iter()
+                .map(|iso| PlainDate::new_unchecked(iso, start.calendar().clone()))
+                .collect(),
+        )
+    }
+}
+
+/// A set of [`HolidayRule`]s.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HolidaySet {
+    rules: Vec<HolidayRule>,
+}
+
+impl HolidaySet {
+    /// Creates an empty holiday set.
+    pub const fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Returns this set with `rule` added.
+    pub fn with_rule(mut self, rule: HolidayRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Adds `rule` to this set.
+    pub fn push(&mut self, rule: HolidayRule) {
+        self.rules.push(rule);
+    }
+
+    /// Returns the rules of this set.
+    pub fn rules(&self) -> &[HolidayRule] {
+        &self.rules
+    }
+}
+
+impl FromIterator<HolidayRule> for HolidaySet {
+    fn from_iter<T: IntoIterator<Item = HolidayRule>>(iter: T) -> Self {
+        Self {
+            rules: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl HolidayCalendar for HolidaySet {
+    fn is_holiday(&self, date: &PlainDate) -> bool {
+        self.rules.iter().any(|rule| rule.matches(&date.iso))
+    }
+
+    fn holidays_between(&self, start: &PlainDate, end: &PlainDate) -> Option<Vec<PlainDate>> {
+        let mut holidays = Vec::new();
+        for rule in &self.rules {
+            holidays.extend(rule.holidays_between(start, end)?);
+        }
+        Some(holidays)
+    }
+}
+
+impl<H: HolidayCalendar + ?Sized> HolidayCalendar for &H {
+    fn is_holiday(&self, date: &PlainDate) -> bool {
+        (**self).is_holiday(date)
+    }
+
+    fn holidays_between(&self, start: &PlainDate, end: &PlainDate) -> Option<Vec<PlainDate>> {
+        (**self).holidays_between(start, end)
+    }
+}
+
+/// The convention used to move a date that is not a business day onto one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RollConvention {
+    /// The date is not adjusted.
+    Unadjusted,
+    /// The next business day.
+    #[default]
+    Following,
+    /// The next business day, unless it is in the next calendar month, in
+    /// which case the previous business day.
+    ModifiedFollowing,
+    /// The previous business day.
+    Preceding,
+    /// The previous business day, unless it is in the previous calendar month,
+    /// in which case the next business day.
+    ModifiedPreceding,
+}
+
+/// A calendar of business days made of a [`Weekend`] and holidays.
+///
+/// ```rust
+/// use temporal_rs::business::{BusinessCalendar, HolidayRule, HolidaySet, Weekend};
+/// use temporal_rs::PlainDate;
+///
+/// let holidays = HolidaySet::new().with_rule(HolidayRule::Easter { offset: 1 });
+/// let calendar = BusinessCalendar::new(Weekend::SATURDAY_SUNDAY, holidays);
+///
+/// // Thursday before Easter 2025, T+2 skips the weekend and Easter Monday.
+/// let trade = PlainDate::try_new_iso(2025, 4, 17).unwrap();
+/// let settlement = calendar.add_business_days(&trade, 2).unwrap();
+/// assert_eq!(settlement, PlainDate::try_new_iso(2025, 4, 22).unwrap());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BusinessCalendar<H = HolidaySet> {
+    weekend: Weekend,
+    holidays: H,
+}
+
+impl<H: HolidayCalendar> BusinessCalendar<H> {
+    /// Creates a new business calendar.
+    pub const fn new(weekend: Weekend, holidays: H) -> Self {
+        Self { weekend, holidays }
+    }
+
+    /// Returns the weekend of this calendar.
+    pub fn weekend(&self) -> Weekend {
+        self.weekend
+    }
+
+    /// Returns the holidays of this calendar.
+    pub fn holidays(&self) -> &H {
+        &self.holidays
+    }
+
+    /// Returns whether `date` is a business day.
+    pub fn is_business_day(&self, date: &PlainDate) -> bool {
+        !self.weekend.contains(iso_weekday(&date.iso)) && !self.holidays.is_holiday(date)
+    }
+
+    /// Adds `days` business days to `date`.
+    ///
+    /// Each business day moves the date to the next (or, for negative `days`,
+    /// previous) business day, so the result is always a business day unless
+    /// `days` is zero, in which case `date` is returned unchanged.
+    pub fn add_business_days(&self, date: &PlainDate, days: i64) -> TemporalResult<PlainDate> {
+        let step = if days < 0 { -1 } else { 1 };
+        let mut result = date.clone();
+        for _ in 0..days.unsigned_abs() {
+            result = self.next_business_day(&result, step)?;
+        }
+        Ok(result)
+    }
+
+    /// Returns the number of business days after `start` up to and including
+    /// `end`, or the negated number of business days before `start` down to and
+    /// including `end` if `end` is earlier.
+    ///
+    /// If `end` is a business day, adding the result to `start` with
+    /// [`BusinessCalendar::add_business_days`] returns `end`.
+    pub fn business_days_between(&self, start: &PlainDate, end: &PlainDate) -> i64 {
+        let (first, last, sign) = match start.iso.cmp(&end.iso) {
+            Ordering::Less => (add_days(&start.iso, 1), end.iso, 1),
+            Ordering::Equal => return 0,
+            Ordering::Greater => (end.iso, add_days(&start.iso, -1), -1),
+        };
+        let first_day = i64::from(first.to_epoch_days());
+        let days = i64::from(last.to_epoch_days()) - first_day + 1;
+        // Every whole week has the same number of weekdays.
+        let weeks = days / 7;
+        let mut count = weeks * (7 - i64::from(self.weekend.0.count_ones()));
+        count += (first_day + weeks * 7..first_day + days)
+            .filter(|day| !self.weekend.contains_epoch_day(*day))
+            .count() as i64;
+        let first = PlainDate::new_unchecked(first, start.calendar().clone());
+        let last = PlainDate::new_unchecked(last, start.calendar().clone());
+        sign * (count - self.weekday_holidays_between(&first, &last))
+    }
+
+    /// Returns the number of holidays from `first` to `last` inclusive that are
+    /// not weekend days.
+    fn weekday_holidays_between(&self, first: &PlainDate, last: &PlainDate) -> i64 {
+        let is_weekday = |iso: &IsoDate| !self.weekend.contains(iso_weekday(iso));
+        if let Some(holidays) = self.holidays.holidays_between(first, last) {
+            let mut holidays: Vec<IsoDate> = holidays
+                .into_iter()
+                .map(|date| date.iso)
+                .filter(|iso| first.iso <= *iso && *iso <= last.iso && is_weekday(iso))
+                .collect();
+            holidays.sort_unstable();
+            holidays.dedup();
+            return holidays.len() as i64;
+        }
+        let mut count = 0;
+        let mut iso = first.iso;
+        loop {
+            if is_weekday(&iso)
+                && self
+                    .holidays
+                    .is_holiday(&PlainDate::new_unchecked(iso, first.calendar().clone()))
+            {
+                count += 1;
+            }
+            if iso == last.iso {
+                return count;
+            }
+            iso = add_days(&iso, 1);
+        }
+    }
+
+    /// Moves `date` onto a business day according to `convention`.
+    pub fn roll(&self, date: &PlainDate, convention: RollConvention) -> TemporalResult<PlainDate> {
+        if convention == RollConvention::Unadjusted || self.is_business_day(date) {
+            return Ok(date.clone());
+        }
+        let (step, modified) = match convention {
+            RollConvention::Following => (1, false),
+            RollConvention::ModifiedFollowing => (1, true),
+            RollConvention::Preceding => (-1, false),
+            RollConvention::ModifiedPreceding => (-1, true),
+            RollConvention::Unadjusted => return Err(TemporalError::assert()),
+        };
+        let result = self.next_business_day(date, step)?;
+        if modified && (result.year(), result.month()) != (date.year(), date.month()) {
+            return self.next_business_day(date, -step);
+        }
+        Ok(result)
+    }
+
+    /// Returns the first business day after `date` in the direction of `step`.
+    ///
+    /// Fails if there is no business day within a year of `date`.
+    fn next_business_day(&self, date: &PlainDate, step: i32) -> TemporalResult<PlainDate> {
+        if self.weekend.is_full() {
+            return Err(TemporalError::range().with_message("The weekend has no business days."));
+        }
+        let mut iso = date.iso;
+        for _ in 0..MAX_NON_BUSINESS_DAYS {
+            iso = add_days(&iso, step);
+            iso.check_validity()?;
+            let candidate = PlainDate::new_unchecked(iso, date.calendar().clone());
+            if self.is_business_day(&candidate) {
+                return Ok(candidate);
+            }
+        }
+        Err(TemporalError::range().with_message("No business day within a year of the date."))
+    }
+}
+
+/// The number of consecutive days without a business day after which the
+/// holidays are assumed to never end.
+const MAX_NON_BUSINESS_DAYS: u16 = 366;
+
+/// Returns the ISO date `days` days after `iso`.
+fn add_days(iso: &IsoDate, days: i32) -> IsoDate {
+    IsoDate::balance(iso.year, iso.month.into(), i32::from(iso.day) + days)
+}
+
+/// Returns the ISO weekday of `iso`.
+fn iso_weekday(iso: &IsoDate) -> Weekday {
+    iso.to_icu4x().day_of_week()
+}
+
+/// Returns Western Easter Sunday of an ISO year.
+///
+/// Uses the anonymous Gregorian algorithm (Meeus/Jones/Butcher).
+fn easter_sunday(year: i32) -> IsoDate {
+    let a = year.rem_euclid(19);
+    let b = year.div_euclid(100);
+    let c = year.rem_euclid(100);
+    let d = b.div_euclid(4);
+    let e = b.rem_euclid(4);
+    let f = (b + 8).div_euclid(25);
+    let g = (b - f + 1).div_euclid(3);
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let i = c.div_euclid(4);
+    let k = c.rem_euclid(4);
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l).div_euclid(451);
+    let month = (h + l - 7 * m + 114).div_euclid(31);
+    let day = (h + l - 7 * m + 114).rem_euclid(31) + 1;
+    IsoDate::new_unchecked(year, month as u8, day as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use icu_calendar::types::Weekday;
+
+    use super::{
+        easter_sunday, BusinessCalendar, HolidayCalendar, HolidayRule, HolidaySet, RollConvention,
+        Weekend,
+    };
+    use crate::{
+        iso::IsoDate,
+        options::{DifferenceSettings, Unit},
+        PlainDate,
+    };
+
+    fn date(year: i32, month: u8, day: u8) -> PlainDate {
+        PlainDate::try_new_iso(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn easter() {
+        for (year, month, day) in [(2024, 3, 31), (2025, 4, 20), (2000, 4, 23), (1818, 3, 22)] {
+            assert_eq!(
+                easter_sunday(year),
+                IsoDate::new_unchecked(year, month, day)
+            );
+        }
+    }
+
+    #[test]
+    fn holiday_rules() {
+        let may_day = HolidayRule::NthWeekday {
+            month: 5,
+            weekday: Weekday::Monday,
+            n: 1,
+        };
+        assert!(may_day.is_holiday(&date(2025, 5, 5)));
+        assert!(!may_day.is_holiday(&date(2025, 5, 12)));
+
+        let memorial_day = HolidayRule::NthWeekday {
+            month: 5,
+            weekday: Weekday::Monday,
+            n: -1,
+        };
+        assert!(memorial_day.is_holiday(&date(2025, 5, 26)));
+        assert!(!memorial_day.is_holiday(&date(2025, 5, 19)));
+
+        let good_friday = HolidayRule::Easter { offset: -2 };
+        assert!(good_friday.is_holiday(&date(2025, 4, 18)));
+        assert!(!good_friday.is_holiday(&date(2025, 4, 20)));
+
+        // Offsets may reach more than a year away from Easter.
+        let late = HolidayRule::Easter { offset: 400 };
+        assert!(late.is_holiday(&date(2025, 5, 5)));
+        assert!(!late.is_holiday(&date(2024, 5, 5)));
+        assert_eq!(
+            late.holidays_between(&date(2025, 1, 1), &date(2026, 12, 31)),
+            Some([date(2025, 5, 5), date(2026, 5, 25)].into())
+        );
+
+        let christmas = HolidayRule::Annual { month: 12, day: 25 };
+        assert!(christmas.is_holiday(&date(1999, 12, 25)));
+        assert!(HolidayRule::Date(date(2025, 1, 9)).is_holiday(&date(2025, 1, 9)));
+    }
+
+    #[test]
+    fn business_days() {
+        let holidays: HolidaySet = [
+            HolidayRule::Annual { month: 12, day: 25 },
+            HolidayRule::Annual { month: 12, day: 26 },
+            HolidayRule::Annual { month: 1, day: 1 },
+        ]
+        .into_iter()
+        .collect();
+        let calendar = BusinessCalendar::new(Weekend::default(), holidays);
+
+        // Tuesday 2024-12-24, T+2 skips Christmas, Boxing Day and the weekend.
+        let trade = date(2024, 12, 24);
+        let settlement = calendar.add_business_days(&trade, 2).unwrap();
+        assert_eq!(settlement, date(2024, 12, 30));
+        assert_eq!(calendar.add_business_days(&settlement, -2).unwrap(), trade);
+        assert_eq!(calendar.add_business_days(&trade, 0).unwrap(), trade);
+        assert_eq!(calendar.business_days_between(&trade, &settlement), 2);
+        assert_eq!(calendar.business_days_between(&settlement, &trade), -2);
+
+        // The calendar-day difference agrees with `PlainDate::until`.
+        let settings = DifferenceSettings {
+            largest_unit: Some(Unit::Day),
+            ..Default::default()
+        };
+        assert_eq!(trade.until(&settlement, settings).unwrap().days(), 6);
+
+        for start in 0..14 {
+            let start = date(2024, 12, 20 + start / 2);
+            for days in -12..12 {
+                let end = calendar.add_business_days(&start, days).unwrap();
+                assert_eq!(calendar.business_days_between(&start, &end), days);
+            }
+        }
+
+        // Long ranges agree with counting each day.
+        let holidays = HolidaySet::new()
+            .with_rule(HolidayRule::Annual { month: 12, day: 25 })
+            .with_rule(HolidayRule::Annual { month: 2, day: 29 })
+            .with_rule(HolidayRule::Date(date(2025, 12, 25)))
+            .with_rule(HolidayRule::Easter { offset: -2 })
+            .with_rule(HolidayRule::NthWeekday {
+                month: 5,
+                weekday: Weekday::Monday,
+                n: -1,
+            });
+        let calendar = BusinessCalendar::new(Weekend::FRIDAY_SATURDAY, holidays);
+        let (start, end) = (date(2019, 3, 7), date(2031, 8, 12));
+        let mut expected = 0;
+        let mut day = start.clone();
+        while day != end {
+            day = day.add(&"P1D".parse().unwrap(), None).unwrap();
+            expected += i64::from(calendar.is_business_day(&day));
+        }
+        assert_eq!(calendar.business_days_between(&start, &end), expected);
+        assert_eq!(calendar.business_days_between(&end, &start), -expected);
+
+        let gulf = BusinessCalendar::new(Weekend::FRIDAY_SATURDAY, HolidaySet::new());
+        assert!(gulf.is_business_day(&date(2025, 1, 5)));
+        assert!(!gulf.is_business_day(&date(2025, 1, 3)));
+        assert_eq!(
+            Weekend::new(&[Weekday::Friday, Weekday::Saturday]),
+            Weekend::FRIDAY_SATURDAY
+        );
+
+        let never = BusinessCalendar::new(
+            Weekend::new(&[
+                Weekday::Monday,
+                Weekday::Tuesday,
+                Weekday::Wednesday,
+                Weekday::Thursday,
+                Weekday::Friday,
+                Weekday::Saturday,
+                Weekday::Sunday,
+            ]),
+            HolidaySet::new(),
+        );
+        assert!(never.add_business_days(&trade, 1).is_err());
+
+        // Every weekday is a holiday.
+        struct Closed;
+        impl HolidayCalendar for Closed {
+            fn is_holiday(&self, _: &PlainDate) -> bool {
+                true
+            }
+        }
+        let closed = BusinessCalendar::new(Weekend::default(), Closed);
+        assert!(closed.add_business_days(&trade, 1).is_err());
+        assert_eq!(closed.business_days_between(&trade, &date(2025, 12, 24)), 0);
+        assert!(closed
+            .roll(&date(2025, 5, 31), RollConvention::Following)
+            .is_err());
+    }
+
+    #[test]
+    fn roll_conventions() {
+        let calendar = BusinessCalendar::new(Weekend::default(), HolidaySet::new());
+        // Saturday 2025-05-31 is the last day of the month.
+        let saturday = date(2025, 5, 31);
+        let roll = |convention| calendar.roll(&saturday, convention).unwrap();
+        assert_eq!(roll(RollConvention::Unadjusted), saturday);
+        assert_eq!(roll(RollConvention::Following), date(2025, 6, 2));
+        assert_eq!(roll(RollConvention::ModifiedFollowing), date(2025, 5, 30));
+        assert_eq!(roll(RollConvention::Preceding), date(2025, 5, 30));
+
+        // Sunday 2025-06-01 is the first day of the month.
+        let sunday = date(2025, 6, 1);
+        assert_eq!(
+            calendar
+                .roll(&sunday, RollConvention::ModifiedPreceding)
+                .unwrap(),
+            date(2025, 6, 2)
+        );
+        let business_day = date(2025, 6, 3);
+        assert_eq!(
+            calendar
+                .roll(&business_day, RollConvention::Preceding)
+                .unwrap(),
+            business_day
+        );
+    }
+}
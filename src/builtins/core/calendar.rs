@@ -9,18 +9,18 @@ use crate::{
     },
     iso::IsoDate,
     options::{ArithmeticOverflow, Unit},
-    parsers::parse_allowed_calendar_formats,
+    parsers::{parse_allowed_calendar_formats, Encoded},
     TemporalError, TemporalResult,
 };
-use alloc::string::ToString;
+use alloc::{format, string::ToString, vec::Vec};
 use core::str::FromStr;
 
 use icu_calendar::{
     cal::{
         Buddhist, Chinese, Coptic, Dangi, Ethiopian, EthiopianEraStyle, Hebrew, HijriSimulated,
-        HijriTabular, HijriUmmAlQura, Indian, Japanese, JapaneseExtended, Persian, Roc,
+        HijriTabular, HijriUmmAlQura, Indian, Japanese, JapaneseExtended, Julian, Persian, Roc,
     },
-    AnyCalendar, AnyCalendarKind, Calendar as IcuCalendar, Iso, Ref,
+    AnyCalendar, AnyCalendarKind, Calendar as IcuCalendar, Date as IcuDate, Iso, Ref,
 };
 use icu_calendar::{
     cal::{HijriTabularEpoch, HijriTabularLeapYears},
@@ -30,27 +30,67 @@ use icu_calendar::{
 };
 use icu_locale::extensions::unicode::Value;
 use tinystr::{tinystr, TinyAsciiStr};
+use writeable::{impl_display_with_writeable, LengthHint, Writeable};
 
 use super::ZonedDateTime;
 
+mod custom;
 mod era;
 mod fields;
+mod julian;
 mod types;
+mod week;
 
+pub(crate) use custom::NO_CUSTOM_CALENDARS;
+pub use custom::{CustomCalendar, CustomCalendarResolver};
 pub use fields::{CalendarFields, YearMonthCalendarFields};
 #[cfg(test)]
 pub(crate) use types::month_to_month_code;
 pub(crate) use types::ResolutionType;
-pub use types::{MonthCode, ResolvedCalendarFields};
+pub use types::{CalendarMonth, MonthCode, ResolvedCalendarFields};
+pub use week::WeekRules;
+
+pub use era::{CalendarEra, EraDirection};
 
 use era::EraInfo;
 
 /// The core `Calendar` type for `temporal_rs`
 ///
 /// A `Calendar` in `temporal_rs` can be any calendar that is currently
-/// supported by [`icu_calendar`].
+/// supported by [`icu_calendar`], the proleptic Julian calendar, or a
+/// [`CustomCalendar`].
+#[derive(Debug, Clone)]
+pub struct Calendar(CalendarInner);
+
+/// The calendar implementation that backs a [`Calendar`].
 #[derive(Debug, Clone)]
-pub struct Calendar(Ref<'static, AnyCalendar>);
+enum CalendarInner {
+    /// A calendar supported by [`AnyCalendar`].
+    Any(Ref<'static, AnyCalendar>),
+    /// The proleptic Julian calendar, which [`AnyCalendar`] does not support.
+    Julian,
+    /// A user-defined calendar.
+    Custom(&'static dyn CustomCalendar),
+}
+
+/// Converts an `IsoDate` into an [`icu_calendar::Date`] of the calendar and
+/// evaluates the body with it, or evaluates the custom body with a
+/// [`CustomCalendar`].
+macro_rules! with_calendar_date {
+    ($calendar:expr, $iso_date:expr, |$date:ident| $body:expr, |$custom:ident| $custom_body:expr) => {
+        match &$calendar.0 {
+            CalendarInner::Any(cal) => {
+                let $date = IcuDate::new_from_iso($iso_date.to_icu4x(), *cal);
+                $body
+            }
+            CalendarInner::Julian => {
+                let $date = IcuDate::new_from_iso($iso_date.to_icu4x(), Julian);
+                $body
+            }
+            CalendarInner::Custom($custom) => $custom_body,
+        }
+    };
+}
 
 impl Default for Calendar {
     fn default() -> Self {
@@ -66,10 +106,26 @@ impl PartialEq for Calendar {
 
 impl Eq for Calendar {}
 
+/// Writes the calendar's identifier.
+impl Writeable for Calendar {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        sink.write_str(self.identifier())
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        LengthHint::exact(self.identifier().len())
+    }
+}
+
+impl_display_with_writeable!(Calendar);
+
 impl Calendar {
     /// The ISO 8601 calendar
     pub const ISO: Self = Self::new(AnyCalendarKind::Iso);
 
+    /// The proleptic Julian calendar
+    pub const JULIAN: Self = Self(CalendarInner::Julian);
+
     /// Create a `Calendar` from an ICU [`AnyCalendarKind`].
     #[warn(clippy::wildcard_enum_match_arm)] // Warns if the calendar kind gets out of sync.
     pub const fn new(kind: AnyCalendarKind) -> Self {
@@ -128,19 +184,62 @@ impl Calendar {
             _ => panic!("Unreachable: match must handle all variants of `AnyCalendarKind`"),
         };
 
-        Self(Ref(cal))
+        Self(CalendarInner::Any(Ref(cal)))
     }
 
     /// Returns a `Calendar` from the a slice of UTF-8 encoded bytes.
     pub fn try_from_utf8(bytes: &[u8]) -> TemporalResult<Self> {
+        // "julian" is not supported by `CalendarAlgorithm`
+        if bytes.eq_ignore_ascii_case(b"julian") {
+            return Ok(Self::JULIAN);
+        }
         let kind = Self::try_kind_from_utf8(bytes)?;
         Ok(Self::new(kind))
     }
 
+    /// Returns a `Calendar` from a slice of UTF-8 encoded bytes, resolving
+    /// identifiers that are not built-in calendars with `resolver`.
+    pub fn try_from_utf8_with_resolver(
+        bytes: &[u8],
+        resolver: &(impl CustomCalendarResolver + ?Sized),
+    ) -> TemporalResult<Self> {
+        Self::try_from_utf8(bytes).or_else(|e| {
+            resolver
+                .resolve(bytes)
+                .ok_or(e)
+                .and_then(Self::try_new_custom)
+        })
+    }
+
+    /// Returns a `Calendar` from a slice of UTF-16 encoded code units.
+    pub fn try_from_utf16(units: &[u16]) -> TemporalResult<Self> {
+        Encoded::Utf16(units).with_ascii(Self::try_from_utf8)
+    }
+
+    /// Create a `Calendar` from a [`CustomCalendar`].
+    ///
+    /// Returns an error if the identifier of the calendar is not a valid Unicode
+    /// extension value, or if it is the identifier of a built-in calendar.
+    pub fn try_new_custom(calendar: &'static dyn CustomCalendar) -> TemporalResult<Self> {
+        let identifier = calendar.identifier().as_bytes();
+        Value::try_from_utf8(identifier).map_err(|_| {
+            TemporalError::range().with_message("Invalid custom calendar identifier.")
+        })?;
+        if identifier.eq_ignore_ascii_case(b"julian")
+            || Self::try_kind_from_utf8(identifier).is_ok()
+        {
+            return Err(TemporalError::range()
+                .with_message("Custom calendar identifier conflicts with a built-in calendar."));
+        }
+        Ok(Self::custom(calendar))
+    }
+
+    const fn custom(calendar: &'static dyn CustomCalendar) -> Self {
+        Self(CalendarInner::Custom(calendar))
+    }
+
     /// Returns a `Calendar` from the a slice of UTF-8 encoded bytes.
     pub(crate) fn try_kind_from_utf8(bytes: &[u8]) -> TemporalResult<AnyCalendarKind> {
-        // TODO: Determine the best way to handle "julian" here.
-        // Not supported by `CalendarAlgorithm`
         let icu_locale_value = Value::try_from_utf8(&bytes.to_ascii_lowercase())
             .map_err(|e| TemporalError::range().with_message(e.to_string()))?;
         let algorithm = CalendarAlgorithm::try_from(&icu_locale_value)
@@ -179,13 +278,42 @@ impl Calendar {
     /// Returns whether the current calendar is `ISO`
     #[inline]
     pub fn is_iso(&self) -> bool {
-        matches!(self.0 .0, AnyCalendar::Iso(_))
+        matches!(self.0, CalendarInner::Any(Ref(AnyCalendar::Iso(_))))
+    }
+
+    /// Returns whether the current calendar is the proleptic Julian calendar
+    #[inline]
+    pub fn is_julian(&self) -> bool {
+        matches!(self.0, CalendarInner::Julian)
+    }
+
+    /// Returns the [`CustomCalendar`] of this calendar, if any.
+    #[inline]
+    pub fn as_custom(&self) -> Option<&'static dyn CustomCalendar> {
+        match self.0 {
+            CalendarInner::Custom(cal) => Some(cal),
+            _ => None,
+        }
     }
 
     /// Returns the kind of this calendar
+    ///
+    /// This is lossy: the Julian and custom calendars are not supported by
+    /// [`AnyCalendar`] and report [`AnyCalendarKind::Iso`]. Use
+    /// [`Calendar::any_calendar_kind`] to tell them apart from the ISO calendar.
     #[inline]
     pub fn kind(&self) -> AnyCalendarKind {
-        self.0 .0.kind()
+        self.any_calendar_kind().unwrap_or(AnyCalendarKind::Iso)
+    }
+
+    /// Returns the kind of this calendar, or `None` if the calendar
+    /// is not supported by [`AnyCalendar`].
+    #[inline]
+    pub fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        match &self.0 {
+            CalendarInner::Any(cal) => Some(cal.0.kind()),
+            CalendarInner::Julian | CalendarInner::Custom(_) => None,
+        }
     }
 
     /// `CalendarDateFromFields`
@@ -194,6 +322,10 @@ impl Calendar {
         fields: CalendarFields,
         overflow: ArithmeticOverflow,
     ) -> TemporalResult<PlainDate> {
+        if let Some(cal) = self.as_custom() {
+            let iso = cal.date_from_fields(&fields, overflow)?;
+            return PlainDate::try_new(iso.year, iso.month, iso.day, self.clone());
+        }
         let resolved_fields =
             ResolvedCalendarFields::try_from_fields(self, &fields, overflow, ResolutionType::Date)?;
 
@@ -208,16 +340,7 @@ impl Calendar {
             );
         }
 
-        let calendar_date = self
-            .0
-            .from_codes(
-                resolved_fields.era_year.era.as_ref().map(|e| e.0.as_str()),
-                resolved_fields.era_year.year,
-                IcuMonthCode(resolved_fields.month_code.0),
-                resolved_fields.day,
-            )
-            .map_err(TemporalError::from_icu4x)?;
-        let iso = self.0.to_iso(&calendar_date);
+        let iso = *self.iso_from_resolved_fields(&resolved_fields)?.inner();
         PlainDate::new_with_overflow(
             Iso.extended_year(&iso),
             Iso.month(&iso).ordinal,
@@ -250,6 +373,22 @@ impl Calendar {
             let date = self.date_from_fields(fields, overflow)?;
             fields = CalendarFields::from_date(&date);
         }
+        if let Some(cal) = self.as_custom() {
+            // Custom calendars have no reference years, so the year
+            // of the resolved date is used as the reference year.
+            if fields.year.is_none() {
+                return Err(TemporalError::r#type()
+                    .with_message("A year is required for a custom calendar PlainMonthDay."));
+            }
+            let iso = cal.date_from_fields(&fields, overflow)?;
+            return PlainMonthDay::new_with_overflow(
+                iso.month,
+                iso.day,
+                self.clone(),
+                overflow,
+                Some(iso.year),
+            );
+        }
         let resolved_fields = ResolvedCalendarFields::try_from_fields(
             self,
             &fields,
@@ -267,16 +406,7 @@ impl Calendar {
         }
 
         // We trust ResolvedCalendarFields to have calculated an appropriate reference year for us
-        let calendar_date = self
-            .0
-            .from_codes(
-                resolved_fields.era_year.era.as_ref().map(|e| e.0.as_str()),
-                resolved_fields.era_year.year,
-                IcuMonthCode(resolved_fields.month_code.0),
-                resolved_fields.day,
-            )
-            .map_err(TemporalError::from_icu4x)?;
-        let iso = self.0.to_iso(&calendar_date);
+        let iso = *self.iso_from_resolved_fields(&resolved_fields)?.inner();
         PlainMonthDay::new_with_overflow(
             Iso.month(&iso).ordinal,
             Iso.day_of_month(&iso).0,
@@ -292,6 +422,17 @@ impl Calendar {
         fields: YearMonthCalendarFields,
         overflow: ArithmeticOverflow,
     ) -> TemporalResult<PlainYearMonth> {
+        if let Some(cal) = self.as_custom() {
+            let fields = CalendarFields::from(fields).with_day(1);
+            let iso = cal.date_from_fields(&fields, overflow)?;
+            return PlainYearMonth::new_with_overflow(
+                iso.year,
+                iso.month,
+                Some(iso.day),
+                self.clone(),
+                overflow,
+            );
+        }
         // TODO: add a from_partial_year_month method on ResolvedCalendarFields
         let resolved_fields = ResolvedCalendarFields::try_from_fields(
             self,
@@ -310,16 +451,7 @@ impl Calendar {
         }
 
         // NOTE: This might preemptively throw as `ICU4X` does not support regulating.
-        let calendar_date = self
-            .0
-            .from_codes(
-                resolved_fields.era_year.era.as_ref().map(|e| e.0.as_str()),
-                resolved_fields.era_year.year,
-                IcuMonthCode(resolved_fields.month_code.0),
-                resolved_fields.day,
-            )
-            .map_err(TemporalError::from_icu4x)?;
-        let iso = self.0.to_iso(&calendar_date);
+        let iso = *self.iso_from_resolved_fields(&resolved_fields)?.inner();
         PlainYearMonth::new_with_overflow(
             Iso.year_info(&iso).year,
             Iso.month(&iso).ordinal,
@@ -342,6 +474,14 @@ impl Calendar {
             // 11. Return ? CreateTemporalDate(result.[[Year]], result.[[Month]], result.[[Day]], "iso8601").
             return PlainDate::try_new(result.year, result.month, result.day, self.clone());
         }
+        if self.is_julian() {
+            let result = julian::date_add(date, duration, overflow)?;
+            return PlainDate::try_new(result.year, result.month, result.day, self.clone());
+        }
+        if let Some(cal) = self.as_custom() {
+            let result = cal.date_add(date, duration, overflow)?;
+            return PlainDate::try_new(result.year, result.month, result.day, self.clone());
+        }
 
         Err(TemporalError::range().with_message("Not yet implemented."))
     }
@@ -357,6 +497,14 @@ impl Calendar {
             let date_duration = one.diff_iso_date(two, largest_unit)?;
             return Ok(Duration::from(date_duration));
         }
+        if self.is_julian() {
+            let date_duration = julian::date_until(one, two, largest_unit)?;
+            return Ok(Duration::from(date_duration));
+        }
+        if let Some(cal) = self.as_custom() {
+            let date_duration = cal.date_until(one, two, largest_unit)?;
+            return Ok(Duration::from(date_duration));
+        }
         Err(TemporalError::range().with_message("Not yet implemented."))
     }
 
@@ -365,11 +513,12 @@ impl Calendar {
         if self.is_iso() {
             return None;
         }
-        let calendar_date = self.0.from_iso(*iso_date.to_icu4x().inner());
-        self.0
-            .year_info(&calendar_date)
-            .era()
-            .map(|era_info| era_info.era)
+        with_calendar_date!(
+            self,
+            iso_date,
+            |date| date.year().era().map(|era_info| era_info.era),
+            |cal| cal.era(iso_date)
+        )
     }
 
     /// `CalendarEraYear`
@@ -377,11 +526,12 @@ impl Calendar {
         if self.is_iso() {
             return None;
         }
-        let calendar_date = self.0.from_iso(*iso_date.to_icu4x().inner());
-        self.0
-            .year_info(&calendar_date)
-            .era()
-            .map(|era_info| era_info.year)
+        with_calendar_date!(
+            self,
+            iso_date,
+            |date| date.year().era().map(|era_info| era_info.year),
+            |cal| cal.era_year(iso_date)
+        )
     }
 
     /// `CalendarArithmeticYear`
@@ -389,8 +539,8 @@ impl Calendar {
         if self.is_iso() {
             return iso_date.year;
         }
-        let calendar_date = self.0.from_iso(*iso_date.to_icu4x().inner());
-        self.0.extended_year(&calendar_date)
+        with_calendar_date!(self, iso_date, |date| date.extended_year(), |cal| cal
+            .year(iso_date))
     }
 
     /// `CalendarMonth`
@@ -398,8 +548,8 @@ impl Calendar {
         if self.is_iso() {
             return iso_date.month;
         }
-        let calendar_date = self.0.from_iso(*iso_date.to_icu4x().inner());
-        self.0.month(&calendar_date).ordinal
+        with_calendar_date!(self, iso_date, |date| date.month().ordinal, |cal| cal
+            .month(iso_date))
     }
 
     /// `CalendarMonthCode`
@@ -408,8 +558,12 @@ impl Calendar {
             let mc = iso_date.to_icu4x().month().standard_code.0;
             return MonthCode(mc);
         }
-        let calendar_date = self.0.from_iso(*iso_date.to_icu4x().inner());
-        MonthCode(self.0.month(&calendar_date).standard_code.0)
+        with_calendar_date!(
+            self,
+            iso_date,
+            |date| MonthCode(date.month().standard_code.0),
+            |cal| cal.month_code(iso_date)
+        )
     }
 
     /// `CalendarDay`
@@ -417,8 +571,8 @@ impl Calendar {
         if self.is_iso() {
             return iso_date.day;
         }
-        let calendar_date = self.0.from_iso(*iso_date.to_icu4x().inner());
-        self.0.day_of_month(&calendar_date).0
+        with_calendar_date!(self, iso_date, |date| date.day_of_month().0, |cal| cal
+            .day(iso_date))
     }
 
     /// `CalendarDayOfWeek`
@@ -431,26 +585,56 @@ impl Calendar {
         if self.is_iso() {
             return iso_date.to_icu4x().day_of_year().0;
         }
-        let calendar_date = self.0.from_iso(*iso_date.to_icu4x().inner());
-        self.0.day_of_year(&calendar_date).0
+        with_calendar_date!(self, iso_date, |date| date.day_of_year().0, |cal| cal
+            .day_of_year(iso_date))
     }
 
     /// `CalendarWeekOfYear`
+    ///
+    /// Non-ISO calendars number the weeks of the calendar year with [`WeekRules::ISO`].
     pub fn week_of_year(&self, iso_date: &IsoDate) -> Option<u8> {
         if self.is_iso() {
             return Some(iso_date.to_icu4x().week_of_year().week_number);
         }
-        // TODO: Research in ICU4X and determine best approach.
-        None
+        self.week_of_year_with_rules(iso_date, WeekRules::ISO)
     }
 
     /// `CalendarYearOfWeek`
+    ///
+    /// Non-ISO calendars number the weeks of the calendar year with [`WeekRules::ISO`].
     pub fn year_of_week(&self, iso_date: &IsoDate) -> Option<i32> {
         if self.is_iso() {
             return Some(iso_date.to_icu4x().week_of_year().iso_year);
         }
-        // TODO: Research in ICU4X and determine best approach.
-        None
+        self.year_of_week_with_rules(iso_date, WeekRules::ISO)
+    }
+
+    /// Returns the week of the calendar year according to `rules`.
+    pub fn week_of_year_with_rules(&self, iso_date: &IsoDate, rules: WeekRules) -> Option<u8> {
+        Some(self.week_of(iso_date, rules).0)
+    }
+
+    /// Returns the calendar year that the week of `iso_date` belongs to according to `rules`.
+    pub fn year_of_week_with_rules(&self, iso_date: &IsoDate, rules: WeekRules) -> Option<i32> {
+        let (_, year_offset) = self.week_of(iso_date, rules);
+        Some(self.year(iso_date) + i32::from(year_offset))
+    }
+
+    fn week_of(&self, iso_date: &IsoDate, rules: WeekRules) -> (u8, i8) {
+        let day_of_year = self.day_of_year(iso_date);
+        rules.week_of(
+            day_of_year,
+            self.day_of_week(iso_date),
+            self.days_in_year(iso_date),
+            || {
+                let last_day_of_previous_year = IsoDate::balance(
+                    iso_date.year,
+                    iso_date.month.into(),
+                    i32::from(iso_date.day) - i32::from(day_of_year),
+                );
+                self.days_in_year(&last_day_of_previous_year)
+            },
+        )
     }
 
     /// `CalendarDaysInWeek`
@@ -463,8 +647,12 @@ impl Calendar {
         if self.is_iso() {
             return iso_date.to_icu4x().days_in_month() as u16;
         }
-        let calendar_date = self.0.from_iso(*iso_date.to_icu4x().inner());
-        self.0.days_in_month(&calendar_date) as u16
+        with_calendar_date!(
+            self,
+            iso_date,
+            |date| u16::from(date.days_in_month()),
+            |cal| cal.days_in_month(iso_date)
+        )
     }
 
     /// `CalendarDaysInYear`
@@ -472,8 +660,8 @@ impl Calendar {
         if self.is_iso() {
             return iso_date.to_icu4x().days_in_year();
         }
-        let calendar_date = self.0.from_iso(*iso_date.to_icu4x().inner());
-        self.0.days_in_year(&calendar_date)
+        with_calendar_date!(self, iso_date, |date| date.days_in_year(), |cal| cal
+            .days_in_year(iso_date))
     }
 
     /// `CalendarMonthsInYear`
@@ -481,8 +669,39 @@ impl Calendar {
         if self.is_iso() {
             return 12;
         }
-        let calendar_date = self.0.from_iso(*iso_date.to_icu4x().inner());
-        self.0.months_in_year(&calendar_date) as u16
+        with_calendar_date!(
+            self,
+            iso_date,
+            |date| u16::from(date.months_in_year()),
+            |cal| cal.months_in_year(iso_date)
+        )
+    }
+
+    /// Returns the months of the calendar year of `iso_date` in order, including
+    /// any leap months.
+    pub fn month_codes_in_year(&self, iso_date: &IsoDate) -> Vec<CalendarMonth> {
+        let day_of_year = self.day_of_year(iso_date);
+        let mut start = IsoDate::balance(
+            iso_date.year,
+            iso_date.month.into(),
+            i32::from(iso_date.day) - i32::from(day_of_year) + 1,
+        );
+        (0..self.months_in_year(iso_date))
+            .map(|_| {
+                let month = CalendarMonth {
+                    month: self.month(&start),
+                    month_code: self.month_code(&start),
+                    days_in_month: self.days_in_month(&start),
+                    start,
+                };
+                start = IsoDate::balance(
+                    start.year,
+                    start.month.into(),
+                    i32::from(start.day) + i32::from(month.days_in_month),
+                );
+                month
+            })
+            .collect()
     }
 
     /// `CalendarInLeapYear`
@@ -490,101 +709,171 @@ impl Calendar {
         if self.is_iso() {
             return iso_date.to_icu4x().is_in_leap_year();
         }
-        let calendar_date = self.0.from_iso(*iso_date.to_icu4x().inner());
-        self.0.is_in_leap_year(&calendar_date)
+        with_calendar_date!(self, iso_date, |date| date.is_in_leap_year(), |cal| cal
+            .in_leap_year(iso_date))
     }
 
     /// Returns the identifier of this calendar slot.
     pub fn identifier(&self) -> &'static str {
-        // icu_calendar lists iso8601 and julian as None
-        match self.0.calendar_algorithm() {
-            Some(c) => c.as_str(),
-            None if self.is_iso() => "iso8601",
-            None => "julian",
+        match &self.0 {
+            // icu_calendar lists iso8601 as None
+            CalendarInner::Any(cal) => match cal.calendar_algorithm() {
+                Some(c) => c.as_str(),
+                None => "iso8601",
+            },
+            CalendarInner::Julian => "julian",
+            CalendarInner::Custom(cal) => cal.identifier(),
         }
     }
+
+    /// Returns the eras of this calendar in chronological order.
+    ///
+    /// Calendars without eras, such as `iso8601`, return an empty list.
+    pub fn eras(&self) -> Vec<CalendarEra> {
+        let table = self.era_table();
+        let day_before =
+            |date: IsoDate| IsoDate::balance(date.year, date.month.into(), i32::from(date.day) - 1);
+        table
+            .iter()
+            .enumerate()
+            .map(|(i, era)| {
+                let info = CalendarEra::new(era, None, None);
+                let start = match info.direction() {
+                    EraDirection::Forward => era.start.or_else(|| {
+                        info.min_era_year()
+                            .and_then(|min| self.start_of_year(info.arithmetic_year(min)).ok())
+                    }),
+                    EraDirection::Backward => info
+                        .max_era_year()
+                        .and_then(|max| self.start_of_year(info.arithmetic_year(max)).ok()),
+                };
+                let next_year_start = match info.direction() {
+                    EraDirection::Forward => info.max_era_year().and_then(|max| {
+                        match table.get(i + 1).filter(|next| next.start.is_some()) {
+                            Some(next) => next.start,
+                            None => self.start_of_year(info.arithmetic_year(max) + 1).ok(),
+                        }
+                    }),
+                    EraDirection::Backward => info
+                        .min_era_year()
+                        .and_then(|min| self.start_of_year(info.arithmetic_year(min) + 1).ok()),
+                };
+                CalendarEra::new(era, start, next_year_start.map(day_before))
+            })
+            .collect()
+    }
 }
 
 impl Calendar {
-    pub(crate) fn get_era_info(&self, era_alias: &TinyAsciiStr<19>) -> Option<EraInfo> {
-        match self.0 .0.kind() {
-            AnyCalendarKind::Buddhist if *era_alias == tinystr!(19, "be") => {
-                Some(era::BUDDHIST_ERA)
-            }
-            AnyCalendarKind::Coptic if *era_alias == tinystr!(19, "am") => Some(era::COPTIC_ERA),
-            AnyCalendarKind::Ethiopian if era::ETHIOPIC_ERA_IDENTIFIERS.contains(era_alias) => {
-                Some(era::ETHIOPIC_ERA)
-            }
-            AnyCalendarKind::Ethiopian
-                if era::ETHIOPIC_ETHOPICAA_ERA_IDENTIFIERS.contains(era_alias) =>
-            {
-                Some(era::ETHIOPIC_ETHIOAA_ERA)
-            }
-            AnyCalendarKind::EthiopianAmeteAlem
-                if era::ETHIOAA_ERA_IDENTIFIERS.contains(era_alias) =>
-            {
-                Some(era::ETHIOAA_ERA)
-            }
-            AnyCalendarKind::Gregorian if era::GREGORY_ERA_IDENTIFIERS.contains(era_alias) => {
-                Some(era::GREGORY_ERA)
-            }
-            AnyCalendarKind::Gregorian
-                if era::GREGORY_INVERSE_ERA_IDENTIFIERS.contains(era_alias) =>
-            {
-                Some(era::GREGORY_INVERSE_ERA)
-            }
-            AnyCalendarKind::Hebrew if *era_alias == tinystr!(19, "am") => Some(era::HEBREW_ERA),
-            AnyCalendarKind::Indian if *era_alias == tinystr!(19, "shaka") => Some(era::INDIAN_ERA),
-            AnyCalendarKind::HijriTabularTypeIIFriday
-            | AnyCalendarKind::HijriSimulatedMecca
-            | AnyCalendarKind::HijriTabularTypeIIThursday
-            | AnyCalendarKind::HijriUmmAlQura
-                if *era_alias == tinystr!(19, "ah") =>
-            {
-                Some(era::ISLAMIC_ERA)
-            }
-            AnyCalendarKind::HijriTabularTypeIIFriday
-            | AnyCalendarKind::HijriSimulatedMecca
-            | AnyCalendarKind::HijriTabularTypeIIThursday
-            | AnyCalendarKind::HijriUmmAlQura
-                if *era_alias == tinystr!(19, "bh") =>
-            {
-                Some(era::ISLAMIC_INVERSE_ERA)
-            }
-            AnyCalendarKind::Japanese if *era_alias == tinystr!(19, "heisei") => {
-                Some(era::HEISEI_ERA)
-            }
-            AnyCalendarKind::Japanese if era::JAPANESE_ERA_IDENTIFIERS.contains(era_alias) => {
-                Some(era::JAPANESE_ERA)
-            }
-            AnyCalendarKind::Japanese
-                if era::JAPANESE_INVERSE_ERA_IDENTIFIERS.contains(era_alias) =>
-            {
-                Some(era::JAPANESE_INVERSE_ERA)
-            }
-            AnyCalendarKind::Japanese if *era_alias == tinystr!(19, "meiji") => {
-                Some(era::MEIJI_ERA)
-            }
-            AnyCalendarKind::Japanese if *era_alias == tinystr!(19, "reiwa") => {
-                Some(era::REIWA_ERA)
-            }
-            AnyCalendarKind::Japanese if *era_alias == tinystr!(19, "showa") => {
-                Some(era::SHOWA_ERA)
+    /// Converts resolved fields into an ISO date using this calendar.
+    fn iso_from_resolved_fields(
+        &self,
+        resolved_fields: &ResolvedCalendarFields,
+    ) -> TemporalResult<IcuDate<Iso>> {
+        let era = resolved_fields.era_year.era.as_ref().map(|e| e.0.as_str());
+        let year = resolved_fields.era_year.year;
+        let month_code = IcuMonthCode(resolved_fields.month_code.0);
+        let day = resolved_fields.day;
+        match &self.0 {
+            CalendarInner::Any(cal) => {
+                IcuDate::try_new_from_codes(era, year, month_code, day, *cal).map(|d| d.to_iso())
             }
-            AnyCalendarKind::Japanese if *era_alias == tinystr!(19, "taisho") => {
-                Some(era::TAISHO_ERA)
+            CalendarInner::Julian => {
+                IcuDate::try_new_from_codes(era, year, month_code, day, Julian).map(|d| d.to_iso())
             }
-            AnyCalendarKind::Persian if *era_alias == tinystr!(19, "ap") => Some(era::PERSIAN_ERA),
-            AnyCalendarKind::Roc if *era_alias == tinystr!(19, "roc") => Some(era::ROC_ERA),
-            AnyCalendarKind::Roc if *era_alias == tinystr!(19, "broc") => {
-                Some(era::ROC_INVERSE_ERA)
+            CalendarInner::Custom(_) => {
+                return Err(TemporalError::assert()
+                    .with_message("Custom calendars do not resolve calendar fields."))
             }
-            _ => None,
         }
+        .map_err(TemporalError::from_icu4x)
+    }
+
+    /// Returns the eras of this calendar in the order in which they are listed by
+    /// [`Calendar::eras`].
+    fn era_table(&self) -> &'static [EraInfo] {
+        let kind = match &self.0 {
+            CalendarInner::Any(cal) => cal.0.kind(),
+            // The Julian calendar has the same eras as the Gregorian calendar.
+            CalendarInner::Julian => AnyCalendarKind::Gregorian,
+            CalendarInner::Custom(_) => return &[],
+        };
+        era::ALL_ALLOWED_ERAS
+            .iter()
+            .find(|(k, _)| *k == kind)
+            .map_or(&[], |(_, eras)| eras)
+    }
+
+    pub(crate) fn get_era_info(&self, era_alias: &TinyAsciiStr<19>) -> Option<&'static EraInfo> {
+        self.era_table().iter().find(|era| era.matches(era_alias))
+    }
+
+    /// Returns the error for an era that is not valid for this calendar.
+    pub(crate) fn invalid_era_error(&self, era_alias: &TinyAsciiStr<19>) -> TemporalError {
+        let eras = self.era_table();
+        if eras.is_empty() {
+            return TemporalError::range().with_message(format!(
+                "The {} calendar does not support eras.",
+                self.identifier()
+            ));
+        }
+        let expected = eras
+            .iter()
+            .flat_map(|era| {
+                core::iter::once(era.name.as_str()).chain(era.aliases.iter().map(|a| a.as_str()))
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        TemporalError::range().with_message(format!(
+            "Invalid era '{era_alias}' for the {} calendar; expected one of: {expected}.",
+            self.identifier()
+        ))
+    }
+
+    /// Returns the first ISO date of the calendar year `year`.
+    fn start_of_year(&self, year: i32) -> TemporalResult<IsoDate> {
+        let fields = CalendarFields::new()
+            .with_year(year)
+            .with_month_code(MonthCode(tinystr!(4, "M01")))
+            .with_day(1);
+        self.date_from_fields(fields, ArithmeticOverflow::Reject)
+            .map(|date| date.iso)
+    }
+
+    /// Returns the ISO date of the `day_of_year` day of the calendar year `year`.
+    pub(crate) fn date_from_ordinal(
+        &self,
+        year: i32,
+        day_of_year: u16,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<IsoDate> {
+        let start = self.start_of_year(year)?;
+        let days_in_year = self.days_in_year(&start);
+        let day_of_year = match overflow {
+            ArithmeticOverflow::Constrain => day_of_year.clamp(1, days_in_year),
+            ArithmeticOverflow::Reject if !(1..=days_in_year).contains(&day_of_year) => {
+                return Err(TemporalError::range().with_message(format!(
+                    "Day of year {day_of_year} is not valid; year {year} has {days_in_year} days."
+                )))
+            }
+            ArithmeticOverflow::Reject => day_of_year,
+        };
+        let iso = IsoDate::balance(
+            start.year,
+            start.month.into(),
+            i32::from(start.day) + i32::from(day_of_year) - 1,
+        );
+        iso.check_validity()?;
+        Ok(iso)
     }
 
     pub(crate) fn get_calendar_default_era(&self) -> Option<EraInfo> {
-        match self.0 .0.kind() {
+        let cal = match &self.0 {
+            CalendarInner::Any(cal) => cal,
+            CalendarInner::Julian => return Some(era::GREGORY_ERA),
+            CalendarInner::Custom(_) => return None,
+        };
+        match cal.0.kind() {
             AnyCalendarKind::Buddhist => Some(era::BUDDHIST_ERA),
             AnyCalendarKind::Chinese => None,
             AnyCalendarKind::Coptic => Some(era::COPTIC_ERA),
@@ -606,8 +895,13 @@ impl Calendar {
         }
     }
 
-    pub(crate) fn calendar_has_eras(kind: AnyCalendarKind) -> bool {
-        match kind {
+    pub(crate) fn has_eras(&self) -> bool {
+        let cal = match &self.0 {
+            CalendarInner::Any(cal) => cal,
+            CalendarInner::Julian => return true,
+            CalendarInner::Custom(cal) => return cal.has_eras(),
+        };
+        match cal.0.kind() {
             AnyCalendarKind::Buddhist
             | AnyCalendarKind::Coptic
             | AnyCalendarKind::Ethiopian
@@ -663,7 +957,320 @@ mod tests {
     use crate::{iso::IsoDate, options::Unit};
     use core::str::FromStr;
 
-    use super::Calendar;
+    use super::{Calendar, WeekRules};
+
+    #[test]
+    fn week_rules_iso_matches_iso_weeks() {
+        let gregorian = Calendar::from_str("gregory").unwrap();
+        let start = IsoDate::new_unchecked(1999, 12, 1).to_epoch_days();
+        let end = IsoDate::new_unchecked(2031, 1, 31).to_epoch_days();
+        for days in start..end {
+            let date = IsoDate::balance(1970, 1, days + 1);
+            let week = date.to_icu4x().week_of_year();
+            assert_eq!(
+                gregorian.week_of_year(&date),
+                Some(week.week_number),
+                "{date:?}"
+            );
+            assert_eq!(
+                gregorian.year_of_week(&date),
+                Some(week.iso_year),
+                "{date:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn week_rules_from_locale() {
+        use icu_calendar::types::Weekday;
+        use icu_locale::locale;
+
+        let us = WeekRules::try_from_locale(&locale!("en-US")).unwrap();
+        assert_eq!(us.first_weekday(), Weekday::Sunday);
+        assert_eq!(us.minimal_days(), 4);
+        let de = WeekRules::try_from_locale(&locale!("de")).unwrap();
+        assert_eq!(de, WeekRules::ISO);
+        let us_monday = WeekRules::try_from_locale(&locale!("en-US-u-fw-mon")).unwrap();
+        assert_eq!(us_monday, WeekRules::ISO);
+
+        let us = us.with_minimal_days(1).unwrap();
+        assert_eq!(us, WeekRules::try_new(Weekday::Sunday, 1).unwrap());
+        assert!(us.with_minimal_days(0).is_err());
+        assert!(WeekRules::try_new(Weekday::Monday, 0).is_err());
+        assert!(WeekRules::try_new(Weekday::Monday, 8).is_err());
+    }
+
+    #[test]
+    fn week_of_year_with_rules() {
+        let us = WeekRules::try_from_locale(&icu_locale::locale!("en-US"))
+            .and_then(|rules| rules.with_minimal_days(1))
+            .unwrap();
+        let gregorian = Calendar::from_str("gregory").unwrap();
+        // (date, US week, US year of week, ISO week, ISO year of week)
+        let cases = [
+            ((2020, 12, 31), 1, 2021, 53, 2020),
+            ((2021, 1, 3), 2, 2021, 53, 2020),
+            ((2022, 1, 1), 1, 2022, 52, 2021),
+            ((2022, 12, 31), 53, 2022, 52, 2022),
+            ((2023, 1, 1), 1, 2023, 52, 2022),
+            ((2024, 12, 30), 1, 2025, 1, 2025),
+        ];
+        for ((year, month, day), us_week, us_year, iso_week, iso_year) in cases {
+            let date = IsoDate::new_unchecked(year, month, day);
+            assert_eq!(gregorian.week_of_year_with_rules(&date, us), Some(us_week));
+            assert_eq!(gregorian.year_of_week_with_rules(&date, us), Some(us_year));
+            assert_eq!(gregorian.week_of_year(&date), Some(iso_week));
+            assert_eq!(gregorian.year_of_week(&date), Some(iso_year));
+        }
+    }
+
+    #[test]
+    fn week_of_year_non_iso() {
+        // Hebrew year 5784 starts on Saturday 2023-09-16, so its first week starts on
+        // Monday 2023-09-18 and the weekend belongs to the last week of 5783.
+        let hebrew = Calendar::from_str("hebrew").unwrap();
+        let rosh_hashanah = IsoDate::new_unchecked(2023, 9, 16);
+        assert_eq!(hebrew.week_of_year(&rosh_hashanah), Some(51));
+        assert_eq!(hebrew.year_of_week(&rosh_hashanah), Some(5783));
+        let monday = IsoDate::new_unchecked(2023, 9, 18);
+        assert_eq!(hebrew.week_of_year(&monday), Some(1));
+        assert_eq!(hebrew.year_of_week(&monday), Some(5784));
+
+        // Persian year 1403 starts on Wednesday 2024-03-20, so its first week
+        // starts on Monday 2024-03-18, at the end of 1402.
+        let persian = Calendar::from_str("persian").unwrap();
+        let monday = IsoDate::new_unchecked(2024, 3, 18);
+        assert_eq!(persian.week_of_year(&monday), Some(1));
+        assert_eq!(persian.year_of_week(&monday), Some(1403));
+        let sunday = IsoDate::new_unchecked(2024, 3, 17);
+        assert_eq!(persian.week_of_year(&sunday), Some(52));
+        assert_eq!(persian.year_of_week(&sunday), Some(1402));
+    }
+
+    #[test]
+    fn calendar_eras() {
+        use super::EraDirection;
+        use crate::{fields::CalendarFields, iso::IsoDate, options::ArithmeticOverflow};
+        use alloc::vec::Vec;
+        use icu_calendar::AnyCalendarKind;
+        use tinystr::{tinystr, TinyAsciiStr};
+
+        let codes = |calendar: &Calendar| {
+            calendar
+                .eras()
+                .iter()
+                .map(|era| era.code())
+                .collect::<Vec<_>>()
+        };
+        assert!(Calendar::ISO.eras().is_empty());
+        assert_eq!(codes(&Calendar::JULIAN), ["bce", "ce"]);
+
+        let gregory = Calendar::new(AnyCalendarKind::Gregorian);
+        let eras = gregory.eras();
+        assert_eq!(eras[0].code(), "bce");
+        assert_eq!(eras[0].aliases(), [tinystr!(19, "bc")]);
+        assert_eq!(eras[0].direction(), EraDirection::Backward);
+        assert_eq!(eras[0].arithmetic_year(1), 0);
+        assert_eq!(eras[0].start(), None);
+        assert_eq!(eras[0].end(), Some(IsoDate::new_unchecked(0, 12, 31)));
+        assert_eq!(eras[1].code(), "ce");
+        assert_eq!(eras[1].direction(), EraDirection::Forward);
+        assert_eq!(eras[1].min_era_year(), Some(1));
+        assert_eq!(eras[1].max_era_year(), None);
+        assert_eq!(eras[1].start(), Some(IsoDate::new_unchecked(1, 1, 1)));
+        assert_eq!(eras[1].end(), None);
+
+        let japanese = Calendar::new(AnyCalendarKind::Japanese);
+        assert_eq!(
+            codes(&japanese),
+            ["bce", "ce", "meiji", "taisho", "showa", "heisei", "reiwa"]
+        );
+        let eras = japanese.eras();
+        assert_eq!(eras[1].end(), Some(IsoDate::new_unchecked(1868, 10, 22)));
+        let heisei = &eras[5];
+        assert_eq!(heisei.start(), Some(IsoDate::new_unchecked(1989, 1, 8)));
+        assert_eq!(heisei.end(), Some(IsoDate::new_unchecked(2019, 4, 30)));
+        assert_eq!(heisei.max_era_year(), Some(31));
+        assert_eq!(heisei.arithmetic_year(31), 2019);
+
+        let ethiopic = Calendar::new(AnyCalendarKind::Ethiopian);
+        let eras = ethiopic.eras();
+        assert_eq!(codes(&ethiopic), ["aa", "am"]);
+        assert_eq!(eras[0].aliases(), [tinystr!(19, "mundi")]);
+        assert_eq!(eras[0].start(), None);
+        assert_eq!(eras[0].end(), Some(IsoDate::new_unchecked(8, 8, 26)));
+        assert_eq!(eras[1].start(), Some(IsoDate::new_unchecked(8, 8, 27)));
+
+        // Era input is validated against the era metadata.
+        let date = |calendar: &Calendar, era: &str, era_year: i32| {
+            calendar
+                .date_from_fields(
+                    CalendarFields::new()
+                        .with_era(Some(TinyAsciiStr::from_str(era).unwrap()))
+                        .with_era_year(Some(era_year))
+                        .with_month(1)
+                        .with_day(31),
+                    ArithmeticOverflow::Reject,
+                )
+                .map_err(|e| e.into_message())
+        };
+        assert_eq!(
+            date(&gregory, "meiji", 1).unwrap_err(),
+            "Invalid era 'meiji' for the gregory calendar; expected one of: bce, bc, ce, ad."
+        );
+        assert_eq!(
+            date(&japanese, "heisei", 32).unwrap_err(),
+            "Era year 32 is not valid for the era heisei; expected between 1 and 31."
+        );
+        assert_eq!(
+            date(&ethiopic, "aa", 5501).unwrap_err(),
+            "Era year 5501 is not valid for the era aa; expected at most 5500."
+        );
+        assert_eq!(
+            date(&Calendar::ISO, "ce", 1).unwrap_err(),
+            "The iso8601 calendar does not support eras."
+        );
+        assert_eq!(date(&japanese, "heisei", 31).unwrap().year(), 2019);
+        assert_eq!(
+            gregory
+                .date_from_fields(
+                    CalendarFields::new()
+                        .with_era(Some(tinystr!(19, "ce")))
+                        .with_month(1)
+                        .with_day(1),
+                    ArithmeticOverflow::Reject,
+                )
+                .unwrap_err()
+                .into_message(),
+            "eraYear is required when era is provided."
+        );
+    }
+
+    #[test]
+    fn month_codes_in_year() {
+        use icu_calendar::AnyCalendarKind;
+
+        let months = |kind, iso: IsoDate| {
+            Calendar::new(kind)
+                .month_codes_in_year(&iso)
+                .iter()
+                .map(|m| (m.month(), m.month_code().as_str().into(), m.days_in_month()))
+                .collect::<alloc::vec::Vec<(u8, alloc::string::String, u16)>>()
+        };
+
+        // Hebrew year 5784 is a leap year with Adar I (M05L).
+        let hebrew = months(AnyCalendarKind::Hebrew, IsoDate::new_unchecked(2024, 3, 1));
+        assert_eq!(hebrew.len(), 13);
+        assert_eq!(hebrew[0], (1, "M01".into(), 30));
+        assert_eq!(hebrew[5], (6, "M05L".into(), 30));
+        assert_eq!(hebrew[6], (7, "M06".into(), 29));
+        assert_eq!(hebrew[12], (13, "M12".into(), 29));
+        let days: u16 = hebrew.iter().map(|m| m.2).sum();
+        assert_eq!(days, 383);
+
+        // Chinese year 2023 has a leap second month.
+        let chinese = Calendar::new(AnyCalendarKind::Chinese)
+            .month_codes_in_year(&IsoDate::new_unchecked(2023, 12, 31));
+        assert_eq!(chinese.len(), 13);
+        assert_eq!(chinese[0].start(), IsoDate::new_unchecked(2023, 1, 22));
+        assert_eq!(chinese[2].month_code().as_str(), "M02L");
+        assert_eq!(chinese[2].month(), 3);
+
+        let iso = Calendar::ISO.month_codes_in_year(&IsoDate::new_unchecked(2024, 7, 4));
+        assert_eq!(iso.len(), 12);
+        assert_eq!(iso[1].days_in_month(), 29);
+        assert_eq!(iso[11].start(), IsoDate::new_unchecked(2024, 12, 1));
+    }
+
+    #[test]
+    fn julian_calendar() {
+        use crate::{
+            fields::CalendarFields,
+            options::{ArithmeticOverflow, DifferenceSettings, DisplayCalendar},
+            partial::PartialDate,
+            Duration, PlainDate,
+        };
+        use tinystr::tinystr;
+
+        let julian = Calendar::from_str("julian").unwrap();
+        assert!(julian.is_julian());
+        assert_eq!(julian.any_calendar_kind(), None);
+        assert_eq!(julian.kind(), icu_calendar::AnyCalendarKind::Iso);
+        assert_eq!(julian.identifier(), "julian");
+
+        // 2024-01-14 is January 1, 2024 in the Julian calendar.
+        let date = PlainDate::from_utf8(b"2024-01-14[u-ca=julian]").unwrap();
+        assert_eq!(date.calendar(), &julian);
+        assert_eq!((date.year(), date.month(), date.day()), (2024, 1, 1));
+        assert_eq!(date.month_code().as_str(), "M01");
+        assert_eq!(date.era(), Some(tinystr!(16, "ce")));
+        assert_eq!(date.era_year(), Some(2024));
+        assert!(date.in_leap_year());
+        assert_eq!(
+            date.to_ixdtf_string(DisplayCalendar::Auto),
+            "2024-01-14[u-ca=julian]"
+        );
+
+        // 1900 is a leap year in the Julian calendar, but not in the ISO calendar.
+        let leap_day = PlainDate::from_partial(
+            PartialDate {
+                calendar_fields: CalendarFields::new()
+                    .with_era(Some(tinystr!(19, "ad")))
+                    .with_era_year(Some(1900))
+                    .with_month(2)
+                    .with_day(29),
+                calendar: julian.clone(),
+            },
+            Some(ArithmeticOverflow::Reject),
+        )
+        .unwrap();
+        assert_eq!(
+            leap_day.to_ixdtf_string(DisplayCalendar::Never),
+            "1900-03-13"
+        );
+        assert_eq!(leap_day.days_in_month(), 29);
+
+        let bc = PlainDate::from_partial(
+            PartialDate {
+                calendar_fields: CalendarFields::new()
+                    .with_era(Some(tinystr!(19, "bc")))
+                    .with_era_year(Some(1))
+                    .with_month(1)
+                    .with_day(1),
+                calendar: julian.clone(),
+            },
+            None,
+        )
+        .unwrap();
+        assert_eq!(bc.year(), 0);
+        assert_eq!(bc.era(), Some(tinystr!(16, "bce")));
+
+        // Adding one month to January 31 constrains to the Julian leap day.
+        let jan_31 = PlainDate::from_utf8(b"1900-02-12[u-ca=julian]").unwrap();
+        assert_eq!((jan_31.month(), jan_31.day()), (1, 31));
+        let added = jan_31
+            .add(&Duration::from_str("P1M").unwrap(), None)
+            .unwrap();
+        assert_eq!((added.month(), added.day()), (2, 29));
+        assert!(jan_31
+            .add(
+                &Duration::from_str("P1M").unwrap(),
+                Some(ArithmeticOverflow::Reject)
+            )
+            .is_err());
+
+        let settings = DifferenceSettings {
+            largest_unit: Some(Unit::Year),
+            ..Default::default()
+        };
+        let until = jan_31.until(&added, settings).unwrap();
+        assert_eq!((until.years(), until.months(), until.days()), (0, 0, 29));
+        let until = date.until(&jan_31, settings).unwrap();
+        assert_eq!(
+            (until.years(), until.months(), until.days()),
+            (-123, -11, -1)
+        );
+    }
 
     #[test]
     fn calendar_from_str_is_case_insensitive() {
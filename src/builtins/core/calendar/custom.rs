@@ -0,0 +1,388 @@
+//! User-defined calendars.
+
+use core::fmt::Debug;
+
+use tinystr::TinyAsciiStr;
+
+use crate::{
+    builtins::core::duration::DateDuration,
+    iso::IsoDate,
+    options::{ArithmeticOverflow, Unit},
+    TemporalResult,
+};
+
+use super::{CalendarFields, MonthCode};
+
+/// A calendar implemented outside of `temporal_rs`.
+///
+/// A `CustomCalendar` maps between ISO dates and its own calendar fields. Dates
+/// are always stored as ISO dates, so every method receives and returns an
+/// [`IsoDate`]. Implementations create dates with [`IsoDate::new`].
+///
+/// Implementations are wrapped in a [`Calendar`][super::Calendar] with
+/// [`Calendar::try_new_custom`][super::Calendar::try_new_custom].
+pub trait CustomCalendar: Debug + Send + Sync {
+    /// Returns the identifier of the calendar, used as its `u-ca` value.
+    ///
+    /// The identifier must be a valid Unicode extension value, and it must not
+    /// be the identifier of a built-in calendar.
+    fn identifier(&self) -> &str;
+
+    /// Returns whether the calendar uses the `era` and `eraYear` fields.
+    fn has_eras(&self) -> bool {
+        false
+    }
+
+    /// `CalendarDateFromFields`
+    fn date_from_fields(
+        &self,
+        fields: &CalendarFields,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<IsoDate>;
+
+    /// `CalendarDateAdd`
+    fn date_add(
+        &self,
+        date: &IsoDate,
+        duration: &DateDuration,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<IsoDate>;
+
+    /// `CalendarDateUntil`
+    fn date_until(
+        &self,
+        one: &IsoDate,
+        two: &IsoDate,
+        largest_unit: Unit,
+    ) -> TemporalResult<DateDuration>;
+
+    /// `CalendarEra`
+    fn era(&self, _iso_date: &IsoDate) -> Option<TinyAsciiStr<16>> {
+        None
+    }
+
+    /// `CalendarEraYear`
+    fn era_year(&self, _iso_date: &IsoDate) -> Option<i32> {
+        None
+    }
+
+    /// `CalendarArithmeticYear`
+    fn year(&self, iso_date: &IsoDate) -> i32;
+
+    /// `CalendarMonth`
+    fn month(&self, iso_date: &IsoDate) -> u8;
+
+    /// `CalendarMonthCode`
+    fn month_code(&self, iso_date: &IsoDate) -> MonthCode;
+
+    /// `CalendarDay`
+    fn day(&self, iso_date: &IsoDate) -> u8;
+
+    /// `CalendarDayOfYear`
+    fn day_of_year(&self, iso_date: &IsoDate) -> u16;
+
+    /// `CalendarDaysInMonth`
+    fn days_in_month(&self, iso_date: &IsoDate) -> u16;
+
+    /// `CalendarDaysInYear`
+    fn days_in_year(&self, iso_date: &IsoDate) -> u16;
+
+    /// `CalendarMonthsInYear`
+    fn months_in_year(&self, iso_date: &IsoDate) -> u16;
+
+    /// `CalendarInLeapYear`
+    fn in_leap_year(&self, iso_date: &IsoDate) -> bool;
+}
+
+/// Resolves the identifiers of custom calendars when parsing calendar
+/// annotations.
+///
+/// A slice of custom calendars resolves the calendar whose identifier matches
+/// ASCII case-insensitively.
+pub trait CustomCalendarResolver {
+    /// Returns the custom calendar with the identifier `identifier`, if any.
+    fn resolve(&self, identifier: &[u8]) -> Option<&'static dyn CustomCalendar>;
+}
+
+impl CustomCalendarResolver for [&'static dyn CustomCalendar] {
+    fn resolve(&self, identifier: &[u8]) -> Option<&'static dyn CustomCalendar> {
+        self.iter()
+            .find(|c| c.identifier().as_bytes().eq_ignore_ascii_case(identifier))
+            .copied()
+    }
+}
+
+/// A resolver that does not recognize any custom calendar.
+pub(crate) const NO_CUSTOM_CALENDARS: &[&'static dyn CustomCalendar] = &[];
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use super::CustomCalendar;
+    use crate::{
+        builtins::core::{calendar::month_to_month_code, duration::DateDuration},
+        fields::CalendarFields,
+        iso::IsoDate,
+        options::{ArithmeticOverflow, DifferenceSettings, DisplayCalendar, Unit},
+        partial::PartialDate,
+        Calendar, Duration, MonthCode, PlainDate, TemporalError, TemporalResult,
+    };
+
+    /// A fiscal calendar whose years start in October of the previous ISO year.
+    #[derive(Debug)]
+    struct Fiscal;
+
+    impl Fiscal {
+        fn fields(iso_date: &IsoDate) -> (i32, u8) {
+            let year = iso_date.year + i32::from(iso_date.month >= 10);
+            (year, (iso_date.month + 2) % 12 + 1)
+        }
+
+        fn iso_year_month(year: i32, month: u8) -> (i32, u8) {
+            let iso_month = (month + 8) % 12 + 1;
+            (year - i32::from(iso_month >= 10), iso_month)
+        }
+    }
+
+    impl CustomCalendar for Fiscal {
+        fn identifier(&self) -> &str {
+            "fiscal"
+        }
+
+        fn date_from_fields(
+            &self,
+            fields: &CalendarFields,
+            overflow: ArithmeticOverflow,
+        ) -> TemporalResult<IsoDate> {
+            let missing = || TemporalError::r#type().with_message("Missing fiscal field.");
+            let year = fields.year.ok_or_else(missing)?;
+            let month = match (fields.month, fields.month_code) {
+                (Some(month), _) => month,
+                (None, Some(month_code)) => month_code.to_month_integer(),
+                (None, None) => return Err(missing()),
+            };
+            if !(1..=12).contains(&month) {
+                return Err(TemporalError::range().with_message("Invalid fiscal month."));
+            }
+            let (year, month) = Self::iso_year_month(year, month);
+            IsoDate::new(year, month, fields.day.ok_or_else(missing)?, overflow)
+        }
+
+        fn date_add(
+            &self,
+            date: &IsoDate,
+            duration: &DateDuration,
+            overflow: ArithmeticOverflow,
+        ) -> TemporalResult<IsoDate> {
+            // Fiscal months are aligned with ISO months.
+            date.add_date_duration(duration, overflow)
+        }
+
+        fn date_until(
+            &self,
+            one: &IsoDate,
+            two: &IsoDate,
+            largest_unit: Unit,
+        ) -> TemporalResult<DateDuration> {
+            one.diff_iso_date(two, largest_unit)
+        }
+
+        fn year(&self, iso_date: &IsoDate) -> i32 {
+            Self::fields(iso_date).0
+        }
+
+        fn month(&self, iso_date: &IsoDate) -> u8 {
+            Self::fields(iso_date).1
+        }
+
+        fn month_code(&self, iso_date: &IsoDate) -> MonthCode {
+            month_to_month_code(self.month(iso_date)).unwrap()
+        }
+
+        fn day(&self, iso_date: &IsoDate) -> u8 {
+            iso_date.day
+        }
+
+        fn day_of_year(&self, iso_date: &IsoDate) -> u16 {
+            let start = IsoDate::new_unchecked(self.year(iso_date) - 1, 10, 1);
+            (iso_date.to_epoch_days() - start.to_epoch_days() + 1) as u16
+        }
+
+        fn days_in_month(&self, iso_date: &IsoDate) -> u16 {
+            iso_date.to_icu4x().days_in_month().into()
+        }
+
+        fn days_in_year(&self, iso_date: &IsoDate) -> u16 {
+            if self.in_leap_year(iso_date) {
+                366
+            } else {
+                365
+            }
+        }
+
+        fn months_in_year(&self, _iso_date: &IsoDate) -> u16 {
+            12
+        }
+
+        fn in_leap_year(&self, iso_date: &IsoDate) -> bool {
+            let year = self.year(iso_date);
+            year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+        }
+    }
+
+    #[derive(Debug)]
+    struct Named(&'static str);
+
+    impl CustomCalendar for Named {
+        fn identifier(&self) -> &str {
+            self.0
+        }
+
+        fn date_from_fields(
+            &self,
+            _: &CalendarFields,
+            _: ArithmeticOverflow,
+        ) -> TemporalResult<IsoDate> {
+            Err(TemporalError::general("unsupported"))
+        }
+
+        fn date_add(
+            &self,
+            _: &IsoDate,
+            _: &DateDuration,
+            _: ArithmeticOverflow,
+        ) -> TemporalResult<IsoDate> {
+            Err(TemporalError::general("unsupported"))
+        }
+
+        fn date_until(&self, _: &IsoDate, _: &IsoDate, _: Unit) -> TemporalResult<DateDuration> {
+            Err(TemporalError::general("unsupported"))
+        }
+
+        fn year(&self, iso_date: &IsoDate) -> i32 {
+            iso_date.year
+        }
+
+        fn month(&self, iso_date: &IsoDate) -> u8 {
+            iso_date.month
+        }
+
+        fn month_code(&self, iso_date: &IsoDate) -> MonthCode {
+            month_to_month_code(iso_date.month).unwrap()
+        }
+
+        fn day(&self, iso_date: &IsoDate) -> u8 {
+            iso_date.day
+        }
+
+        fn day_of_year(&self, _: &IsoDate) -> u16 {
+            1
+        }
+
+        fn days_in_month(&self, _: &IsoDate) -> u16 {
+            31
+        }
+
+        fn days_in_year(&self, _: &IsoDate) -> u16 {
+            365
+        }
+
+        fn months_in_year(&self, _: &IsoDate) -> u16 {
+            12
+        }
+
+        fn in_leap_year(&self, _: &IsoDate) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn custom_calendar_identifier() {
+        assert!(Calendar::try_new_custom(&Named("gregory")).is_err());
+        assert!(Calendar::try_new_custom(&Named("Julian")).is_err());
+        assert!(Calendar::try_new_custom(&Named("x")).is_err());
+        assert!(Calendar::try_new_custom(&Named("not a calendar")).is_err());
+        let calendar = Calendar::try_new_custom(&Named("academic")).unwrap();
+        assert_eq!(calendar.identifier(), "academic");
+        assert_eq!(calendar.any_calendar_kind(), None);
+        // Custom calendars are only recognized when parsing with a resolver.
+        assert!(Calendar::from_str("academic").is_err());
+        let calendars: &[&'static dyn CustomCalendar] = &[&Named("academic")];
+        assert_eq!(
+            Calendar::try_from_utf8_with_resolver(b"ACADEMIC", calendars).unwrap(),
+            calendar
+        );
+        assert!(Calendar::try_from_utf8_with_resolver(b"fiscal", calendars).is_err());
+        assert_eq!(
+            Calendar::try_from_utf8_with_resolver(b"iso8601", calendars).unwrap(),
+            Calendar::ISO
+        );
+    }
+
+    #[test]
+    fn custom_calendar_date() {
+        let calendars: &[&'static dyn CustomCalendar] = &[&Fiscal];
+        let fiscal = Calendar::try_new_custom(&Fiscal).unwrap();
+        assert!(fiscal.as_custom().is_some());
+        assert_eq!(
+            Calendar::try_from_utf8_with_resolver(b"FISCAL", calendars).unwrap(),
+            fiscal
+        );
+
+        let date = PlainDate::from_partial(
+            PartialDate {
+                calendar_fields: CalendarFields::new()
+                    .with_year(2025)
+                    .with_month(1)
+                    .with_day(15),
+                calendar: fiscal.clone(),
+            },
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            date.to_ixdtf_string(DisplayCalendar::Auto),
+            "2024-10-15[u-ca=fiscal]"
+        );
+        assert_eq!((date.year(), date.month(), date.day()), (2025, 1, 15));
+        assert_eq!(date.month_code().as_str(), "M01");
+        assert_eq!(date.day_of_year(), 15);
+        assert_eq!(date.era(), None);
+
+        assert!(PlainDate::from_str("2024-10-15[u-ca=fiscal]").is_err());
+        let parsed =
+            PlainDate::from_utf8_with_resolver(b"2024-10-15[u-ca=fiscal]", calendars).unwrap();
+        assert_eq!(parsed, date);
+        assert_eq!(parsed.calendar(), &fiscal);
+        let utf16: alloc::vec::Vec<u16> = "2024-10-15[u-ca=fiscal]".encode_utf16().collect();
+        assert_eq!(
+            PlainDate::from_utf16_with_resolver(&utf16, calendars).unwrap(),
+            date
+        );
+
+        let with_month = date
+            .with(CalendarFields::new().with_month(12), None)
+            .unwrap();
+        assert_eq!(
+            with_month.to_ixdtf_string(DisplayCalendar::Never),
+            "2025-09-15"
+        );
+
+        let added = date
+            .add(&Duration::from_str("P1Y3M").unwrap(), None)
+            .unwrap();
+        assert_eq!((added.year(), added.month(), added.day()), (2026, 4, 15));
+
+        let settings = DifferenceSettings {
+            largest_unit: Some(Unit::Year),
+            ..Default::default()
+        };
+        let until = date.until(&added, settings).unwrap();
+        assert_eq!((until.years(), until.months(), until.days()), (1, 3, 0));
+
+        let year_month = added.to_plain_year_month().unwrap();
+        assert_eq!(year_month.year(), 2026);
+        assert_eq!(year_month.month(), 4);
+    }
+}
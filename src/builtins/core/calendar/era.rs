@@ -11,18 +11,24 @@
 
 use core::ops::RangeInclusive;
 
-#[cfg(test)]
 use icu_calendar::AnyCalendarKind;
 use tinystr::{tinystr, TinyAsciiStr};
 
+use crate::iso::IsoDate;
+
 /// Relevant Era info.
+#[derive(Debug, PartialEq, Eq)]
 pub(crate) struct EraInfo {
     pub(crate) name: TinyAsciiStr<16>,
+    pub(crate) aliases: &'static [TinyAsciiStr<19>],
     pub(crate) range: RangeInclusive<i32>,
     pub(crate) arithmetic_year: ArithmeticYear,
+    /// The ISO start date of eras that do not start at the beginning of a year.
+    pub(crate) start: Option<IsoDate>,
 }
 
 /// The way to map an era to an extended year
+#[derive(Debug, PartialEq, Eq)]
 pub(crate) enum ArithmeticYear {
     // This era is the default era, 1 ERA = 1 ArithmeticYear
     DefaultEra,
@@ -40,6 +46,88 @@ impl EraInfo {
             ArithmeticYear::Inverse => 1 - era_year,
         }
     }
+
+    /// Returns whether `alias` is the name or an alias of this era.
+    pub(crate) fn matches(&self, alias: &TinyAsciiStr<19>) -> bool {
+        self.name.as_str() == alias.as_str() || self.aliases.contains(alias)
+    }
+}
+
+/// The direction in which the years of an era are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EraDirection {
+    /// Era years increase forward in time, like `ce`.
+    Forward,
+    /// Era years increase backward in time, like `bce`.
+    Backward,
+}
+
+/// The metadata of an era of a [`Calendar`][super::Calendar].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarEra {
+    info: &'static EraInfo,
+    start: Option<IsoDate>,
+    end: Option<IsoDate>,
+}
+
+impl CalendarEra {
+    pub(crate) fn new(
+        info: &'static EraInfo,
+        start: Option<IsoDate>,
+        end: Option<IsoDate>,
+    ) -> Self {
+        Self { info, start, end }
+    }
+
+    /// Returns the era code, as returned by `Calendar::era`.
+    pub fn code(&self) -> &'static str {
+        self.info.name.as_str()
+    }
+
+    /// Returns the alternative codes accepted for this era.
+    pub fn aliases(&self) -> &'static [TinyAsciiStr<19>] {
+        self.info.aliases
+    }
+
+    /// Returns the direction in which the years of this era are counted.
+    pub fn direction(&self) -> EraDirection {
+        match self.info.arithmetic_year {
+            ArithmeticYear::Inverse => EraDirection::Backward,
+            _ => EraDirection::Forward,
+        }
+    }
+
+    /// Returns the smallest valid era year, or `None` if it is unbounded.
+    pub fn min_era_year(&self) -> Option<i32> {
+        Some(*self.info.range.start()).filter(|y| *y != i32::MIN)
+    }
+
+    /// Returns the largest valid era year, or `None` if it is unbounded.
+    pub fn max_era_year(&self) -> Option<i32> {
+        Some(*self.info.range.end()).filter(|y| *y != i32::MAX)
+    }
+
+    /// Returns whether `era_year` is a valid year of this era.
+    pub fn contains_era_year(&self, era_year: i32) -> bool {
+        self.info.range.contains(&era_year)
+    }
+
+    /// Returns the arithmetic year of a year of this era.
+    pub fn arithmetic_year(&self, era_year: i32) -> i32 {
+        self.info.arithmetic_year_for(era_year)
+    }
+
+    /// Returns the first ISO date of this era, or `None` if it is unbounded
+    /// or outside the representable range.
+    pub fn start(&self) -> Option<IsoDate> {
+        self.start
+    }
+
+    /// Returns the last ISO date of this era, or `None` if it is unbounded
+    /// or outside the representable range.
+    pub fn end(&self) -> Option<IsoDate> {
+        self.end
+    }
 }
 
 macro_rules! era_identifier {
@@ -49,111 +137,157 @@ macro_rules! era_identifier {
 }
 
 macro_rules! valid_era {
-    ($name:literal, $range:expr, $ext:expr ) => {
+    ($name:literal, $range:expr, $ext:expr, aliases: $aliases:expr, start: $start:expr) => {
         EraInfo {
             name: tinystr!(16, $name),
+            aliases: $aliases,
             range: $range,
             arithmetic_year: $ext,
+            start: $start,
         }
     };
+    ($name:literal, $range:expr, $ext:expr, aliases: $aliases:expr) => {
+        valid_era!($name, $range, $ext, aliases: $aliases, start: None)
+    };
+    ($name:literal, $range:expr, $ext:expr, start: $start:expr) => {
+        valid_era!($name, $range, $ext, aliases: &[], start: Some($start))
+    };
+    ($name:literal, $range:expr, $ext:expr) => {
+        valid_era!($name, $range, $ext, aliases: &[])
+    };
     ($name:literal, $range:expr ) => {
         valid_era!($name, $range, ArithmeticYear::DefaultEra)
     };
 }
 
-pub(crate) const ETHIOPIC_ERA_IDENTIFIERS: [TinyAsciiStr<19>; 2] =
-    [era_identifier!("am"), era_identifier!("incar")];
-
-pub(crate) const ETHIOPIC_ETHOPICAA_ERA_IDENTIFIERS: [TinyAsciiStr<19>; 2] =
-    [era_identifier!("aa"), era_identifier!("mundi")];
-
-pub(crate) const ETHIOAA_ERA_IDENTIFIERS: [TinyAsciiStr<19>; 2] =
-    [era_identifier!("aa"), era_identifier!("mundi")];
-
-pub(crate) const GREGORY_ERA_IDENTIFIERS: [TinyAsciiStr<19>; 2] =
-    [era_identifier!("ce"), era_identifier!("ad")];
-
-pub(crate) const GREGORY_INVERSE_ERA_IDENTIFIERS: [TinyAsciiStr<19>; 2] =
-    [era_identifier!("bc"), era_identifier!("bce")];
-pub(crate) const JAPANESE_ERA_IDENTIFIERS: [TinyAsciiStr<19>; 2] =
-    [era_identifier!("ce"), era_identifier!("ad")];
-
-pub(crate) const JAPANESE_INVERSE_ERA_IDENTIFIERS: [TinyAsciiStr<19>; 2] =
-    [era_identifier!("bc"), era_identifier!("bce")];
+const AD_ALIASES: &[TinyAsciiStr<19>] = &[era_identifier!("ad")];
+const BC_ALIASES: &[TinyAsciiStr<19>] = &[era_identifier!("bc")];
+const INCAR_ALIASES: &[TinyAsciiStr<19>] = &[era_identifier!("incar")];
+const MUNDI_ALIASES: &[TinyAsciiStr<19>] = &[era_identifier!("mundi")];
 
 // NOTE: The below currently might not align 100% with ICU4X.
 // TODO: Update to align with ICU4X depending on any Era updates.
 pub(crate) const BUDDHIST_ERA: EraInfo = valid_era!("be", i32::MIN..=i32::MAX);
 pub(crate) const COPTIC_ERA: EraInfo = valid_era!("am", 1..=i32::MAX);
-pub(crate) const ETHIOPIC_ERA: EraInfo = valid_era!("am", 1..=i32::MAX);
-pub(crate) const ETHIOPIC_ETHIOAA_ERA: EraInfo =
-    valid_era!("aa", i32::MIN..=5500, ArithmeticYear::Offset(-5499));
-pub(crate) const ETHIOAA_ERA: EraInfo = valid_era!("aa", i32::MIN..=i32::MAX);
-pub(crate) const GREGORY_ERA: EraInfo = valid_era!("ce", 1..=i32::MAX);
+pub(crate) const ETHIOPIC_ERA: EraInfo = valid_era!(
+    "am",
+    1..=i32::MAX,
+    ArithmeticYear::DefaultEra,
+    aliases: INCAR_ALIASES
+);
+pub(crate) const ETHIOPIC_ETHIOAA_ERA: EraInfo = valid_era!(
+    "aa",
+    i32::MIN..=5500,
+    ArithmeticYear::Offset(-5499),
+    aliases: MUNDI_ALIASES
+);
+pub(crate) const ETHIOAA_ERA: EraInfo = valid_era!(
+    "aa",
+    i32::MIN..=i32::MAX,
+    ArithmeticYear::DefaultEra,
+    aliases: MUNDI_ALIASES
+);
+pub(crate) const GREGORY_ERA: EraInfo = valid_era!(
+    "ce",
+    1..=i32::MAX,
+    ArithmeticYear::DefaultEra,
+    aliases: AD_ALIASES
+);
 pub(crate) const GREGORY_INVERSE_ERA: EraInfo =
-    valid_era!("bce", 1..=i32::MAX, ArithmeticYear::Inverse);
+    valid_era!("bce", 1..=i32::MAX, ArithmeticYear::Inverse, aliases: BC_ALIASES);
 pub(crate) const HEBREW_ERA: EraInfo = valid_era!("am", i32::MIN..=i32::MAX);
 pub(crate) const INDIAN_ERA: EraInfo = valid_era!("shaka", i32::MIN..=i32::MAX);
 pub(crate) const ISLAMIC_ERA: EraInfo = valid_era!("ah", i32::MIN..=i32::MAX);
 pub(crate) const ISLAMIC_INVERSE_ERA: EraInfo =
     valid_era!("bh", i32::MIN..=i32::MAX, ArithmeticYear::Inverse);
-pub(crate) const HEISEI_ERA: EraInfo = valid_era!("heisei", 1..=31, ArithmeticYear::Offset(1989));
-pub(crate) const JAPANESE_ERA: EraInfo = valid_era!("ce", 1..=1868);
+pub(crate) const HEISEI_ERA: EraInfo = valid_era!(
+    "heisei",
+    1..=31,
+    ArithmeticYear::Offset(1989),
+    start: IsoDate::new_unchecked(1989, 1, 8)
+);
+pub(crate) const JAPANESE_ERA: EraInfo = valid_era!(
+    "ce",
+    1..=1868,
+    ArithmeticYear::DefaultEra,
+    aliases: AD_ALIASES
+);
 pub(crate) const JAPANESE_INVERSE_ERA: EraInfo =
-    valid_era!("bce", 1..=i32::MAX, ArithmeticYear::Inverse);
-pub(crate) const MEIJI_ERA: EraInfo = valid_era!("meiji", 1..=45, ArithmeticYear::Offset(1868));
-pub(crate) const REIWA_ERA: EraInfo =
-    valid_era!("reiwa", 1..=i32::MAX, ArithmeticYear::Offset(2019));
-pub(crate) const SHOWA_ERA: EraInfo = valid_era!("showa", 1..=64, ArithmeticYear::Offset(1926));
-pub(crate) const TAISHO_ERA: EraInfo = valid_era!("taisho", 1..=45, ArithmeticYear::Offset(1912));
+    valid_era!("bce", 1..=i32::MAX, ArithmeticYear::Inverse, aliases: BC_ALIASES);
+pub(crate) const MEIJI_ERA: EraInfo = valid_era!(
+    "meiji",
+    1..=45,
+    ArithmeticYear::Offset(1868),
+    start: IsoDate::new_unchecked(1868, 10, 23)
+);
+pub(crate) const REIWA_ERA: EraInfo = valid_era!(
+    "reiwa",
+    1..=i32::MAX,
+    ArithmeticYear::Offset(2019),
+    start: IsoDate::new_unchecked(2019, 5, 1)
+);
+pub(crate) const SHOWA_ERA: EraInfo = valid_era!(
+    "showa",
+    1..=64,
+    ArithmeticYear::Offset(1926),
+    start: IsoDate::new_unchecked(1926, 12, 25)
+);
+pub(crate) const TAISHO_ERA: EraInfo = valid_era!(
+    "taisho",
+    1..=45,
+    ArithmeticYear::Offset(1912),
+    start: IsoDate::new_unchecked(1912, 7, 30)
+);
 pub(crate) const PERSIAN_ERA: EraInfo = valid_era!("ap", i32::MIN..=i32::MAX);
 pub(crate) const ROC_ERA: EraInfo = valid_era!("roc", 1..=i32::MAX);
 pub(crate) const ROC_INVERSE_ERA: EraInfo =
     valid_era!("broc", 1..=i32::MAX, ArithmeticYear::Inverse);
 
-#[cfg(test)]
+/// The eras of each calendar in chronological order.
+///
 /// https://tc39.es/proposal-intl-era-monthcode/#sec-temporal-calendarsupportsera
 pub(crate) const ALL_ALLOWED_ERAS: &[(AnyCalendarKind, &[EraInfo])] = &[
     (AnyCalendarKind::Buddhist, &[BUDDHIST_ERA]),
     (AnyCalendarKind::Coptic, &[COPTIC_ERA]),
     (
         AnyCalendarKind::Ethiopian,
-        &[ETHIOPIC_ERA, ETHIOPIC_ETHIOAA_ERA],
+        &[ETHIOPIC_ETHIOAA_ERA, ETHIOPIC_ERA],
     ),
     (AnyCalendarKind::EthiopianAmeteAlem, &[ETHIOAA_ERA]),
     (
         AnyCalendarKind::Gregorian,
-        &[GREGORY_ERA, GREGORY_INVERSE_ERA],
+        &[GREGORY_INVERSE_ERA, GREGORY_ERA],
     ),
     (AnyCalendarKind::Hebrew, &[HEBREW_ERA]),
     (AnyCalendarKind::Indian, &[INDIAN_ERA]),
     (
         AnyCalendarKind::HijriSimulatedMecca,
-        &[ISLAMIC_ERA, ISLAMIC_INVERSE_ERA],
+        &[ISLAMIC_INVERSE_ERA, ISLAMIC_ERA],
     ),
     (
         AnyCalendarKind::HijriTabularTypeIIFriday,
-        &[ISLAMIC_ERA, ISLAMIC_INVERSE_ERA],
+        &[ISLAMIC_INVERSE_ERA, ISLAMIC_ERA],
     ),
     (
         AnyCalendarKind::HijriTabularTypeIIThursday,
-        &[ISLAMIC_ERA, ISLAMIC_INVERSE_ERA],
+        &[ISLAMIC_INVERSE_ERA, ISLAMIC_ERA],
     ),
     (
         AnyCalendarKind::HijriUmmAlQura,
-        &[ISLAMIC_ERA, ISLAMIC_INVERSE_ERA],
+        &[ISLAMIC_INVERSE_ERA, ISLAMIC_ERA],
     ),
     (
         AnyCalendarKind::Japanese,
         &[
-            JAPANESE_ERA,
             JAPANESE_INVERSE_ERA,
+            JAPANESE_ERA,
             MEIJI_ERA,
-            REIWA_ERA,
-            SHOWA_ERA,
             TAISHO_ERA,
+            SHOWA_ERA,
+            HEISEI_ERA,
+            REIWA_ERA,
         ],
     ),
     (AnyCalendarKind::Persian, &[PERSIAN_ERA]),
-    (AnyCalendarKind::Roc, &[ROC_ERA, ROC_INVERSE_ERA]),
+    (AnyCalendarKind::Roc, &[ROC_INVERSE_ERA, ROC_ERA]),
 ];
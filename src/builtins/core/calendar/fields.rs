@@ -258,7 +258,7 @@ impl YearMonthCalendarFields {
 #[macro_export]
 macro_rules! impl_with_fallback_method {
     ($method_name:ident, $fields_type:ident, ( $(with_day: $day:ident)? ) $component_type:ty) => {
-        pub(crate) fn $method_name(&self, fallback: &$component_type, calendar: icu_calendar::AnyCalendarKind, overflow: ArithmeticOverflow) -> TemporalResult<Self> {
+        pub(crate) fn $method_name(&self, fallback: &$component_type, calendar: &Calendar, overflow: ArithmeticOverflow) -> TemporalResult<Self> {
             let keys_to_ignore = self.field_keys_to_ignore(calendar);
             let mut era = self.era;
 
@@ -325,13 +325,13 @@ macro_rules! impl_with_fallback_method {
 macro_rules! impl_field_keys_to_ignore {
     (( $(with_day: $day:ident)? )) => {
         /// <https://tc39.es/proposal-temporal/#sec-temporal-calendarfieldkeystoignore>
-        fn field_keys_to_ignore(&self, calendar: icu_calendar::AnyCalendarKind) -> $crate::builtins::core::calendar::fields::FieldKeysToIgnore {
+        fn field_keys_to_ignore(&self, calendar: &Calendar) -> $crate::builtins::core::calendar::fields::FieldKeysToIgnore {
             let mut keys = $crate::builtins::core::calendar::fields::FieldKeysToIgnore::default();
             // All calendars have months/month codes
             if self.month.is_some() || self.month_code.is_some() {
                 keys.month = true;
             }
-            if Calendar::calendar_has_eras(calendar) {
+            if calendar.has_eras() {
                 // We should clear years only if the calendar has eras
                 if self.year.is_some() || self.era_year.is_some() || self.era.is_some() {
                     keys.era = true;
@@ -342,7 +342,7 @@ macro_rules! impl_field_keys_to_ignore {
                 // List should contain era and era-year if keys contains day, month, or month-code
                 // (not only if it contains era, era-year, or year, as in the example above) because it's possible for
                 // changing the day or month to cause a conflict with the era.
-                if calendar == icu_calendar::AnyCalendarKind::Japanese {
+                if calendar.kind() == icu_calendar::AnyCalendarKind::Japanese {
                     if self.month.is_some() || self.month_code.is_some() {
                         keys.era = true;
                     }
@@ -0,0 +1,149 @@
+//! Date arithmetic for the proleptic Julian calendar.
+//!
+//! The Julian calendar has the same month structure as the ISO 8601 calendar, so
+//! its arithmetic follows the ISO 8601 algorithms on Julian year, month and day
+//! fields.
+
+use icu_calendar::{cal::Julian, Date as IcuDate};
+
+use crate::{
+    builtins::core::duration::DateDuration,
+    iso::IsoDate,
+    options::{ArithmeticOverflow, Unit},
+    TemporalError, TemporalResult,
+};
+
+/// A Julian year, month and day.
+type JulianDate = (i32, u8, u8);
+
+fn is_leap_year(year: i32) -> bool {
+    year.rem_euclid(4) == 0
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn balance_year_month(year: i64, month: i64) -> TemporalResult<(i32, u8)> {
+    let year = year + (month - 1).div_euclid(12);
+    let month = (month - 1).rem_euclid(12) + 1;
+    let year = i32::try_from(year)
+        .map_err(|_| TemporalError::range().with_message("Julian year is out of range."))?;
+    Ok((year, month as u8))
+}
+
+fn from_iso(iso_date: &IsoDate) -> JulianDate {
+    let date = IcuDate::new_from_iso(iso_date.to_icu4x(), Julian);
+    (
+        date.extended_year(),
+        date.month().ordinal,
+        date.day_of_month().0,
+    )
+}
+
+fn to_iso((year, month, day): JulianDate) -> TemporalResult<IsoDate> {
+    let iso = IcuDate::try_new_julian(year, month, day)
+        .map_err(|e| TemporalError::from_icu4x(e.into()))?
+        .to_iso();
+    Ok(IsoDate::new_unchecked(
+        iso.extended_year(),
+        iso.month().ordinal,
+        iso.day_of_month().0,
+    ))
+}
+
+/// Returns whether `this` is past `other` in the direction of `sign`.
+fn surpasses(this: JulianDate, other: JulianDate, sign: i8) -> bool {
+    this.cmp(&other) as i8 * sign == 1
+}
+
+/// `CalendarDateAdd` for the Julian calendar.
+pub(crate) fn date_add(
+    date: &IsoDate,
+    duration: &DateDuration,
+    overflow: ArithmeticOverflow,
+) -> TemporalResult<IsoDate> {
+    let (year, month, day) = from_iso(date);
+    let (year, month) = balance_year_month(
+        i64::from(year) + duration.years,
+        i64::from(month) + duration.months,
+    )?;
+    let days_in_month = days_in_month(year, month);
+    let day = match overflow {
+        ArithmeticOverflow::Constrain => day.min(days_in_month),
+        ArithmeticOverflow::Reject if day > days_in_month => {
+            return Err(TemporalError::range().with_message("day value is not in a valid range."))
+        }
+        ArithmeticOverflow::Reject => day,
+    };
+    let intermediate = to_iso((year, month, day))?;
+    IsoDate::try_balance(
+        intermediate.year,
+        intermediate.month.into(),
+        i64::from(intermediate.day) + duration.days + 7 * duration.weeks,
+    )
+}
+
+/// `CalendarDateUntil` for the Julian calendar.
+pub(crate) fn date_until(
+    one: &IsoDate,
+    two: &IsoDate,
+    largest_unit: Unit,
+) -> TemporalResult<DateDuration> {
+    let sign = -(one.cmp(two) as i8);
+    if sign == 0 {
+        return Ok(DateDuration::default());
+    }
+    let start = from_iso(one);
+    let end = from_iso(two);
+
+    let mut years = 0;
+    let mut months = 0;
+    if largest_unit == Unit::Year || largest_unit == Unit::Month {
+        let mut candidate_years = end.0 - start.0;
+        if candidate_years != 0 {
+            candidate_years -= i32::from(sign);
+        }
+        while !surpasses((start.0 + candidate_years, start.1, start.2), end, sign) {
+            years = candidate_years;
+            candidate_years += i32::from(sign);
+        }
+
+        let mut candidate_months = i32::from(sign);
+        let mut intermediate = balance_year_month(
+            i64::from(start.0 + years),
+            i64::from(start.1) + i64::from(candidate_months),
+        )?;
+        while !surpasses((intermediate.0, intermediate.1, start.2), end, sign) {
+            months = candidate_months;
+            candidate_months += i32::from(sign);
+            intermediate = balance_year_month(
+                i64::from(intermediate.0),
+                i64::from(intermediate.1) + i64::from(sign),
+            )?;
+        }
+
+        if largest_unit == Unit::Month {
+            months += years * 12;
+            years = 0;
+        }
+    }
+
+    let (year, month) = balance_year_month(
+        i64::from(start.0 + years),
+        i64::from(start.1) + i64::from(months),
+    )?;
+    let constrained = to_iso((year, month, start.2.min(days_in_month(year, month))))?;
+    let days = i64::from(two.to_epoch_days() - constrained.to_epoch_days());
+    let (weeks, days) = if largest_unit == Unit::Week {
+        (days / 7, days % 7)
+    } else {
+        (0, days)
+    };
+    DateDuration::new(years.into(), months.into(), weeks, days)
+}
@@ -6,7 +6,7 @@ use tinystr::TinyAsciiStr;
 use alloc::format;
 
 use crate::fields::CalendarFields;
-use crate::iso::{constrain_iso_day, is_valid_iso_day};
+use crate::iso::{constrain_iso_day, is_valid_iso_day, IsoDate};
 use crate::options::ArithmeticOverflow;
 use crate::Calendar;
 use crate::{TemporalError, TemporalResult};
@@ -158,11 +158,16 @@ impl EraYear {
             }
             (maybe_year, Some(era), Some(era_year)) => {
                 let Some(era_info) = calendar.get_era_info(&era) else {
-                    return Err(TemporalError::range().with_message("Invalid era provided."));
+                    return Err(calendar.invalid_era_error(&era));
                 };
                 if !era_info.range.contains(&era_year) {
+                    let expected = match (*era_info.range.start(), *era_info.range.end()) {
+                        (i32::MIN, max) => format!("at most {max}"),
+                        (min, i32::MAX) => format!("at least {min}"),
+                        (min, max) => format!("between {min} and {max}"),
+                    };
                     return Err(TemporalError::range().with_message(format!(
-                        "Year is not valid for the era {}",
+                        "Era year {era_year} is not valid for the era {}; expected {expected}.",
                         era_info.name.as_str()
                     )));
                 }
@@ -187,6 +192,14 @@ impl EraYear {
                 year,
                 arithmetic_year: year,
             }),
+            (_, Some(_), None) => {
+                Err(TemporalError::r#type()
+                    .with_message("eraYear is required when era is provided."))
+            }
+            (_, None, Some(_)) => {
+                Err(TemporalError::r#type()
+                    .with_message("era is required when eraYear is provided."))
+            }
             _ => Err(TemporalError::r#type()
                 .with_message("Required fields missing to determine an era and year.")),
         }
@@ -228,12 +241,10 @@ impl EraYear {
             }
         }
 
-        let kind = calendar.kind();
-
         // This behavior is required by tests, but is not yet specced.
         // https://github.com/tc39/proposal-intl-era-monthcode/issues/60
         let Some(month_code) = month_code else {
-            if kind == AnyCalendarKind::Iso {
+            if calendar.is_iso() {
                 return Ok(1972);
             } else {
                 return Err(TemporalError::r#type()
@@ -249,7 +260,19 @@ impl EraYear {
         // For example, Hebrew calendar leap month Adar I occurred in calendar years 5730 and 5733 (respectively overlapping
         // ISO 8601 February/March 1970 and February/March 1973), but did not occur between them, so the reference year for days of that month is 1970.
 
-        Ok(match calendar.kind() {
+        let Some(kind) = calendar.any_calendar_kind() else {
+            // Julian: 1972-12-31 is 1972-12-18, and 1972 is a leap year.
+            let month = month_code.to_month_integer();
+            return Ok(threshold_with_leap_day(
+                month,
+                day,
+                (1972, 12, 18),
+                (2, 29),
+                1972,
+            ));
+        };
+
+        Ok(match kind {
             AnyCalendarKind::Iso | AnyCalendarKind::Gregorian => 1972,
             // These calendars just wrap Gregorian with a different epoch
             AnyCalendarKind::Buddhist => 1972 + 543,
@@ -534,6 +557,37 @@ impl core::str::FromStr for MonthCode {
     }
 }
 
+/// A month of a calendar year, as returned by [`Calendar::month_codes_in_year`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CalendarMonth {
+    pub(crate) month: u8,
+    pub(crate) month_code: MonthCode,
+    pub(crate) days_in_month: u16,
+    pub(crate) start: IsoDate,
+}
+
+impl CalendarMonth {
+    /// Returns the ordinal month number within the year.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the month code.
+    pub fn month_code(&self) -> MonthCode {
+        self.month_code
+    }
+
+    /// Returns the number of days in the month.
+    pub fn days_in_month(&self) -> u16 {
+        self.days_in_month
+    }
+
+    /// Returns the ISO date of the first day of the month.
+    pub fn start(&self) -> IsoDate {
+        self.start
+    }
+}
+
 // NOTE: This is a greedy function, should handle differently for all calendars.
 #[inline]
 pub(crate) fn month_to_month_code(month: u8) -> TemporalResult<MonthCode> {
@@ -713,7 +767,11 @@ mod tests {
 
                 // Get the full partial date.
                 let full_partial = CalendarFields::default()
-                    .with_fallback_date(&plain_date, *cal, ArithmeticOverflow::Constrain)
+                    .with_fallback_date(
+                        &plain_date,
+                        &Calendar::new(*cal),
+                        ArithmeticOverflow::Constrain,
+                    )
                     .expect(&expect_str);
 
                 let era_year = super::EraYear::try_from_fields(
@@ -0,0 +1,122 @@
+//! Week numbering rules for calendar years.
+
+use icu_calendar::{types::Weekday, week::WeekInformation};
+use icu_locale::Locale;
+
+use crate::{TemporalError, TemporalResult};
+
+/// The rules used to number the weeks of a calendar year.
+///
+/// Week 1 is the first week that contains at least `minimal_days` days of the
+/// year, with weeks starting on `first_weekday`. Days before week 1 belong to
+/// the last week of the previous year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekRules {
+    first_weekday: Weekday,
+    minimal_days: u8,
+}
+
+impl Default for WeekRules {
+    fn default() -> Self {
+        Self::ISO
+    }
+}
+
+impl WeekRules {
+    /// The ISO 8601 week rules: weeks start on Monday, and the first week
+    /// contains at least four days of the year.
+    pub const ISO: Self = Self {
+        first_weekday: Weekday::Monday,
+        minimal_days: 4,
+    };
+
+    /// Creates new week rules, validating that `minimal_days` is in `1..=7`.
+    pub fn try_new(first_weekday: Weekday, minimal_days: u8) -> TemporalResult<Self> {
+        if !(1..=7).contains(&minimal_days) {
+            return Err(
+                TemporalError::range().with_message("minimal days must be between 1 and 7.")
+            );
+        }
+        Ok(Self {
+            first_weekday,
+            minimal_days,
+        })
+    }
+
+    /// Creates the week rules of a locale from ICU4X week data.
+    ///
+    /// The first weekday respects the `-u-fw` extension of the locale. ICU4X
+    /// has no per-locale minimal days, so the ISO 8601 minimum of four days is
+    /// used; see [`WeekRules::with_minimal_days`] to override it.
+    pub fn try_from_locale(locale: &Locale) -> TemporalResult<Self> {
+        let info = WeekInformation::try_new(locale.into()).map_err(|_| {
+            TemporalError::general("Week information is not available for this locale.")
+        })?;
+        Ok(Self {
+            first_weekday: info.first_weekday,
+            ..Self::ISO
+        })
+    }
+
+    /// Returns these rules with a different minimal number of days in the first week.
+    pub fn with_minimal_days(self, minimal_days: u8) -> TemporalResult<Self> {
+        Self::try_new(self.first_weekday, minimal_days)
+    }
+
+    /// Returns the first day of the week.
+    pub fn first_weekday(&self) -> Weekday {
+        self.first_weekday
+    }
+
+    /// Returns the minimal number of days of the year in the first week.
+    pub fn minimal_days(&self) -> u8 {
+        self.minimal_days
+    }
+
+    /// Returns the day of year on which week 1 starts, which may be zero or negative.
+    ///
+    /// `first_day` is the day of week of the first day of the year, from 1 (Monday) to 7 (Sunday).
+    fn first_week_start(&self, first_day: u16) -> i32 {
+        let offset = (i32::from(first_day) - i32::from(self.first_weekday as u8)).rem_euclid(7);
+        if 7 - offset >= i32::from(self.minimal_days) {
+            1 - offset
+        } else {
+            8 - offset
+        }
+    }
+
+    /// Returns the week number and the year offset (-1, 0 or 1) of a day.
+    ///
+    /// `day_of_year` is 1-based, `day_of_week` is from 1 (Monday) to 7 (Sunday), and
+    /// `days_in_previous_year` is only evaluated for days in the last week of the
+    /// previous year.
+    pub(crate) fn week_of(
+        &self,
+        day_of_year: u16,
+        day_of_week: u16,
+        days_in_year: u16,
+        days_in_previous_year: impl FnOnce() -> u16,
+    ) -> (u8, i8) {
+        let day_of_year = i32::from(day_of_year);
+        let days_in_year = i32::from(days_in_year);
+        let weekday_shift =
+            |day: u16, days: i32| (i32::from(day) - 1 + days).rem_euclid(7) as u16 + 1;
+        let first_day = weekday_shift(day_of_week, 1 - day_of_year);
+
+        let start = self.first_week_start(first_day);
+        if day_of_year < start {
+            let days_in_previous_year = i32::from(days_in_previous_year());
+            let previous_first_day = weekday_shift(first_day, -days_in_previous_year);
+            let previous_start = self.first_week_start(previous_first_day);
+            let week = (day_of_year + days_in_previous_year - previous_start) / 7 + 1;
+            return (week as u8, -1);
+        }
+
+        let next_start =
+            days_in_year + self.first_week_start(weekday_shift(first_day, days_in_year));
+        if day_of_year >= next_start {
+            return (1, 1);
+        }
+        (((day_of_year - start) / 7 + 1) as u8, 0)
+    }
+}
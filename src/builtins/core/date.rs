@@ -3,7 +3,10 @@
 use crate::parsed_intermediates::ParsedDate;
 use crate::{
     builtins::{
-        calendar::{CalendarFields, YearMonthCalendarFields},
+        calendar::{
+            CalendarFields, CalendarMonth, CustomCalendarResolver, WeekRules,
+            YearMonthCalendarFields,
+        },
         core::{
             calendar::Calendar, duration::DateDuration, Duration, PlainDateTime, PlainTime,
             ZonedDateTime,
@@ -14,14 +17,17 @@ use crate::{
         ArithmeticOverflow, DifferenceOperation, DifferenceSettings, Disambiguation,
         DisplayCalendar, ResolvedRoundingOptions, Unit, UnitGroup,
     },
-    parsers::IxdtfStringBuilder,
+    parsers::{
+        parse_ascii_digits, parse_iso_year_prefix, write_year, FormattableStrftime,
+        IxdtfStringBuilder,
+    },
     provider::{NeverProvider, TimeZoneProvider},
     MonthCode, TemporalError, TemporalResult, TimeZone,
 };
-use alloc::{format, string::String};
+use alloc::{format, string::String, vec::Vec};
 use core::{cmp::Ordering, str::FromStr};
 use icu_calendar::AnyCalendarKind;
-use writeable::Writeable;
+use writeable::{impl_display_with_writeable, LengthHint, Writeable};
 
 use super::{duration::normalized::InternalDurationRecord, PlainMonthDay, PlainYearMonth};
 use tinystr::TinyAsciiStr;
@@ -166,9 +172,44 @@ pub struct PlainDate {
     calendar: Calendar,
 }
 
-impl core::fmt::Display for PlainDate {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str(&self.to_ixdtf_string(DisplayCalendar::Auto))
+impl Writeable for PlainDate {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        self.to_ixdtf_writeable(DisplayCalendar::Auto)
+            .write_to(sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        self.to_ixdtf_writeable(DisplayCalendar::Auto)
+            .writeable_length_hint()
+    }
+}
+
+impl_display_with_writeable!(PlainDate);
+
+/// An ISO 8601 ordinal date in the extended format.
+struct FormattableOrdinalDate(IsoDate);
+
+impl Writeable for FormattableOrdinalDate {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        write_year(self.0.year, sink)?;
+        sink.write_char('-')?;
+        let day_of_year = self.0.to_icu4x().day_of_year().0;
+        if day_of_year < 100 {
+            sink.write_char('0')?;
+        }
+        if day_of_year < 10 {
+            sink.write_char('0')?;
+        }
+        day_of_year.write_to(sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        let year_length = if (0..=9999).contains(&self.0.year) {
+            4
+        } else {
+            7
+        };
+        LengthHint::exact(year_length + 4)
     }
 }
 
@@ -355,6 +396,64 @@ impl PlainDate {
         Ok(Self::new_unchecked(iso, calendar))
     }
 
+    /// Creates a new `PlainDate` from a calendar year and a day of that year,
+    /// automatically constraining the day of year into a valid range.
+    #[inline]
+    pub fn new_ordinal(year: i32, day_of_year: u16, calendar: Calendar) -> TemporalResult<Self> {
+        Self::new_ordinal_with_overflow(year, day_of_year, calendar, ArithmeticOverflow::Constrain)
+    }
+
+    /// Creates a new `PlainDate` from a calendar year and a day of that year,
+    /// rejecting any day of year that is not valid.
+    #[inline]
+    pub fn try_new_ordinal(
+        year: i32,
+        day_of_year: u16,
+        calendar: Calendar,
+    ) -> TemporalResult<Self> {
+        Self::new_ordinal_with_overflow(year, day_of_year, calendar, ArithmeticOverflow::Reject)
+    }
+
+    /// Creates a new `PlainDate` from a calendar year and a day of that year
+    /// with the specified overflow.
+    ///
+    /// `year` and `day_of_year` are in the given calendar, mirroring
+    /// [`PlainDate::year`] and [`PlainDate::day_of_year`].
+    ///
+    /// ```rust
+    /// use temporal_rs::{Calendar, PlainDate};
+    ///
+    /// let date = PlainDate::try_new_ordinal(2024, 60, Calendar::ISO).unwrap();
+    /// assert_eq!((date.month(), date.day()), (2, 29));
+    /// assert_eq!(date.day_of_year(), 60);
+    /// ```
+    pub fn new_ordinal_with_overflow(
+        year: i32,
+        day_of_year: u16,
+        calendar: Calendar,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<Self> {
+        let iso = calendar.date_from_ordinal(year, day_of_year, overflow)?;
+        Ok(Self::new_unchecked(iso, calendar))
+    }
+
+    /// Parses an ISO 8601 ordinal date in the extended (`2025-123`) or basic
+    /// (`2025123`) format into a `PlainDate` with the ISO 8601 calendar.
+    ///
+    /// Ordinal dates are not accepted by [`PlainDate::from_utf8`].
+    pub fn from_ordinal_utf8(s: &[u8]) -> TemporalResult<Self> {
+        let invalid = || TemporalError::range().with_message("Invalid ISO 8601 ordinal date.");
+        let (year, rest) = parse_iso_year_prefix(s).ok_or_else(invalid)?;
+        let digits = match rest {
+            [b'-', digits @ ..] | digits => digits,
+        };
+        if digits.len() != 3 {
+            return Err(invalid());
+        }
+        let day_of_year = parse_ascii_digits(digits).ok_or_else(invalid)?;
+        Self::try_new_ordinal(year, day_of_year as u16, Calendar::ISO)
+    }
+
     /// Create a `PlainDate` from a `PartialDate`
     ///
     /// ```rust
@@ -403,12 +502,41 @@ impl PlainDate {
         Self::from_parsed(parsed)
     }
 
+    // Converts a UTF-16 encoded string into a `PlainDate`.
+    pub fn from_utf16(s: &[u16]) -> TemporalResult<Self> {
+        let parsed = ParsedDate::from_utf16(s)?;
+
+        Self::from_parsed(parsed)
+    }
+
+    /// Converts a UTF-8 encoded string into a `PlainDate`, resolving a custom
+    /// calendar annotation with `resolver`.
+    pub fn from_utf8_with_resolver(
+        s: &[u8],
+        resolver: &(impl CustomCalendarResolver + ?Sized),
+    ) -> TemporalResult<Self> {
+        let parsed = ParsedDate::from_utf8_with_resolver(s, resolver)?;
+
+        Self::from_parsed(parsed)
+    }
+
+    /// Converts a UTF-16 encoded string into a `PlainDate`, resolving a custom
+    /// calendar annotation with `resolver`.
+    pub fn from_utf16_with_resolver(
+        s: &[u16],
+        resolver: &(impl CustomCalendarResolver + ?Sized),
+    ) -> TemporalResult<Self> {
+        let parsed = ParsedDate::from_utf16_with_resolver(s, resolver)?;
+
+        Self::from_parsed(parsed)
+    }
+
     pub fn from_parsed(parsed: ParsedDate) -> TemporalResult<Self> {
         Self::try_new(
             parsed.record.year,
             parsed.record.month,
             parsed.record.day,
-            Calendar::new(parsed.calendar),
+            parsed.calendar,
         )
     }
 
@@ -428,7 +556,7 @@ impl PlainDate {
         // 10. Return ? CalendarDateFromFields(calendarRec, fields, resolvedOptions).
         let overflow = overflow.unwrap_or(ArithmeticOverflow::Constrain);
         self.calendar.date_from_fields(
-            fields.with_fallback_date(self, self.calendar.kind(), overflow)?,
+            fields.with_fallback_date(self, &self.calendar, overflow)?,
             overflow,
         )
     }
@@ -564,6 +692,16 @@ impl PlainDate {
         self.calendar.year_of_week(&self.iso)
     }
 
+    /// Returns the calendar week of year value according to `rules`.
+    pub fn week_of_year_with_rules(&self, rules: WeekRules) -> Option<u8> {
+        self.calendar.week_of_year_with_rules(&self.iso, rules)
+    }
+
+    /// Returns the calendar year of week value according to `rules`.
+    pub fn year_of_week_with_rules(&self, rules: WeekRules) -> Option<i32> {
+        self.calendar.year_of_week_with_rules(&self.iso, rules)
+    }
+
     /// Returns the calendar days in week value.
     pub fn days_in_week(&self) -> u16 {
         self.calendar.days_in_week(&self.iso)
@@ -589,6 +727,12 @@ impl PlainDate {
         self.calendar.in_leap_year(&self.iso)
     }
 
+    /// Returns the months of the calendar year of this date in order, including
+    /// any leap months.
+    pub fn month_codes_in_year(&self) -> Vec<CalendarMonth> {
+        self.calendar.month_codes_in_year(&self.iso)
+    }
+
     pub fn era(&self) -> Option<TinyAsciiStr<16>> {
         self.calendar.era(&self.iso)
     }
@@ -638,7 +782,7 @@ impl PlainDate {
     pub fn to_plain_month_day(&self) -> TemporalResult<PlainMonthDay> {
         let overflow = ArithmeticOverflow::Constrain;
         self.calendar().month_day_from_fields(
-            CalendarFields::default().with_fallback_date(self, self.calendar.kind(), overflow)?,
+            CalendarFields::default().with_fallback_date(self, &self.calendar, overflow)?,
             overflow,
         )
     }
@@ -650,12 +794,44 @@ impl PlainDate {
             .into()
     }
 
+    /// Returns the ISO 8601 ordinal date of this date in the extended format,
+    /// e.g. `2025-123`.
+    ///
+    /// The ordinal date always uses the ISO 8601 year and day of year.
+    pub fn to_ordinal_string(&self) -> String {
+        self.to_ordinal_writeable().write_to_string().into()
+    }
+
+    /// Returns a [`Writeable`] for the ISO 8601 ordinal date of this date,
+    /// e.g. `2025-123`.
+    pub fn to_ordinal_writeable(&self) -> impl Writeable {
+        FormattableOrdinalDate(self.iso)
+    }
+
     #[inline]
     pub fn to_ixdtf_writeable(&self, display_calendar: DisplayCalendar) -> impl Writeable + '_ {
         IxdtfStringBuilder::default()
             .with_date(self.iso)
             .with_calendar(self.calendar.identifier(), display_calendar)
-            .build()
+    }
+
+    /// Formats this date with a strftime-style pattern, e.g. `%d/%m/%Y`.
+    ///
+    /// See [`FormattableStrftime`] for the supported directives. Time and
+    /// offset directives are an error.
+    pub fn to_strftime_string(&self, pattern: &str) -> TemporalResult<String> {
+        self.to_strftime_writeable(pattern)
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Returns a [`Writeable`] that formats this date with `pattern`.
+    ///
+    /// Fails like [`PlainDate::to_strftime_string`] for an invalid pattern.
+    pub fn to_strftime_writeable<'a>(
+        &self,
+        pattern: &'a str,
+    ) -> TemporalResult<impl Writeable + 'a> {
+        FormattableStrftime::try_new(pattern, Some(self.iso), None, None, None)
     }
 
     /// Creates a [`ZonedDateTime`] from the current `PlainDate` with a provided [`TimeZone`] and
@@ -714,6 +890,61 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn ordinal_dates() {
+        let date = PlainDate::try_new_ordinal(2025, 123, Calendar::ISO).unwrap();
+        assert_eq!(date, PlainDate::try_new_iso(2025, 5, 3).unwrap());
+        assert_eq!(date.to_ordinal_string(), "2025-123");
+        assert_eq!(
+            date.to_ordinal_writeable().writeable_length_hint(),
+            LengthHint::exact(8)
+        );
+        assert_eq!(PlainDate::from_ordinal_utf8(b"2025-123").unwrap(), date);
+        assert_eq!(PlainDate::from_ordinal_utf8(b"2025123").unwrap(), date);
+        assert_eq!(
+            PlainDate::from_ordinal_utf8(b"+010000-001")
+                .unwrap()
+                .to_ordinal_string(),
+            "+010000-001"
+        );
+
+        assert!(PlainDate::try_new_ordinal(2025, 366, Calendar::ISO).is_err());
+        assert!(PlainDate::try_new_ordinal(2025, 0, Calendar::ISO).is_err());
+        assert_eq!(
+            PlainDate::new_ordinal(2025, 366, Calendar::ISO).unwrap(),
+            PlainDate::try_new_iso(2025, 12, 31).unwrap()
+        );
+        assert_eq!(
+            PlainDate::try_new_ordinal(2024, 366, Calendar::ISO)
+                .unwrap()
+                .to_ordinal_string(),
+            "2024-366"
+        );
+        for invalid in [
+            "2025-12",
+            "2025-1234",
+            "2025-366",
+            "2025-12-3",
+            "25-123",
+            "2025-123Z",
+        ] {
+            assert!(PlainDate::from_ordinal_utf8(invalid.as_bytes()).is_err());
+        }
+        // Ordinal dates are opt-in.
+        assert!(PlainDate::from_utf8(b"2025-123").is_err());
+
+        // Hebrew year 5785 starts on 2024-10-03.
+        let hebrew = Calendar::from_str("hebrew").unwrap();
+        let date = PlainDate::try_new_ordinal(5785, 1, hebrew.clone()).unwrap();
+        assert_eq!(date.to_ixdtf_string(DisplayCalendar::Never), "2024-10-03");
+        let last = PlainDate::try_new_ordinal(5785, date.days_in_year(), hebrew.clone()).unwrap();
+        assert_eq!(
+            (last.year(), last.day_of_year()),
+            (5785, date.days_in_year())
+        );
+        assert!(PlainDate::try_new_ordinal(5785, date.days_in_year() + 1, hebrew).is_err());
+    }
+
     #[test]
     fn new_date_limits() {
         let err = PlainDate::try_new(-271_821, 4, 18, Calendar::default());
@@ -7,7 +7,7 @@ use super::{
 use crate::parsed_intermediates::ParsedDateTime;
 use crate::{
     builtins::{
-        calendar::CalendarFields,
+        calendar::{CalendarFields, WeekRules},
         core::{calendar::Calendar, Instant},
     },
     iso::{IsoDate, IsoDateTime, IsoTime},
@@ -16,7 +16,7 @@ use crate::{
         DisplayCalendar, ResolvedRoundingOptions, RoundingOptions, ToStringRoundingOptions, Unit,
         UnitGroup,
     },
-    parsers::IxdtfStringBuilder,
+    parsers::{parse_strptime, FormattableStrftime, IxdtfStringBuilder},
     primitive::FiniteF64,
     provider::{NeverProvider, TimeZoneProvider},
     MonthCode, TemporalError, TemporalResult, TimeZone,
@@ -24,7 +24,7 @@ use crate::{
 use alloc::string::String;
 use core::{cmp::Ordering, str::FromStr};
 use tinystr::TinyAsciiStr;
-use writeable::Writeable;
+use writeable::{impl_display_with_writeable, LengthHint, Writeable};
 
 /// A partial PlainDateTime record
 #[derive(Debug, Default, Clone)]
@@ -35,6 +35,30 @@ pub struct PartialDateTime {
     pub calendar: Calendar,
 }
 
+impl PartialDateTime {
+    /// Parses `source` with a strftime-style pattern into ISO 8601 fields,
+    /// e.g. `05/01/2024 14:30` with `%d/%m/%Y %H:%M`.
+    ///
+    /// See [`FormattableStrftime`] for the supported directives. Offset and
+    /// time zone directives are an error; use
+    /// [`PartialZonedDateTime::from_strptime_with_provider`](crate::partial::PartialZonedDateTime::from_strptime_with_provider)
+    /// for those.
+    pub fn from_strptime(source: &str, pattern: &str) -> TemporalResult<Self> {
+        let record = parse_strptime(source, pattern)?;
+        if record.offset.is_some() || record.time_zone.is_some() {
+            return Err(TemporalError::r#type()
+                .with_message("A PartialDateTime cannot hold a UTC offset or time zone."));
+        }
+        Ok(Self {
+            fields: DateTimeFields {
+                calendar_fields: record.calendar_fields,
+                time: record.time,
+            },
+            calendar: Calendar::ISO,
+        })
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct DateTimeFields {
     pub calendar_fields: CalendarFields,
@@ -181,20 +205,28 @@ pub struct PlainDateTime {
     calendar: Calendar,
 }
 
-impl core::fmt::Display for PlainDateTime {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let string =
-            self.to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto);
+impl Writeable for PlainDateTime {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        let writeable =
+            self.to_ixdtf_writeable(ToStringRoundingOptions::default(), DisplayCalendar::Auto);
 
         debug_assert!(
-            string.is_ok(),
-            "Duration must return a valid string with default options."
+            writeable.is_ok(),
+            "PlainDateTime must return a valid string with default options."
         );
 
-        f.write_str(&string.map_err(|_| Default::default())?)
+        writeable.map_err(|_| core::fmt::Error)?.write_to(sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        self.to_ixdtf_writeable(ToStringRoundingOptions::default(), DisplayCalendar::Auto)
+            .map(|x| x.writeable_length_hint())
+            .unwrap_or(LengthHint::undefined())
     }
 }
 
+impl_display_with_writeable!(PlainDateTime);
+
 // ==== Private PlainDateTime API ====
 
 impl PlainDateTime {
@@ -572,6 +604,12 @@ impl PlainDateTime {
         Self::from_parsed(parsed)
     }
 
+    // Converts a UTF-16 encoded string into a `PlainDateTime`.
+    pub fn from_utf16(s: &[u16]) -> TemporalResult<Self> {
+        let parsed = ParsedDateTime::from_utf16(s)?;
+        Self::from_parsed(parsed)
+    }
+
     pub fn from_parsed(parsed: ParsedDateTime) -> TemporalResult<Self> {
         let date = IsoDate::new_with_overflow(
             parsed.date.record.year,
@@ -581,10 +619,7 @@ impl PlainDateTime {
         )?;
         let iso = IsoDateTime::new(date, parsed.time)?;
 
-        Ok(Self::new_unchecked(
-            iso,
-            Calendar::new(parsed.date.calendar),
-        ))
+        Ok(Self::new_unchecked(iso, parsed.date.calendar))
     }
 
     /// Creates a new `DateTime` with the fields of a `PartialDateTime`.
@@ -633,7 +668,7 @@ impl PlainDateTime {
         let result_date = self.calendar.date_from_fields(
             fields
                 .calendar_fields
-                .with_fallback_datetime(self, self.calendar.kind(), overflow)?,
+                .with_fallback_datetime(self, &self.calendar, overflow)?,
             overflow,
         )?;
 
@@ -792,6 +827,16 @@ impl PlainDateTime {
         self.calendar.year_of_week(&self.iso.date)
     }
 
+    /// Returns the calendar week of year value according to `rules`.
+    pub fn week_of_year_with_rules(&self, rules: WeekRules) -> Option<u8> {
+        self.calendar.week_of_year_with_rules(&self.iso.date, rules)
+    }
+
+    /// Returns the calendar year of week value according to `rules`.
+    pub fn year_of_week_with_rules(&self, rules: WeekRules) -> Option<i32> {
+        self.calendar.year_of_week_with_rules(&self.iso.date, rules)
+    }
+
     /// Returns the calendar days in week value.
     pub fn days_in_week(&self) -> u16 {
         self.calendar.days_in_week(&self.iso.date)
@@ -945,6 +990,32 @@ impl PlainDateTime {
         self.to_ixdtf_writeable(options, display_calendar)
             .map(|x| x.write_to_string().into())
     }
+
+    /// Formats this date-time with a strftime-style pattern, e.g.
+    /// `%d/%m/%Y %H:%M`.
+    ///
+    /// See [`FormattableStrftime`] for the supported directives. Offset and
+    /// time zone directives are an error.
+    pub fn to_strftime_string(&self, pattern: &str) -> TemporalResult<String> {
+        self.to_strftime_writeable(pattern)
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Returns a [`Writeable`] that formats this date-time with `pattern`.
+    ///
+    /// Fails like [`PlainDateTime::to_strftime_string`] for an invalid pattern.
+    pub fn to_strftime_writeable<'a>(
+        &self,
+        pattern: &'a str,
+    ) -> TemporalResult<impl Writeable + 'a> {
+        FormattableStrftime::try_new(
+            pattern,
+            Some(self.iso.date),
+            Some(self.iso.time),
+            None,
+            None,
+        )
+    }
 }
 
 // ==== Trait impls ====
@@ -8,19 +8,25 @@ use crate::{
         ArithmeticOverflow, RelativeTo, ResolvedRoundingOptions, RoundingIncrement,
         RoundingOptions, ToStringRoundingOptions, Unit,
     },
-    parsers::{FormattableDateDuration, FormattableDuration, FormattableTimeDuration, Precision},
+    parsers::{
+        ixdtf_error, Encoded, FormattableDateDuration, FormattableDuration,
+        FormattableTimeDuration, Precision,
+    },
     primitive::FiniteF64,
     provider::TimeZoneProvider,
     temporal_assert, Sign, TemporalError, TemporalResult, NS_PER_DAY,
 };
-use alloc::format;
 use alloc::string::String;
 use core::{cmp::Ordering, str::FromStr};
 use ixdtf::{
-    encoding::Utf8, parsers::IsoDurationParser, records::Fraction, records::TimeDurationRecord,
+    encoding::{Utf16, Utf8},
+    parsers::IsoDurationParser,
+    records::{DurationParseRecord, Fraction, TimeDurationRecord},
+    ParseError,
 };
 use normalized::InternalDurationRecord;
 use num_traits::Euclid;
+use writeable::{impl_display_with_writeable, LengthHint, Writeable};
 
 use self::normalized::TimeDuration;
 
@@ -322,18 +328,26 @@ impl Default for Duration {
     }
 }
 
-impl core::fmt::Display for Duration {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let string = self.as_temporal_string(ToStringRoundingOptions::default());
+impl Writeable for Duration {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        let writeable = self.as_temporal_writeable(ToStringRoundingOptions::default());
 
         debug_assert!(
-            string.is_ok(),
+            writeable.is_ok(),
             "Duration must return a valid string with default options."
         );
-        f.write_str(&string.map_err(|_| Default::default())?)
+        writeable.map_err(|_| core::fmt::Error)?.write_to(sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        self.as_temporal_writeable(ToStringRoundingOptions::default())
+            .map(|x| x.writeable_length_hint())
+            .unwrap_or(LengthHint::undefined())
     }
 }
 
+impl_display_with_writeable!(Duration);
+
 // NOTE(nekevss): Structure of the below is going to be a little convoluted,
 // but intended to section everything based on the below
 //
@@ -697,8 +711,19 @@ impl Duration {
     pub fn from_utf8(s: &[u8]) -> TemporalResult<Self> {
         let parse_record = IsoDurationParser::<Utf8>::from_utf8(s)
             .parse()
-            .map_err(|e| TemporalError::range().with_message(format!("{e}")))?;
+            .map_err(|e| ixdtf_error(e, Encoded::Utf8(s), reparse_duration))?;
+        Self::from_parse_record(parse_record)
+    }
+
+    // Converts a UTF-16 encoded string into a `Duration`.
+    pub fn from_utf16(s: &[u16]) -> TemporalResult<Self> {
+        let parse_record = IsoDurationParser::<Utf16>::new(s)
+            .parse()
+            .map_err(|e| ixdtf_error(e, Encoded::Utf16(s), reparse_duration))?;
+        Self::from_parse_record(parse_record)
+    }
 
+    fn from_parse_record(parse_record: DurationParseRecord) -> TemporalResult<Self> {
         fn fraction_to_unadjusted_ns(fraction: Option<Fraction>) -> Result<u32, TemporalError> {
             if let Some(fraction) = fraction {
                 fraction.to_nanoseconds().ok_or(
@@ -1353,6 +1378,15 @@ impl Duration {
 
     /// Returns the `Duration` as a formatted string
     pub fn as_temporal_string(&self, options: ToStringRoundingOptions) -> TemporalResult<String> {
+        self.as_temporal_writeable(options)
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Returns the `Duration` as a formatted [`Writeable`]
+    pub fn as_temporal_writeable(
+        &self,
+        options: ToStringRoundingOptions,
+    ) -> TemporalResult<impl Writeable> {
         if options.smallest_unit == Some(Unit::Hour) || options.smallest_unit == Some(Unit::Minute)
         {
             return Err(TemporalError::range().with_message(
@@ -1364,8 +1398,7 @@ impl Duration {
         if resolved_options.smallest_unit == Unit::Nanosecond
             && resolved_options.increment == RoundingIncrement::ONE
         {
-            let duration = duration_to_formattable(self, resolved_options.precision)?;
-            return Ok(duration.to_string());
+            return duration_to_formattable(self, resolved_options.precision);
         }
 
         let rounding_options = ResolvedRoundingOptions::from_to_string_options(&resolved_options);
@@ -1388,7 +1421,7 @@ impl Duration {
         let rounded = Self::from_internal(internal_duration, rounded_largest_unit)?;
 
         // 18. Return TemporalDurationToString(roundedDuration, precision.[[Precision]]).
-        Ok(duration_to_formattable(&rounded, resolved_options.precision)?.to_string())
+        duration_to_formattable(&rounded, resolved_options.precision)
     }
 }
 
@@ -1563,6 +1596,14 @@ pub(crate) fn duration_sign(set: &[i64]) -> Sign {
     Sign::Zero
 }
 
+// Reparses a prefix of a failed duration string to locate the error.
+fn reparse_duration(source: Encoded<'_>) -> Result<(), ParseError> {
+    match source {
+        Encoded::Utf8(s) => IsoDurationParser::<Utf8>::from_utf8(s).parse().map(|_| ()),
+        Encoded::Utf16(s) => IsoDurationParser::<Utf16>::new(s).parse().map(|_| ()),
+    }
+}
+
 impl From<DateDuration> for Duration {
     fn from(value: DateDuration) -> Self {
         Self {
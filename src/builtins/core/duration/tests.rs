@@ -23,6 +23,36 @@ fn partial_duration_values() {
     assert_eq!(result.years(), 20);
 }
 
+#[test]
+#[cfg(feature = "std")]
+fn duration_writeable_length_hint() {
+    use alloc::format;
+    use writeable::{assert_writeable_eq, LengthHint, Writeable};
+
+    let cases = [
+        "PT0S",
+        "P1Y",
+        "-P1Y2M3W4DT5H6M7.00800901S",
+        "PT1.5S",
+        "P99999DT23H59M59.999999999S",
+        "PT9007199254740991S",
+    ];
+    for case in cases {
+        let duration = Duration::from_str(case).unwrap();
+        assert_writeable_eq!(duration, case);
+    }
+
+    let duration = Duration::from_str("PT1H30M").unwrap();
+    let options = ToStringRoundingOptions {
+        precision: Precision::Digit(4),
+        smallest_unit: None,
+        rounding_mode: None,
+    };
+    let writeable = duration.as_temporal_writeable(options).unwrap();
+    assert_eq!(writeable.write_to_string(), "PT1H30M0.0000S");
+    assert_eq!(writeable.writeable_length_hint(), LengthHint::exact(14));
+}
+
 #[test]
 fn default_duration_string() {
     let duration = Duration::default();
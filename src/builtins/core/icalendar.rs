@@ -0,0 +1,860 @@
+//! iCalendar ([RFC 5545][rfc]) value parsing and formatting.
+//!
+//! This module adds conversions between Temporal types and the iCalendar
+//! `DATE`, `DATE-TIME`, `DURATION` and `PERIOD` value types, and generates
+//! `VTIMEZONE` components from time zone transition data.
+//!
+//! [rfc]: https://datatracker.ietf.org/doc/html/rfc5545#section-3.3
+
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{self, Write};
+
+use crate::{
+    builtins::core::{
+        timezone::{TimeZone, TimeZoneTransition, UtcOffset},
+        Duration, Instant, PlainDate, PlainDateTime, ZonedDateTime,
+    },
+    iso::{IsoDate, IsoDateTime, IsoTime},
+    options::{ArithmeticOverflow, Disambiguation},
+    parsers::parse_ascii_digits,
+    provider::{TimeZoneProvider, TransitionDirection},
+    Calendar, Sign, TemporalError, TemporalResult,
+};
+
+/// The fields of an iCalendar `DATE` or `DATE-TIME` value.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DateTimeValue {
+    pub(crate) date: IsoDate,
+    pub(crate) time: Option<IsoTime>,
+    pub(crate) utc: bool,
+}
+
+/// Parses `YYYYMMDD`, `YYYYMMDDTHHMMSS` or `YYYYMMDDTHHMMSSZ`.
+pub(crate) fn parse_date_time(value: &[u8]) -> Option<DateTimeValue> {
+    let (date, rest) = value.split_at_checked(8)?;
+    let (year, month_day) = date.split_at_checked(4)?;
+    let (month, day) = month_day.split_at_checked(2)?;
+    let date = IsoDate::new_with_overflow(
+        parse_ascii_digits(year)? as i32,
+        parse_ascii_digits(month)? as u8,
+        parse_ascii_digits(day)? as u8,
+        ArithmeticOverflow::Reject,
+    )
+    .ok()?;
+    if rest.is_empty() {
+        return Some(DateTimeValue {
+            date,
+            time: None,
+            utc: false,
+        });
+    }
+
+    let (time, zone) = rest.strip_prefix(b"T")?.split_at_checked(6)?;
+    let (hour, minute_second) = time.split_at_checked(2)?;
+    let (minute, second) = minute_second.split_at_checked(2)?;
+    let utc = match zone {
+        b"" => false,
+        b"Z" => true,
+        _ => return None,
+    };
+    // A leap second is constrained to the preceding second, as in RFC 9557 strings.
+    let second = match parse_ascii_digits(second)? {
+        60 => 59,
+        second => second as u8,
+    };
+    let time = IsoTime::new(
+        parse_ascii_digits(hour)? as u8,
+        parse_ascii_digits(minute)? as u8,
+        second,
+        0,
+        0,
+        0,
+        ArithmeticOverflow::Reject,
+    )
+    .ok()?;
+    Some(DateTimeValue {
+        date,
+        time: Some(time),
+        utc,
+    })
+}
+
+/// Appends formatted `args` to `out`.
+fn push_fmt(out: &mut String, args: fmt::Arguments<'_>) -> TemporalResult<()> {
+    // Writing to a `String` does not fail.
+    out.write_fmt(args).map_err(|_| TemporalError::assert())
+}
+
+fn write_date(date: &IsoDate, out: &mut String) -> TemporalResult<()> {
+    if !(0..=9999).contains(&date.year) {
+        return Err(TemporalError::range()
+            .with_message("iCalendar values require a year between 0 and 9999."));
+    }
+    push_fmt(
+        out,
+        format_args!("{:04}{:02}{:02}", date.year, date.month, date.day),
+    )
+}
+
+fn write_date_time(date_time: &IsoDateTime, utc: bool, out: &mut String) -> TemporalResult<()> {
+    write_date(&date_time.date, out)?;
+    let time = date_time.time;
+    push_fmt(
+        out,
+        format_args!("T{:02}{:02}{:02}", time.hour, time.minute, time.second),
+    )?;
+    if utc {
+        out.push('Z');
+    }
+    Ok(())
+}
+
+pub(crate) fn format_date_time(date_time: &IsoDateTime, utc: bool) -> TemporalResult<String> {
+    let mut result = String::new();
+    write_date_time(date_time, utc, &mut result)?;
+    Ok(result)
+}
+
+fn write_offset(offset: &UtcOffset, out: &mut String) -> TemporalResult<()> {
+    let sign = if offset.nanoseconds() < 0 { '-' } else { '+' };
+    let seconds = offset.nanoseconds().unsigned_abs() / 1_000_000_000;
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    push_fmt(out, format_args!("{sign}{hours:02}{minutes:02}"))?;
+    if seconds != 0 {
+        push_fmt(out, format_args!("{seconds:02}"))?;
+    }
+    Ok(())
+}
+
+/// Returns whether `time_zone` is written with the UTC designator rather than a `TZID`.
+fn is_utc(time_zone: &TimeZone) -> bool {
+    match time_zone {
+        TimeZone::IanaIdentifier(id) => {
+            id.eq_ignore_ascii_case("UTC") || id.eq_ignore_ascii_case("Etc/UTC")
+        }
+        // iCalendar has no offset time zones, so these are written as UTC.
+        TimeZone::UtcOffset(_) => true,
+    }
+}
+
+fn invalid_value(value_type: &'static str) -> TemporalError {
+    TemporalError::range().with_message(format!("Invalid iCalendar {value_type} value."))
+}
+
+// ==== PlainDate, PlainDateTime and ZonedDateTime ====
+
+impl PlainDate {
+    /// Parses an iCalendar `DATE` value, e.g. `19970714`.
+    pub fn from_icalendar(value: &[u8]) -> TemporalResult<Self> {
+        match parse_date_time(value) {
+            Some(DateTimeValue {
+                date, time: None, ..
+            }) => Ok(Self::new_unchecked(date, Calendar::default())),
+            _ => Err(invalid_value("DATE")),
+        }
+    }
+
+    /// Returns this date as an iCalendar `DATE` value, using its ISO fields.
+    pub fn to_icalendar_string(&self) -> TemporalResult<String> {
+        let mut result = String::new();
+        write_date(&self.iso, &mut result)?;
+        Ok(result)
+    }
+}
+
+impl PlainDateTime {
+    /// Parses a floating iCalendar `DATE-TIME` value, e.g. `19980118T230000`.
+    pub fn from_icalendar(value: &[u8]) -> TemporalResult<Self> {
+        match parse_date_time(value) {
+            Some(DateTimeValue {
+                date,
+                time: Some(time),
+                utc: false,
+            }) => Ok(Self::new_unchecked(
+                IsoDateTime::new(date, time)?,
+                Calendar::default(),
+            )),
+            _ => Err(invalid_value("floating DATE-TIME")),
+        }
+    }
+
+    /// Returns this date-time as a floating iCalendar `DATE-TIME` value.
+    ///
+    /// iCalendar values have a precision of seconds, so subsecond fields are truncated.
+    pub fn to_icalendar_string(&self) -> TemporalResult<String> {
+        format_date_time(&self.iso, false)
+    }
+}
+
+impl ZonedDateTime {
+    /// Parses an iCalendar `DATE-TIME` value in UTC (`19980119T070000Z`), or
+    /// a local `DATE-TIME` value with the `TZID` parameter `tzid`.
+    ///
+    /// Local times in a DST gap or overlap are resolved as RFC 5545 requires,
+    /// which is equivalent to [`Disambiguation::Compatible`].
+    pub fn from_icalendar_with_provider(
+        value: &[u8],
+        tzid: Option<&str>,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Self> {
+        let Some(DateTimeValue {
+            date,
+            time: Some(time),
+            utc,
+        }) = parse_date_time(value)
+        else {
+            return Err(invalid_value("DATE-TIME"));
+        };
+        let local = IsoDateTime::new(date, time)?;
+        match (utc, tzid) {
+            (true, None) => {
+                let time_zone = TimeZone::try_from_identifier_str_with_provider("UTC", provider)?;
+                Self::try_new_with_provider(
+                    local.as_nanoseconds().0,
+                    Calendar::default(),
+                    time_zone,
+                    provider,
+                )
+            }
+            (false, Some(tzid)) => {
+                let time_zone = TimeZone::try_from_identifier_str_with_provider(tzid, provider)?;
+                let resolved = time_zone.get_epoch_nanoseconds_for(
+                    local,
+                    Disambiguation::Compatible,
+                    provider,
+                )?;
+                Self::try_new_with_cached_offset(
+                    resolved.ns.0,
+                    Calendar::default(),
+                    time_zone,
+                    resolved.offset,
+                )
+            }
+            (true, Some(_)) => Err(TemporalError::range()
+                .with_message("The TZID parameter must not be used with a UTC DATE-TIME.")),
+            (false, None) => Err(TemporalError::range()
+                .with_message("A local DATE-TIME requires a TZID to be a ZonedDateTime.")),
+        }
+    }
+
+    /// Returns this date-time as an iCalendar `DATE-TIME` value.
+    ///
+    /// UTC and offset time zones are written in UTC with a `Z` suffix, other
+    /// time zones as local time to be used with a `TZID` parameter.
+    pub fn to_icalendar_string(&self) -> TemporalResult<String> {
+        let mut result = String::new();
+        self.write_icalendar(&mut result)?;
+        Ok(result)
+    }
+
+    /// Returns an iCalendar content line for the property `name` with this
+    /// date-time as its value, e.g. `DTSTART;TZID=America/New_York:19980118T230000`.
+    pub fn to_icalendar_property(&self, name: &str) -> TemporalResult<String> {
+        let mut result = String::from(name);
+        if !is_utc(self.timezone()) {
+            let tzid = self.timezone().identifier();
+            if tzid.contains([':', ';', ',']) {
+                push_fmt(&mut result, format_args!(";TZID=\"{tzid}\""))?;
+            } else {
+                push_fmt(&mut result, format_args!(";TZID={tzid}"))?;
+            }
+        }
+        result.push(':');
+        self.write_icalendar(&mut result)?;
+        Ok(result)
+    }
+
+    fn write_icalendar(&self, out: &mut String) -> TemporalResult<()> {
+        if is_utc(self.timezone()) {
+            let utc = IsoDateTime::from_epoch_nanos(self.epoch_nanoseconds(), 0);
+            write_date_time(&utc, true, out)
+        } else {
+            write_date_time(&self.get_iso_datetime(), false, out)
+        }
+    }
+}
+
+// ==== Duration ====
+
+/// Splits a leading `<digits><designator>` component off `s`.
+fn split_component(s: &[u8]) -> Option<(i64, u8, &[u8])> {
+    let digits = s.iter().take_while(|b| b.is_ascii_digit()).count();
+    let (value, rest) = s.split_at_checked(digits)?;
+    let (designator, rest) = rest.split_first()?;
+    Some((i64::from(parse_ascii_digits(value)?), *designator, rest))
+}
+
+fn parse_duration(value: &[u8]) -> Option<Duration> {
+    let (sign, rest) = match value {
+        [b'-', rest @ ..] => (Sign::Negative, rest),
+        [b'+', rest @ ..] => (Sign::Positive, rest),
+        rest => (Sign::Positive, rest),
+    };
+    let rest = rest.strip_prefix(b"P")?;
+    let mut split = rest.splitn(2, |b| *b == b'T');
+    let date_part = split.next()?;
+    let time_part = split.next();
+
+    let (mut weeks, mut days) = (0, 0);
+    if !date_part.is_empty() {
+        match split_component(date_part)? {
+            (value, b'D', []) => days = value,
+            (value, b'W', []) if time_part.is_none() => weeks = value,
+            _ => return None,
+        }
+    }
+
+    // `dur-time` components must appear in order without gaps, e.g. `T1H0M5S`.
+    let mut time = [0i64; 3];
+    if let Some(mut s) = time_part {
+        let mut next = None;
+        loop {
+            let (value, designator, rest) = split_component(s)?;
+            let index = b"HMS".iter().position(|d| *d == designator)?;
+            if next.is_some_and(|next| next != index) {
+                return None;
+            }
+            *time.get_mut(index)? = value;
+            next = Some(index + 1);
+            s = rest;
+            if s.is_empty() {
+                break;
+            }
+        }
+    } else if date_part.is_empty() {
+        return None;
+    }
+
+    let [hours, minutes, seconds] = time;
+    let duration = Duration::new(0, 0, weeks, days, hours, minutes, seconds, 0, 0, 0).ok()?;
+    Some(if sign == Sign::Negative {
+        duration.negated()
+    } else {
+        duration
+    })
+}
+
+impl Duration {
+    /// Parses an iCalendar `DURATION` value, e.g. `P15DT5H0M20S` or `-PT15M`.
+    pub fn from_icalendar(value: &[u8]) -> TemporalResult<Self> {
+        parse_duration(value).ok_or_else(|| invalid_value("DURATION"))
+    }
+
+    /// Returns this duration as an iCalendar `DURATION` value.
+    ///
+    /// Weeks are written as days unless the duration only has weeks. Durations
+    /// with years, months or subsecond units cannot be represented.
+    pub fn to_icalendar_string(&self) -> TemporalResult<String> {
+        let mut result = String::new();
+        self.write_icalendar(&mut result)?;
+        Ok(result)
+    }
+
+    fn write_icalendar(&self, out: &mut String) -> TemporalResult<()> {
+        if self.years() != 0 || self.months() != 0 {
+            return Err(TemporalError::range()
+                .with_message("iCalendar durations cannot contain years or months."));
+        }
+        if self.milliseconds() != 0 || self.microseconds() != 0 || self.nanoseconds() != 0 {
+            return Err(TemporalError::range()
+                .with_message("iCalendar durations cannot contain subsecond units."));
+        }
+
+        let abs = self.abs();
+        out.push_str(if self.sign() == Sign::Negative {
+            "-P"
+        } else {
+            "P"
+        });
+        let time = [abs.hours(), abs.minutes(), abs.seconds()];
+        if abs.weeks() != 0 && abs.days() == 0 && time == [0; 3] {
+            return push_fmt(out, format_args!("{}W", abs.weeks()));
+        }
+
+        let days = abs
+            .weeks()
+            .checked_mul(7)
+            .and_then(|d| d.checked_add(abs.days()))
+            .ok_or_else(|| TemporalError::range().with_message("Duration days out of range."))?;
+        if days != 0 {
+            push_fmt(out, format_args!("{days}D"))?;
+        }
+        let first = time.iter().position(|v| *v != 0);
+        let last = time.iter().rposition(|v| *v != 0);
+        match (first, last) {
+            (Some(first), Some(last)) => {
+                out.push('T');
+                for (value, designator) in
+                    time.iter().zip(['H', 'M', 'S']).take(last + 1).skip(first)
+                {
+                    push_fmt(out, format_args!("{value}{designator}"))?;
+                }
+            }
+            _ if days == 0 => out.push_str("T0S"),
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+// ==== Period ====
+
+/// The end of an iCalendar [`Period`].
+#[derive(Debug, Clone)]
+pub enum PeriodEnd {
+    /// An explicit end, as in `19970101T180000Z/19970102T070000Z`.
+    DateTime(ZonedDateTime),
+    /// A duration from the start, as in `19970101T180000Z/PT5H30M`.
+    Duration(Duration),
+}
+
+/// An iCalendar `PERIOD` value.
+#[derive(Debug, Clone)]
+pub struct Period {
+    start: ZonedDateTime,
+    end: PeriodEnd,
+}
+
+impl Period {
+    /// Creates a period, rejecting an end before the start or a negative duration.
+    pub fn new(start: ZonedDateTime, end: PeriodEnd) -> TemporalResult<Self> {
+        let valid = match &end {
+            PeriodEnd::DateTime(end) => end.epoch_nanoseconds() >= start.epoch_nanoseconds(),
+            PeriodEnd::Duration(duration) => duration.sign() != Sign::Negative,
+        };
+        if !valid {
+            return Err(TemporalError::range()
+                .with_message("The end of a period must not precede its start."));
+        }
+        Ok(Self { start, end })
+    }
+
+    /// Returns the start of the period.
+    pub fn start(&self) -> &ZonedDateTime {
+        &self.start
+    }
+
+    /// Returns the end of the period as it was specified.
+    pub fn end_value(&self) -> &PeriodEnd {
+        &self.end
+    }
+
+    /// Returns the end of the period, adding the duration to the start if needed.
+    pub fn end_with_provider(
+        &self,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<ZonedDateTime> {
+        match &self.end {
+            PeriodEnd::DateTime(end) => Ok(end.clone()),
+            PeriodEnd::Duration(duration) => self.start.add_with_provider(duration, None, provider),
+        }
+    }
+
+    /// Parses an iCalendar `PERIOD` value, with the `TZID` parameter `tzid`
+    /// applying to both of its date-times.
+    pub fn from_icalendar_with_provider(
+        value: &[u8],
+        tzid: Option<&str>,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Self> {
+        let mut split = value.splitn(2, |b| *b == b'/');
+        let start = split.next().unwrap_or_default();
+        let end = split.next().ok_or_else(|| invalid_value("PERIOD"))?;
+        let start = ZonedDateTime::from_icalendar_with_provider(start, tzid, provider)?;
+        let end = match end {
+            [b'P' | b'+' | b'-', ..] => PeriodEnd::Duration(Duration::from_icalendar(end)?),
+            _ => PeriodEnd::DateTime(ZonedDateTime::from_icalendar_with_provider(
+                end, tzid, provider,
+            )?),
+        };
+        Self::new(start, end)
+    }
+
+    /// Returns this period as an iCalendar `PERIOD` value.
+    pub fn to_icalendar_string(&self) -> TemporalResult<String> {
+        let mut result = String::new();
+        self.start.write_icalendar(&mut result)?;
+        result.push('/');
+        match &self.end {
+            PeriodEnd::DateTime(end) => end.write_icalendar(&mut result)?,
+            PeriodEnd::Duration(duration) => duration.write_icalendar(&mut result)?,
+        }
+        Ok(result)
+    }
+}
+
+// ==== VTIMEZONE ====
+
+/// An observance (`STANDARD` or `DAYLIGHT`) of a `VTIMEZONE` component.
+struct Observance {
+    onset: IsoDateTime,
+    offset_from: UtcOffset,
+    offset_to: UtcOffset,
+    is_dst: bool,
+    name: Option<String>,
+}
+
+impl Observance {
+    fn from_transition(transition: &TimeZoneTransition) -> Self {
+        Self {
+            // DTSTART is the local time of the onset in the offset before it.
+            onset: transition
+                .offset_before
+                .get_iso_datetime_for(&transition.instant),
+            offset_from: transition.offset_before,
+            offset_to: transition.offset_after,
+            is_dst: transition.is_dst_after,
+            name: transition
+                .abbreviation_after
+                .map(|a| String::from(a.as_str())),
+        }
+    }
+
+    /// Appends the lines of this observance to `out`, each ending with CRLF.
+    fn write(&self, out: &mut String) -> TemporalResult<()> {
+        let kind = if self.is_dst { "DAYLIGHT" } else { "STANDARD" };
+        push_fmt(out, format_args!("BEGIN:{kind}\r\nDTSTART:"))?;
+        write_date_time(&self.onset, false, out)?;
+        out.push_str("\r\nTZOFFSETFROM:");
+        write_offset(&self.offset_from, out)?;
+        out.push_str("\r\nTZOFFSETTO:");
+        write_offset(&self.offset_to, out)?;
+        out.push_str("\r\n");
+        if let Some(name) = &self.name {
+            push_fmt(out, format_args!("TZNAME:{name}\r\n"))?;
+        }
+        push_fmt(out, format_args!("END:{kind}\r\n"))
+    }
+}
+
+/// Returns the conventional onset of an observance that has no known start.
+fn observance_epoch() -> IsoDateTime {
+    IsoDateTime::new_unchecked(IsoDate::new_unchecked(1601, 1, 1), IsoTime::default())
+}
+
+impl TimeZone {
+    /// Generates an iCalendar `VTIMEZONE` component describing this time zone
+    /// between `start` and `end`.
+    ///
+    /// The component has one observance for the offset in effect at `start`
+    /// and one for every transition before `end`. Lines are separated by CRLF.
+    pub fn to_vtimezone_with_provider(
+        &self,
+        start: &Instant,
+        end: &Instant,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<String> {
+        if end < start {
+            return Err(TemporalError::range()
+                .with_message("The end of a VTIMEZONE range must not precede its start."));
+        }
+
+        let mut observances = Vec::new();
+        match self {
+            Self::UtcOffset(offset) => observances.push(Observance {
+                onset: observance_epoch(),
+                offset_from: *offset,
+                offset_to: *offset,
+                is_dst: false,
+                name: None,
+            }),
+            Self::IanaIdentifier(identifier) => {
+                // Include a transition that happens exactly at `start`.
+                let after_start = Instant::try_new(start.as_i128() + 1)?;
+                let initial = match self.get_transition_with_provider(
+                    &after_start,
+                    TransitionDirection::Previous,
+                    provider,
+                )? {
+                    Some(transition) => Observance::from_transition(&transition),
+                    None => {
+                        let info = provider
+                            .get_named_tz_offset_nanoseconds(identifier, start.as_i128())?;
+                        let offset = UtcOffset::from(info.offset);
+                        Observance {
+                            onset: observance_epoch(),
+                            offset_from: offset,
+                            offset_to: offset,
+                            is_dst: info.is_dst,
+                            name: info.abbreviation.map(|a| String::from(a.as_str())),
+                        }
+                    }
+                };
+                observances.push(initial);
+
+                let mut cursor = *start;
+                while let Some(transition) =
+                    self.get_transition_with_provider(&cursor, TransitionDirection::Next, provider)?
+                {
+                    if transition.instant >= *end || transition.instant <= cursor {
+                        break;
+                    }
+                    observances.push(Observance::from_transition(&transition));
+                    cursor = transition.instant;
+                }
+            }
+        }
+
+        let mut result = String::from("BEGIN:VTIMEZONE\r\nTZID:");
+        result.push_str(&self.identifier());
+        result.push_str("\r\n");
+        for observance in &observances {
+            observance.write(&mut result)?;
+        }
+        result.push_str("END:VTIMEZONE\r\n");
+        Ok(result)
+    }
+}
+
+#[cfg(all(test, feature = "tzdb"))]
+mod tests {
+    use core::str::FromStr;
+
+    use super::{Period, PeriodEnd};
+    use crate::{
+        options::{
+            Disambiguation, DisplayCalendar, DisplayOffset, DisplayTimeZone, OffsetDisambiguation,
+            ToStringRoundingOptions,
+        },
+        tzdb::FsTzdbProvider,
+        Duration, Instant, PlainDate, PlainDateTime, TimeZone, ZonedDateTime,
+    };
+
+    fn ixdtf(zdt: &ZonedDateTime, provider: &FsTzdbProvider) -> alloc::string::String {
+        zdt.to_ixdtf_string_with_provider(
+            DisplayOffset::Auto,
+            DisplayTimeZone::Auto,
+            DisplayCalendar::Never,
+            ToStringRoundingOptions::default(),
+            provider,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn date_and_date_time_values() {
+        let date = PlainDate::from_icalendar(b"19970714").unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (1997, 7, 14));
+        assert_eq!(date.to_icalendar_string().unwrap(), "19970714");
+        assert!(PlainDate::from_icalendar(b"19970230").is_err());
+        assert!(PlainDate::from_icalendar(b"19970714T000000").is_err());
+        assert!(PlainDate::from_icalendar(b"1997-07-14").is_err());
+
+        let date_time = PlainDateTime::from_icalendar(b"19980118T230000").unwrap();
+        assert_eq!((date_time.hour(), date_time.minute()), (23, 0));
+        assert_eq!(date_time.to_icalendar_string().unwrap(), "19980118T230000");
+        assert!(PlainDateTime::from_icalendar(b"19980118T230000Z").is_err());
+        assert!(PlainDateTime::from_icalendar(b"19980118T240000").is_err());
+        assert!(PlainDateTime::from_icalendar(b"19980118T2300").is_err());
+
+        let leap_second = PlainDateTime::from_icalendar(b"19981231T235960").unwrap();
+        assert_eq!(leap_second.second(), 59);
+
+        let far_future = PlainDate::try_new_iso(10000, 1, 1).unwrap();
+        assert!(far_future.to_icalendar_string().is_err());
+    }
+
+    #[test]
+    fn zoned_date_time_values() {
+        let provider = &FsTzdbProvider::default();
+
+        let utc = ZonedDateTime::from_icalendar_with_provider(b"19980119T070000Z", None, provider)
+            .unwrap();
+        assert_eq!(ixdtf(&utc, provider), "1998-01-19T07:00:00+00:00[UTC]");
+        assert_eq!(utc.to_icalendar_string().unwrap(), "19980119T070000Z");
+        assert_eq!(
+            utc.to_icalendar_property("DTSTART").unwrap(),
+            "DTSTART:19980119T070000Z"
+        );
+
+        let local = ZonedDateTime::from_icalendar_with_provider(
+            b"19980119T020000",
+            Some("America/New_York"),
+            provider,
+        )
+        .unwrap();
+        assert_eq!(local.epoch_nanoseconds(), utc.epoch_nanoseconds());
+        assert_eq!(local.to_icalendar_string().unwrap(), "19980119T020000");
+        assert_eq!(
+            local.to_icalendar_property("DTSTART").unwrap(),
+            "DTSTART;TZID=America/New_York:19980119T020000"
+        );
+
+        // Nonexistent local times use the offset before the gap.
+        let gap = ZonedDateTime::from_icalendar_with_provider(
+            b"20070311T023000",
+            Some("America/New_York"),
+            provider,
+        )
+        .unwrap();
+        assert_eq!(
+            ixdtf(&gap, provider),
+            "2007-03-11T03:30:00-04:00[America/New_York]"
+        );
+
+        let offset = ZonedDateTime::from_utf8_with_provider(
+            b"2024-05-01T12:00+05:30[+05:30]",
+            Disambiguation::Compatible,
+            OffsetDisambiguation::Reject,
+            provider,
+        )
+        .unwrap();
+        assert_eq!(offset.to_icalendar_string().unwrap(), "20240501T063000Z");
+
+        assert!(ZonedDateTime::from_icalendar_with_provider(
+            b"19980119T070000Z",
+            Some("America/New_York"),
+            provider
+        )
+        .is_err());
+        assert!(
+            ZonedDateTime::from_icalendar_with_provider(b"19980119T070000", None, provider)
+                .is_err()
+        );
+        assert!(ZonedDateTime::from_icalendar_with_provider(
+            b"19980119T070000",
+            Some("Not/AZone"),
+            provider
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn duration_values() {
+        let duration = Duration::from_icalendar(b"P15DT5H0M20S").unwrap();
+        assert_eq!(duration, Duration::from_str("P15DT5H20S").unwrap());
+        assert_eq!(duration.to_icalendar_string().unwrap(), "P15DT5H0M20S");
+
+        let duration = Duration::from_icalendar(b"-PT15M").unwrap();
+        assert_eq!(duration, Duration::from_str("-PT15M").unwrap());
+        assert_eq!(duration.to_icalendar_string().unwrap(), "-PT15M");
+
+        let duration = Duration::from_icalendar(b"+P7W").unwrap();
+        assert_eq!(duration.weeks(), 7);
+        assert_eq!(duration.to_icalendar_string().unwrap(), "P7W");
+
+        assert_eq!(
+            Duration::from_str("P1W2D")
+                .unwrap()
+                .to_icalendar_string()
+                .unwrap(),
+            "P9D"
+        );
+        assert_eq!(Duration::default().to_icalendar_string().unwrap(), "PT0S");
+
+        for invalid in [
+            "", "P", "PT", "P1Y", "P1W2D", "P1WT1H", "PT1S1M", "PT1H5S", "P1DT", "PT1.5S", "P-1D",
+            "1D",
+        ] {
+            assert!(
+                Duration::from_icalendar(invalid.as_bytes()).is_err(),
+                "{invalid}"
+            );
+        }
+        assert!(Duration::from_str("P1M")
+            .unwrap()
+            .to_icalendar_string()
+            .is_err());
+        assert!(Duration::from_str("PT0.5S")
+            .unwrap()
+            .to_icalendar_string()
+            .is_err());
+    }
+
+    #[test]
+    fn period_values() {
+        let provider = &FsTzdbProvider::default();
+
+        let period = Period::from_icalendar_with_provider(
+            b"19970101T180000Z/19970102T070000Z",
+            None,
+            provider,
+        )
+        .unwrap();
+        assert!(matches!(period.end_value(), PeriodEnd::DateTime(_)));
+        assert_eq!(
+            period.to_icalendar_string().unwrap(),
+            "19970101T180000Z/19970102T070000Z"
+        );
+
+        let period = Period::from_icalendar_with_provider(
+            b"20240309T120000/P1D",
+            Some("America/New_York"),
+            provider,
+        )
+        .unwrap();
+        // The duration is added in the calendar, across the DST transition.
+        assert_eq!(
+            ixdtf(&period.end_with_provider(provider).unwrap(), provider),
+            "2024-03-10T12:00:00-04:00[America/New_York]"
+        );
+        assert_eq!(period.to_icalendar_string().unwrap(), "20240309T120000/P1D");
+
+        for invalid in [
+            "19970102T070000Z/19970101T180000Z",
+            "19970101T180000Z/-PT1H",
+            "19970101T180000Z",
+            "19970101/19970102",
+        ] {
+            assert!(
+                Period::from_icalendar_with_provider(invalid.as_bytes(), None, provider).is_err(),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn vtimezone() {
+        let provider = &FsTzdbProvider::default();
+        let start = Instant::from_str("2024-01-01T00:00Z").unwrap();
+        let end = Instant::from_str("2025-01-01T00:00Z").unwrap();
+
+        let new_york = TimeZone::try_from_str_with_provider("America/New_York", provider).unwrap();
+        let vtimezone = new_york
+            .to_vtimezone_with_provider(&start, &end, provider)
+            .unwrap();
+        assert_eq!(
+            vtimezone,
+            "BEGIN:VTIMEZONE\r\n\
+             TZID:America/New_York\r\n\
+             BEGIN:STANDARD\r\n\
+             DTSTART:20231105T020000\r\n\
+             TZOFFSETFROM:-0400\r\n\
+             TZOFFSETTO:-0500\r\n\
+             TZNAME:EST\r\n\
+             END:STANDARD\r\n\
+             BEGIN:DAYLIGHT\r\n\
+             DTSTART:20240310T020000\r\n\
+             TZOFFSETFROM:-0500\r\n\
+             TZOFFSETTO:-0400\r\n\
+             TZNAME:EDT\r\n\
+             END:DAYLIGHT\r\n\
+             BEGIN:STANDARD\r\n\
+             DTSTART:20241103T020000\r\n\
+             TZOFFSETFROM:-0400\r\n\
+             TZOFFSETTO:-0500\r\n\
+             TZNAME:EST\r\n\
+             END:STANDARD\r\n\
+             END:VTIMEZONE\r\n"
+        );
+
+        let offset = TimeZone::try_from_str_with_provider("+05:30", provider).unwrap();
+        assert_eq!(
+            offset
+                .to_vtimezone_with_provider(&start, &end, provider)
+                .unwrap(),
+            "BEGIN:VTIMEZONE\r\n\
+             TZID:+05:30\r\n\
+             BEGIN:STANDARD\r\n\
+             DTSTART:16010101T000000\r\n\
+             TZOFFSETFROM:+0530\r\n\
+             TZOFFSETTO:+0530\r\n\
+             END:STANDARD\r\n\
+             END:VTIMEZONE\r\n"
+        );
+
+        assert!(new_york
+            .to_vtimezone_with_provider(&end, &start, provider)
+            .is_err());
+    }
+}
@@ -11,16 +11,19 @@ use crate::{
         DifferenceOperation, DifferenceSettings, DisplayOffset, ResolvedRoundingOptions,
         RoundingOptions, ToStringRoundingOptions, Unit, UnitGroup,
     },
-    parsers::{parse_instant, IxdtfStringBuilder},
-    provider::TimeZoneProvider,
+    parsers::{
+        parse_http_date, parse_instant, parse_rfc2822, parse_rfc3339, Encoded, FormattableRfc2822,
+        FormattableRfc3339, IxdtfStringBuilder, Rfc2822Record, Rfc2822Zone, Rfc3339Record,
+    },
+    provider::{NeverProvider, TimeZoneProvider},
     rounding::IncrementRounder,
     unix_time::EpochNanoseconds,
-    Calendar, TemporalError, TemporalResult, TemporalUnwrap, TimeZone,
+    Calendar, TemporalError, TemporalResult, TemporalUnwrap, TimeZone, UtcOffset,
 };
 
 use ixdtf::records::UtcOffsetRecordOrZ;
 use num_traits::Euclid;
-use writeable::Writeable;
+use writeable::{impl_display_with_writeable, LengthHint, Writeable};
 
 use super::{
     duration::normalized::{InternalDurationRecord, TimeDuration},
@@ -297,7 +300,16 @@ impl Instant {
 
     // Converts a UTF-8 encoded string into a `Instant`.
     pub fn from_utf8(s: &[u8]) -> TemporalResult<Self> {
-        let ixdtf_record = parse_instant(s)?;
+        Self::from_encoded(Encoded::Utf8(s))
+    }
+
+    // Converts a UTF-16 encoded string into a `Instant`.
+    pub fn from_utf16(s: &[u16]) -> TemporalResult<Self> {
+        Self::from_encoded(Encoded::Utf16(s))
+    }
+
+    fn from_encoded(source: Encoded<'_>) -> TemporalResult<Self> {
+        let ixdtf_record = parse_instant(source)?;
 
         // Find the offset
         let ns_offset = match ixdtf_record.offset {
@@ -397,6 +409,137 @@ impl Instant {
     }
 }
 
+// ==== RFC 2822 and HTTP-date API ====
+
+impl Instant {
+    /// Parses an RFC 5322 (RFC 2822) date, e.g. `Fri, 21 Nov 1997 09:55:06 -0600`.
+    ///
+    /// Comments, folding white space and the obsolete syntax, including zone
+    /// names such as `EST` and `GMT`, are accepted.
+    pub fn from_rfc2822(source: &[u8]) -> TemporalResult<Self> {
+        Self::from_rfc2822_record(parse_rfc2822(source)?)
+    }
+
+    /// Parses an RFC 9110 HTTP-date, e.g. `Sun, 06 Nov 1994 08:49:37 GMT`.
+    ///
+    /// The obsolete RFC 850 and asctime formats are also accepted.
+    pub fn from_http_date(source: &[u8]) -> TemporalResult<Self> {
+        Self::from_rfc2822_record(parse_http_date(source)?)
+    }
+
+    pub(crate) fn from_rfc2822_record(record: Rfc2822Record) -> TemporalResult<Self> {
+        let local = IsoDateTime::new(record.date, record.time)?
+            .as_nanoseconds()
+            .0;
+        Self::try_new(local - i128::from(record.offset_minutes) * 60_000_000_000)
+    }
+
+    /// Returns this instant as an RFC 5322 date in UTC, e.g. `Fri, 21 Nov 1997 15:55:06 +0000`.
+    ///
+    /// Subsecond fields are truncated.
+    pub fn to_rfc2822_string(&self) -> TemporalResult<String> {
+        self.to_rfc2822_writeable()
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Returns a [`Writeable`] for the RFC 5322 date of this instant in UTC.
+    ///
+    /// See [`Instant::to_rfc2822_string`].
+    pub fn to_rfc2822_writeable(&self) -> TemporalResult<impl Writeable> {
+        let iso = IsoDateTime::from_epoch_nanos(&self.0, 0);
+        FormattableRfc2822::try_new(iso.date, iso.time, Rfc2822Zone::Offset(0))
+    }
+
+    /// Returns this instant as an RFC 9110 IMF-fixdate, e.g. `Sun, 06 Nov 1994 08:49:37 GMT`.
+    ///
+    /// Subsecond fields are truncated.
+    pub fn to_http_date_string(&self) -> TemporalResult<String> {
+        self.to_http_date_writeable()
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Returns a [`Writeable`] for the RFC 9110 IMF-fixdate of this instant.
+    ///
+    /// See [`Instant::to_http_date_string`].
+    pub fn to_http_date_writeable(&self) -> TemporalResult<impl Writeable> {
+        let iso = IsoDateTime::from_epoch_nanos(&self.0, 0);
+        FormattableRfc2822::try_new(iso.date, iso.time, Rfc2822Zone::Gmt)
+    }
+}
+
+// ==== RFC 3339 API ====
+
+impl Instant {
+    /// Parses a strict RFC 3339 date-time, e.g. `1985-04-12T23:20:50.52Z`.
+    ///
+    /// Unlike [`Instant::from_utf8`], annotations, minute-precision times,
+    /// expanded years and other RFC 9557 extensions are rejected, and a `Z`
+    /// or `±HH:MM` offset is required. A lowercase `t` and `z` are accepted.
+    pub fn from_rfc3339(source: &[u8]) -> TemporalResult<Self> {
+        Self::from_rfc3339_record(parse_rfc3339(source)?)
+    }
+
+    pub(crate) fn from_rfc3339_record(record: Rfc3339Record) -> TemporalResult<Self> {
+        let local = IsoDateTime::new(record.date, record.time)?
+            .as_nanoseconds()
+            .0;
+        let offset = i128::from(record.offset_minutes.unwrap_or(0));
+        Self::try_new(local - offset * i128::from(NANOSECONDS_PER_MINUTE))
+    }
+
+    /// Returns this instant as an RFC 3339 date-time, in UTC with a `Z` when
+    /// `timezone` is `None`.
+    ///
+    /// `options` must not round to minutes, and the offset of `timezone` must
+    /// be a whole number of minutes.
+    pub fn to_rfc3339_string_with_provider(
+        &self,
+        timezone: Option<&TimeZone>,
+        options: ToStringRoundingOptions,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<String> {
+        self.to_rfc3339_writeable_with_provider(timezone, options, provider)
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Returns a [`Writeable`] for the RFC 3339 date-time of this instant.
+    ///
+    /// See [`Instant::to_rfc3339_string_with_provider`].
+    pub fn to_rfc3339_writeable_with_provider(
+        &self,
+        timezone: Option<&TimeZone>,
+        options: ToStringRoundingOptions,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<impl Writeable> {
+        let resolved_options = options.resolve()?;
+        let round = self.round_instant(ResolvedRoundingOptions::from_to_string_options(
+            &resolved_options,
+        ))?;
+        let (offset_minutes, offset_nanos) = match timezone {
+            Some(timezone) => {
+                let nanoseconds = timezone.get_offset_nanos_for(round, provider)?;
+                if nanoseconds % i128::from(NANOSECONDS_PER_MINUTE) != 0 {
+                    return Err(TemporalError::range()
+                        .with_message("RFC 3339 date-times cannot represent sub-minute offsets."));
+                }
+                (
+                    Some((nanoseconds / i128::from(NANOSECONDS_PER_MINUTE)) as i16),
+                    nanoseconds,
+                )
+            }
+            None => (None, 0),
+        };
+        let datetime =
+            IsoDateTime::from_epoch_nanos(&Instant::try_new(round)?.0, offset_nanos as i64);
+        FormattableRfc3339::try_new(
+            datetime.date,
+            datetime.time,
+            offset_minutes,
+            resolved_options.precision,
+        )
+    }
+}
+
 // ==== Instant Provider API ====
 
 impl Instant {
@@ -431,7 +574,7 @@ impl Instant {
             datetime
         } else {
             ixdtf = ixdtf.with_z(DisplayOffset::Auto);
-            TimeZone::default().get_iso_datetime_for(&rounded_instant, provider)?
+            UtcOffset::from_minutes(0).get_iso_datetime_for(&rounded_instant)
         };
         let builder = ixdtf
             .with_date(datetime.date)
@@ -443,6 +586,26 @@ impl Instant {
 
 // ==== Utility Functions ====
 
+impl Writeable for Instant {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        let writeable =
+            self.to_ixdtf_writeable_with_provider(None, Default::default(), &NeverProvider);
+        debug_assert!(
+            writeable.is_ok(),
+            "Instant must return a valid string with default options."
+        );
+        writeable.map_err(|_| core::fmt::Error)?.write_to(sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        self.to_ixdtf_writeable_with_provider(None, Default::default(), &NeverProvider)
+            .map(|x| x.writeable_length_hint())
+            .unwrap_or(LengthHint::undefined())
+    }
+}
+
+impl_display_with_writeable!(Instant);
+
 impl FromStr for Instant {
     type Err = TemporalError;
 
@@ -460,10 +623,11 @@ mod tests {
 
     use crate::{
         builtins::{core::Instant, duration::duration_sign},
-        options::{DifferenceSettings, RoundingMode, Unit},
+        options::{DifferenceSettings, RoundingMode, ToStringRoundingOptions, Unit},
         partial::PartialDuration,
+        provider::NeverProvider,
         unix_time::EpochNanoseconds,
-        Duration, NS_MAX_INSTANT, NS_MIN_INSTANT,
+        Duration, TimeZone, UtcOffset, NS_MAX_INSTANT, NS_MIN_INSTANT,
     };
 
     #[test]
@@ -824,4 +988,131 @@ mod tests {
         assert_eq!(min.subtract(&large_duration).unwrap(), max);
         assert_eq!(max.add(&large_duration).unwrap(), min);
     }
+
+    #[test]
+    fn rfc2822_dates() {
+        let expected = Instant::from_str("1997-11-21T15:55:06Z").unwrap();
+        for source in [
+            "Fri, 21 Nov 1997 09:55:06 -0600",
+            "21 Nov 1997 09:55:06 -0600",
+            "fri, 21 nov 1997 09:55:06 CST",
+            "Fri, 21 Nov 97 15:55:06 GMT",
+            "Fri, 21 Nov 1997 15:55:06 UT",
+            "Fri, 21 Nov 097 15:55:06 Z",
+            "Fri,21 Nov 1997 15:55:06 -0000",
+            "(comment) Fri (day (nested)) , 21\r\n Nov 1997 15 : 55 : 06 +0000 (UTC)",
+            // Folding whitespace: CRLF followed by a space or a tab.
+            "Fri, 21 Nov 1997\r\n 09:55:06\r\n\t-0600",
+            "Fri, 21 Nov 1997 15:55:06 +0000 (folded\r\n comment)",
+        ] {
+            assert_eq!(
+                Instant::from_rfc2822(source.as_bytes()).unwrap(),
+                expected,
+                "{source}"
+            );
+        }
+        // Seconds are optional.
+        assert_eq!(
+            Instant::from_rfc2822(b"21 Nov 1997 15:55 +0000").unwrap(),
+            Instant::from_str("1997-11-21T15:55Z").unwrap()
+        );
+        assert_eq!(
+            Instant::from_rfc2822(b"1 Jan 49 00:00 +0000").unwrap(),
+            Instant::from_str("2049-01-01T00:00Z").unwrap()
+        );
+        assert_eq!(
+            expected.to_rfc2822_string().unwrap(),
+            "Fri, 21 Nov 1997 15:55:06 +0000"
+        );
+        assert!(Instant::from_str("-000001-01-01T00:00Z")
+            .unwrap()
+            .to_rfc2822_string()
+            .is_err());
+    }
+
+    #[test]
+    fn http_dates() {
+        let expected = Instant::from_str("1994-11-06T08:49:37Z").unwrap();
+        for source in [
+            "Sun, 06 Nov 1994 08:49:37 GMT",
+            "Sunday, 06-Nov-94 08:49:37 GMT",
+            "Sun Nov  6 08:49:37 1994",
+        ] {
+            assert_eq!(
+                Instant::from_http_date(source.as_bytes()).unwrap(),
+                expected,
+                "{source}"
+            );
+        }
+        assert_eq!(
+            expected.to_http_date_string().unwrap(),
+            "Sun, 06 Nov 1994 08:49:37 GMT"
+        );
+        assert_eq!(
+            Instant::from_http_date(b"Thu Nov 21 08:49:37 2024")
+                .unwrap()
+                .to_http_date_string()
+                .unwrap(),
+            "Thu, 21 Nov 2024 08:49:37 GMT"
+        );
+        assert!(Instant::from_http_date(b"Mon, 06 Nov 1994 08:49:37 GMT").is_err());
+    }
+
+    #[test]
+    fn rfc3339_dates() {
+        let expected = Instant::from_str("1985-04-12T23:20:50.52Z").unwrap();
+        for source in [
+            "1985-04-12T23:20:50.52Z",
+            "1985-04-12t23:20:50.520z",
+            "1985-04-12T18:20:50.52-05:00",
+            "1985-04-12T23:20:50.52-00:00",
+        ] {
+            assert_eq!(
+                Instant::from_rfc3339(source.as_bytes()).unwrap(),
+                expected,
+                "{source}"
+            );
+        }
+        // Valid RFC 9557 strings outside of RFC 3339.
+        for source in [
+            "1985-04-12T23:20:50.52Z[UTC]",
+            "1985-04-12T23:20Z",
+            "+001985-04-12T23:20:50.52Z",
+            "19850412T232050Z",
+        ] {
+            assert!(Instant::from_str(source).is_ok(), "{source}");
+            assert!(
+                Instant::from_rfc3339(source.as_bytes()).is_err(),
+                "{source}"
+            );
+        }
+        assert!(Instant::from_rfc3339(b"1985-04-12T23:20:50.52").is_err());
+
+        let provider = &NeverProvider;
+        let to_string = |timezone, options| {
+            expected.to_rfc3339_string_with_provider(timezone, options, provider)
+        };
+        assert_eq!(
+            to_string(None, ToStringRoundingOptions::default()).unwrap(),
+            "1985-04-12T23:20:50.52Z"
+        );
+        let offset = TimeZone::UtcOffset(UtcOffset::from_minutes(-300));
+        let seconds = ToStringRoundingOptions {
+            smallest_unit: Some(Unit::Second),
+            ..Default::default()
+        };
+        assert_eq!(
+            to_string(Some(&offset), seconds).unwrap(),
+            "1985-04-12T18:20:50-05:00"
+        );
+        let minutes = ToStringRoundingOptions {
+            smallest_unit: Some(Unit::Minute),
+            ..Default::default()
+        };
+        assert!(to_string(None, minutes).is_err());
+        assert!(Instant::from_str("+010000-01-01T00:00Z")
+            .unwrap()
+            .to_rfc3339_string_with_provider(None, ToStringRoundingOptions::default(), provider)
+            .is_err());
+    }
 }
@@ -0,0 +1,920 @@
+//! ISO 8601 time intervals and repeating intervals.
+//!
+//! An [`Interval`] is written in one of four forms:
+//!
+//! - `<start>/<end>`, e.g. `2025-01-01T00:00Z/2025-02-01T00:00Z`
+//! - `<start>/<duration>`, e.g. `2025-01-01/P1M`
+//! - `<duration>/<end>`, e.g. `P1D/2025-03-01`
+//! - `<duration>`, e.g. `PT36H`, a duration without context
+//!
+//! A [`RepeatingInterval`] prefixes an interval with `R<n>/`, or `R/` when it
+//! repeats without bound, e.g. `R5/2025-01-01T09:00Z/PT1H`.
+//!
+//! The bounds may be [`Instant`]s, [`PlainDate`]s, [`PlainDateTime`]s or
+//! [`ZonedDateTime`]s. Durations are added with the bound's `add` semantics,
+//! so `2025-01-31/P1M` ends on `2025-02-28`.
+
+use alloc::string::String;
+use core::{cmp::Ordering, fmt};
+
+use writeable::{LengthHint, Writeable};
+
+use crate::{
+    builtins::core::{Duration, Instant, PlainDate, PlainDateTime, ZonedDateTime},
+    options::{
+        DifferenceSettings, Disambiguation, DisplayCalendar, DisplayOffset, DisplayTimeZone,
+        OffsetDisambiguation,
+    },
+    parsers::parse_ascii_digits,
+    provider::TimeZoneProvider,
+    Sign, TemporalError, TemporalResult,
+};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A point in time that can bound an [`Interval`].
+///
+/// This trait is sealed and implemented for [`Instant`], [`PlainDate`],
+/// [`PlainDateTime`] and [`ZonedDateTime`].
+pub trait IntervalPoint: Clone + private::Sealed {
+    /// Parses a bound of an interval.
+    fn parse_point(source: &[u8], provider: &impl TimeZoneProvider) -> TemporalResult<Self>;
+
+    /// Adds `duration` to this point.
+    fn add_duration(
+        &self,
+        duration: &Duration,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Self>;
+
+    /// Subtracts `duration` from this point.
+    fn subtract_duration(
+        &self,
+        duration: &Duration,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Self>;
+
+    /// Returns the duration from this point until `other`.
+    fn duration_until(
+        &self,
+        other: &Self,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Duration>;
+
+    /// Compares this point with `other`.
+    fn compare_point(&self, other: &Self) -> Ordering;
+
+    /// Returns a [`Writeable`] for this point as an IXDTF string.
+    fn point_writeable(
+        &self,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<impl Writeable + '_>;
+}
+
+impl private::Sealed for Instant {}
+
+impl IntervalPoint for Instant {
+    fn parse_point(source: &[u8], _: &impl TimeZoneProvider) -> TemporalResult<Self> {
+        Self::from_utf8(source)
+    }
+
+    fn add_duration(&self, duration: &Duration, _: &impl TimeZoneProvider) -> TemporalResult<Self> {
+        self.add(duration)
+    }
+
+    fn subtract_duration(
+        &self,
+        duration: &Duration,
+        _: &impl TimeZoneProvider,
+    ) -> TemporalResult<Self> {
+        self.subtract(duration)
+    }
+
+    fn duration_until(&self, other: &Self, _: &impl TimeZoneProvider) -> TemporalResult<Duration> {
+        self.until(other, DifferenceSettings::default())
+    }
+
+    fn compare_point(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+
+    fn point_writeable(&self, _: &impl TimeZoneProvider) -> TemporalResult<impl Writeable + '_> {
+        Ok(self)
+    }
+}
+
+impl private::Sealed for PlainDate {}
+
+impl IntervalPoint for PlainDate {
+    fn parse_point(source: &[u8], _: &impl TimeZoneProvider) -> TemporalResult<Self> {
+        Self::from_utf8(source)
+    }
+
+    fn add_duration(&self, duration: &Duration, _: &impl TimeZoneProvider) -> TemporalResult<Self> {
+        self.add(duration, None)
+    }
+
+    fn subtract_duration(
+        &self,
+        duration: &Duration,
+        _: &impl TimeZoneProvider,
+    ) -> TemporalResult<Self> {
+        self.subtract(duration, None)
+    }
+
+    fn duration_until(&self, other: &Self, _: &impl TimeZoneProvider) -> TemporalResult<Duration> {
+        self.until(other, DifferenceSettings::default())
+    }
+
+    fn compare_point(&self, other: &Self) -> Ordering {
+        self.compare_iso(other)
+    }
+
+    fn point_writeable(&self, _: &impl TimeZoneProvider) -> TemporalResult<impl Writeable + '_> {
+        Ok(self)
+    }
+}
+
+impl private::Sealed for PlainDateTime {}
+
+impl IntervalPoint for PlainDateTime {
+    fn parse_point(source: &[u8], _: &impl TimeZoneProvider) -> TemporalResult<Self> {
+        Self::from_utf8(source)
+    }
+
+    fn add_duration(&self, duration: &Duration, _: &impl TimeZoneProvider) -> TemporalResult<Self> {
+        self.add(duration, None)
+    }
+
+    fn subtract_duration(
+        &self,
+        duration: &Duration,
+        _: &impl TimeZoneProvider,
+    ) -> TemporalResult<Self> {
+        self.subtract(duration, None)
+    }
+
+    fn duration_until(&self, other: &Self, _: &impl TimeZoneProvider) -> TemporalResult<Duration> {
+        self.until(other, DifferenceSettings::default())
+    }
+
+    fn compare_point(&self, other: &Self) -> Ordering {
+        self.compare_iso(other)
+    }
+
+    fn point_writeable(&self, _: &impl TimeZoneProvider) -> TemporalResult<impl Writeable + '_> {
+        Ok(self)
+    }
+}
+
+impl private::Sealed for ZonedDateTime {}
+
+impl IntervalPoint for ZonedDateTime {
+    fn parse_point(source: &[u8], provider: &impl TimeZoneProvider) -> TemporalResult<Self> {
+        Self::from_utf8_with_provider(
+            source,
+            Disambiguation::Compatible,
+            OffsetDisambiguation::Reject,
+            provider,
+        )
+    }
+
+    fn add_duration(
+        &self,
+        duration: &Duration,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Self> {
+        self.add_with_provider(duration, None, provider)
+    }
+
+    fn subtract_duration(
+        &self,
+        duration: &Duration,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Self> {
+        self.subtract_with_provider(duration, None, provider)
+    }
+
+    fn duration_until(
+        &self,
+        other: &Self,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Duration> {
+        self.until_with_provider(other, DifferenceSettings::default(), provider)
+    }
+
+    fn compare_point(&self, other: &Self) -> Ordering {
+        self.compare_instant(other)
+    }
+
+    fn point_writeable(
+        &self,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<impl Writeable + '_> {
+        self.to_ixdtf_writeable_with_provider(
+            DisplayOffset::Auto,
+            DisplayTimeZone::Auto,
+            DisplayCalendar::Auto,
+            Default::default(),
+            provider,
+        )
+    }
+}
+
+// ==== Interval ====
+
+/// The form in which an [`Interval`] was specified.
+#[derive(Debug, Clone)]
+pub enum IntervalForm<T> {
+    /// A start and an end, as in `2025-01-01T00:00Z/2025-02-01T00:00Z`.
+    StartEnd(T, T),
+    /// A start and a duration, as in `2025-01-01/P1M`.
+    StartDuration(T, Duration),
+    /// A duration and an end, as in `P1D/2025-03-01`.
+    DurationEnd(Duration, T),
+    /// A duration without context, as in `PT36H`.
+    Duration(Duration),
+}
+
+/// An ISO 8601 time interval.
+#[derive(Debug, Clone)]
+pub struct Interval<T> {
+    form: IntervalForm<T>,
+}
+
+impl<T: IntervalPoint> Interval<T> {
+    /// Creates an interval, rejecting an end before the start or a negative duration.
+    pub fn try_new(form: IntervalForm<T>) -> TemporalResult<Self> {
+        let valid = match &form {
+            IntervalForm::StartEnd(start, end) => start.compare_point(end) != Ordering::Greater,
+            IntervalForm::StartDuration(_, duration)
+            | IntervalForm::DurationEnd(duration, _)
+            | IntervalForm::Duration(duration) => duration.sign() != Sign::Negative,
+        };
+        if !valid {
+            return Err(TemporalError::range()
+                .with_message("The end of an interval must not precede its start."));
+        }
+        Ok(Self { form })
+    }
+
+    /// Returns the interval as it was specified.
+    pub fn form(&self) -> &IntervalForm<T> {
+        &self.form
+    }
+
+    /// Returns the start of the interval, subtracting the duration from the
+    /// end if needed, or `None` for a duration without context.
+    pub fn start_with_provider(
+        &self,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Option<T>> {
+        match &self.form {
+            IntervalForm::StartEnd(start, _) | IntervalForm::StartDuration(start, _) => {
+                Ok(Some(start.clone()))
+            }
+            IntervalForm::DurationEnd(duration, end) => {
+                end.subtract_duration(duration, provider).map(Some)
+            }
+            IntervalForm::Duration(_) => Ok(None),
+        }
+    }
+
+    /// Returns the end of the interval, adding the duration to the start if
+    /// needed, or `None` for a duration without context.
+    pub fn end_with_provider(&self, provider: &impl TimeZoneProvider) -> TemporalResult<Option<T>> {
+        match &self.form {
+            IntervalForm::StartEnd(_, end) | IntervalForm::DurationEnd(_, end) => {
+                Ok(Some(end.clone()))
+            }
+            IntervalForm::StartDuration(start, duration) => {
+                start.add_duration(duration, provider).map(Some)
+            }
+            IntervalForm::Duration(_) => Ok(None),
+        }
+    }
+
+    /// Returns the duration of the interval, computing it from the start and
+    /// the end if needed.
+    pub fn duration_with_provider(
+        &self,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Duration> {
+        match &self.form {
+            IntervalForm::StartEnd(start, end) => start.duration_until(end, provider),
+            IntervalForm::StartDuration(_, duration)
+            | IntervalForm::DurationEnd(duration, _)
+            | IntervalForm::Duration(duration) => Ok(*duration),
+        }
+    }
+
+    /// Parses an ISO 8601 interval in any of its four forms.
+    pub fn from_utf8_with_provider(
+        source: &[u8],
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Self> {
+        let Some((first, second)) = split_interval(source) else {
+            return Self::try_new(IntervalForm::Duration(Duration::from_utf8(source)?));
+        };
+        let form = match (is_duration(first), is_duration(second)) {
+            (false, false) => IntervalForm::StartEnd(
+                T::parse_point(first, provider)?,
+                T::parse_point(second, provider)?,
+            ),
+            (false, true) => IntervalForm::StartDuration(
+                T::parse_point(first, provider)?,
+                Duration::from_utf8(second)?,
+            ),
+            (true, false) => IntervalForm::DurationEnd(
+                Duration::from_utf8(first)?,
+                T::parse_point(second, provider)?,
+            ),
+            (true, true) => {
+                return Err(TemporalError::range()
+                    .with_message("An interval cannot consist of two durations."))
+            }
+        };
+        Self::try_new(form)
+    }
+
+    /// Returns this interval as an ISO 8601 string in the form it was specified.
+    pub fn to_ixdtf_string_with_provider(
+        &self,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<String> {
+        self.to_ixdtf_writeable_with_provider(provider)
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Returns a [`Writeable`] for this interval as an ISO 8601 string in the
+    /// form it was specified.
+    pub fn to_ixdtf_writeable_with_provider<'a>(
+        &'a self,
+        provider: &'a impl TimeZoneProvider,
+    ) -> TemporalResult<impl Writeable + 'a> {
+        let (start, duration, end) = match &self.form {
+            IntervalForm::StartEnd(start, end) => (Some(start), None, Some(end)),
+            IntervalForm::StartDuration(start, duration) => (Some(start), Some(duration), None),
+            IntervalForm::DurationEnd(duration, end) => (None, Some(duration), Some(end)),
+            IntervalForm::Duration(duration) => (None, Some(duration), None),
+        };
+        Ok(FormattableInterval {
+            start: start.map(|x| x.point_writeable(provider)).transpose()?,
+            duration,
+            end: end.map(|x| x.point_writeable(provider)).transpose()?,
+        })
+    }
+
+    /// Parses an ISO 8601 interval in any of its four forms.
+    #[cfg(feature = "compiled_data")]
+    pub fn from_utf8(source: &[u8]) -> TemporalResult<Self> {
+        Self::from_utf8_with_provider(source, &*crate::builtins::TZ_PROVIDER)
+    }
+
+    /// Returns the start of the interval, or `None` for a duration without context.
+    #[cfg(feature = "compiled_data")]
+    pub fn start(&self) -> TemporalResult<Option<T>> {
+        self.start_with_provider(&*crate::builtins::TZ_PROVIDER)
+    }
+
+    /// Returns the end of the interval, or `None` for a duration without context.
+    #[cfg(feature = "compiled_data")]
+    pub fn end(&self) -> TemporalResult<Option<T>> {
+        self.end_with_provider(&*crate::builtins::TZ_PROVIDER)
+    }
+
+    /// Returns the duration of the interval.
+    #[cfg(feature = "compiled_data")]
+    pub fn duration(&self) -> TemporalResult<Duration> {
+        self.duration_with_provider(&*crate::builtins::TZ_PROVIDER)
+    }
+
+    /// Returns this interval as an ISO 8601 string.
+    #[cfg(feature = "compiled_data")]
+    pub fn to_ixdtf_string(&self) -> TemporalResult<String> {
+        self.to_ixdtf_string_with_provider(&*crate::builtins::TZ_PROVIDER)
+    }
+}
+
+#[cfg(feature = "compiled_data")]
+impl<T: IntervalPoint> core::str::FromStr for Interval<T> {
+    type Err = TemporalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_utf8(s.as_bytes())
+    }
+}
+
+// Splits an interval at its solidus, skipping those in bracketed annotations
+// such as `[Europe/Paris]`.
+fn split_interval(source: &[u8]) -> Option<(&[u8], &[u8])> {
+    let mut depth = 0usize;
+    let index = source.iter().position(|byte| {
+        match byte {
+            b'[' => depth += 1,
+            b']' => depth = depth.saturating_sub(1),
+            b'/' => return depth == 0,
+            _ => {}
+        }
+        false
+    })?;
+    Some((source.get(..index)?, source.get(index + 1..)?))
+}
+
+fn is_duration(source: &[u8]) -> bool {
+    matches!(source, [b'P' | b'p', ..] | [b'+' | b'-', b'P' | b'p', ..])
+}
+
+// ==== RepeatingInterval ====
+
+/// An ISO 8601 repeating interval, such as `R5/2025-01-01T09:00Z/PT1H`.
+#[derive(Debug, Clone)]
+pub struct RepeatingInterval<T> {
+    repetitions: Option<u32>,
+    interval: Interval<T>,
+}
+
+impl<T: IntervalPoint> RepeatingInterval<T> {
+    /// Creates a repeating interval of `repetitions` intervals, or an
+    /// unbounded one when `repetitions` is `None`.
+    ///
+    /// The interval must not be empty.
+    pub fn try_new(repetitions: Option<u32>, interval: Interval<T>) -> TemporalResult<Self> {
+        let empty = match interval.form() {
+            IntervalForm::StartEnd(start, end) => start.compare_point(end) == Ordering::Equal,
+            IntervalForm::StartDuration(_, duration)
+            | IntervalForm::DurationEnd(duration, _)
+            | IntervalForm::Duration(duration) => duration.is_zero(),
+        };
+        if empty {
+            return Err(
+                TemporalError::range().with_message("A repeating interval must not be empty.")
+            );
+        }
+        Ok(Self {
+            repetitions,
+            interval,
+        })
+    }
+
+    /// Returns the number of repetitions, or `None` if unbounded.
+    pub fn repetitions(&self) -> Option<u32> {
+        self.repetitions
+    }
+
+    /// Returns the repeated interval.
+    pub fn interval(&self) -> &Interval<T> {
+        &self.interval
+    }
+
+    /// Parses an ISO 8601 repeating interval.
+    pub fn from_utf8_with_provider(
+        source: &[u8],
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Self> {
+        let invalid = || TemporalError::range().with_message("Invalid repeating interval.");
+        let [b'R', rest @ ..] = source else {
+            return Err(invalid());
+        };
+        let separator = rest.iter().position(|b| *b == b'/').ok_or_else(invalid)?;
+        let (count, interval) = rest.split_at(separator);
+        let repetitions = match count {
+            [] => None,
+            digits => Some(parse_ascii_digits(digits).ok_or_else(invalid)?),
+        };
+        let interval =
+            Interval::from_utf8_with_provider(interval.get(1..).ok_or_else(invalid)?, provider)?;
+        Self::try_new(repetitions, interval)
+    }
+
+    /// Returns this repeating interval as an ISO 8601 string.
+    pub fn to_ixdtf_string_with_provider(
+        &self,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<String> {
+        self.to_ixdtf_writeable_with_provider(provider)
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Returns a [`Writeable`] for this repeating interval as an ISO 8601 string.
+    pub fn to_ixdtf_writeable_with_provider<'a>(
+        &'a self,
+        provider: &'a impl TimeZoneProvider,
+    ) -> TemporalResult<impl Writeable + 'a> {
+        Ok(FormattableRepeatingInterval {
+            repetitions: self.repetitions,
+            interval: self.interval.to_ixdtf_writeable_with_provider(provider)?,
+        })
+    }
+
+    /// Returns the intervals of this repeating interval.
+    ///
+    /// Each interval starts where the previous one ends. When the interval is
+    /// given by a duration and an end, that end closes the last interval and
+    /// the intervals are returned from the latest to the earliest. A duration
+    /// without context cannot be expanded.
+    pub fn occurrences_with_provider<'a, P: TimeZoneProvider>(
+        &self,
+        provider: &'a P,
+    ) -> TemporalResult<IntervalOccurrences<'a, T, P>> {
+        let (anchor, duration, backward) = match self.interval.form() {
+            IntervalForm::StartEnd(start, end) => {
+                (start.clone(), start.duration_until(end, provider)?, false)
+            }
+            IntervalForm::StartDuration(start, duration) => (start.clone(), *duration, false),
+            IntervalForm::DurationEnd(duration, end) => (end.clone(), *duration, true),
+            IntervalForm::Duration(_) => {
+                return Err(TemporalError::range()
+                    .with_message("A repeating duration without context cannot be expanded."))
+            }
+        };
+        Ok(IntervalOccurrences {
+            provider,
+            boundary: anchor.clone(),
+            anchor,
+            index: 0,
+            duration,
+            backward,
+            remaining: self.repetitions,
+            failed: false,
+        })
+    }
+
+    /// Parses an ISO 8601 repeating interval.
+    #[cfg(feature = "compiled_data")]
+    pub fn from_utf8(source: &[u8]) -> TemporalResult<Self> {
+        Self::from_utf8_with_provider(source, &*crate::builtins::TZ_PROVIDER)
+    }
+
+    /// Returns this repeating interval as an ISO 8601 string.
+    #[cfg(feature = "compiled_data")]
+    pub fn to_ixdtf_string(&self) -> TemporalResult<String> {
+        self.to_ixdtf_string_with_provider(&*crate::builtins::TZ_PROVIDER)
+    }
+
+    /// Returns the intervals of this repeating interval.
+    #[cfg(feature = "compiled_data")]
+    pub fn occurrences(
+        &self,
+    ) -> TemporalResult<IntervalOccurrences<'static, T, crate::tzdb::CompiledTzdbProvider>> {
+        self.occurrences_with_provider(&*crate::builtins::TZ_PROVIDER)
+    }
+}
+
+#[cfg(feature = "compiled_data")]
+impl<T: IntervalPoint> core::str::FromStr for RepeatingInterval<T> {
+    type Err = TemporalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_utf8(s.as_bytes())
+    }
+}
+
+/// The [`Writeable`] of an [`Interval`], with the bounds resolved to their
+/// own writeables.
+struct FormattableInterval<'a, W> {
+    start: Option<W>,
+    duration: Option<&'a Duration>,
+    end: Option<W>,
+}
+
+impl<W: Writeable> Writeable for FormattableInterval<'_, W> {
+    fn write_to<S: fmt::Write + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        if let Some(start) = &self.start {
+            start.write_to(sink)?;
+        }
+        if let Some(duration) = self.duration {
+            if self.start.is_some() {
+                sink.write_char('/')?;
+            }
+            duration.write_to(sink)?;
+        }
+        if let Some(end) = &self.end {
+            sink.write_char('/')?;
+            end.write_to(sink)?;
+        }
+        Ok(())
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        let start = self
+            .start
+            .as_ref()
+            .map_or(LengthHint::exact(0), Writeable::writeable_length_hint);
+        let duration = self
+            .duration
+            .map_or(LengthHint::exact(0), Writeable::writeable_length_hint);
+        let end = self
+            .end
+            .as_ref()
+            .map_or(LengthHint::exact(0), Writeable::writeable_length_hint);
+        // A separator for every pair of adjacent parts.
+        let parts = usize::from(self.start.is_some())
+            + usize::from(self.duration.is_some())
+            + usize::from(self.end.is_some());
+        start + duration + end + parts.saturating_sub(1)
+    }
+}
+
+/// The [`Writeable`] of a [`RepeatingInterval`].
+struct FormattableRepeatingInterval<I> {
+    repetitions: Option<u32>,
+    interval: I,
+}
+
+impl<I: Writeable> Writeable for FormattableRepeatingInterval<I> {
+    fn write_to<S: fmt::Write + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        sink.write_char('R')?;
+        if let Some(repetitions) = self.repetitions {
+            repetitions.write_to(sink)?;
+        }
+        sink.write_char('/')?;
+        self.interval.write_to(sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        let repetitions = self
+            .repetitions
+            .map_or(LengthHint::exact(0), |x| x.writeable_length_hint());
+        repetitions + self.interval.writeable_length_hint() + 2
+    }
+}
+
+#[cfg(feature = "compiled_data")]
+impl<T: IntervalPoint> Writeable for Interval<T> {
+    /// The [`Writeable`] implementation for `Interval`.
+    ///
+    /// Enable with the `compiled_data` feature flag.
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.to_ixdtf_writeable_with_provider(&*crate::builtins::TZ_PROVIDER)
+            .map_err(|_| fmt::Error)?
+            .write_to(sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        self.to_ixdtf_writeable_with_provider(&*crate::builtins::TZ_PROVIDER)
+            .map_or(LengthHint::undefined(), |x| x.writeable_length_hint())
+    }
+}
+
+#[cfg(feature = "compiled_data")]
+impl<T: IntervalPoint> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+#[cfg(feature = "compiled_data")]
+impl<T: IntervalPoint> Writeable for RepeatingInterval<T> {
+    /// The [`Writeable`] implementation for `RepeatingInterval`.
+    ///
+    /// Enable with the `compiled_data` feature flag.
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.to_ixdtf_writeable_with_provider(&*crate::builtins::TZ_PROVIDER)
+            .map_err(|_| fmt::Error)?
+            .write_to(sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        self.to_ixdtf_writeable_with_provider(&*crate::builtins::TZ_PROVIDER)
+            .map_or(LengthHint::undefined(), |x| x.writeable_length_hint())
+    }
+}
+
+#[cfg(feature = "compiled_data")]
+impl<T: IntervalPoint> fmt::Display for RepeatingInterval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+/// An iterator over the intervals of a [`RepeatingInterval`].
+///
+/// Created by [`RepeatingInterval::occurrences_with_provider`]. Every item is
+/// in the [`IntervalForm::StartEnd`] form. Iteration stops after the first
+/// error.
+#[derive(Debug)]
+pub struct IntervalOccurrences<'a, T, P> {
+    provider: &'a P,
+    anchor: T,
+    /// The boundary `anchor ± index × duration` that the next interval starts
+    /// or ends at.
+    boundary: T,
+    index: i64,
+    duration: Duration,
+    backward: bool,
+    remaining: Option<u32>,
+    failed: bool,
+}
+
+impl<T: IntervalPoint, P: TimeZoneProvider> IntervalOccurrences<'_, T, P> {
+    fn step(&mut self) -> TemporalResult<Interval<T>> {
+        // Each boundary is computed from the anchor rather than the previous
+        // boundary, so that constrained month days do not drift.
+        let index = self
+            .index
+            .checked_add(1)
+            .ok_or_else(|| TemporalError::range().with_message("Too many repetitions."))?;
+        let duration = scale_duration(&self.duration, index)?;
+        let next = if self.backward {
+            self.anchor.subtract_duration(&duration, self.provider)?
+        } else {
+            self.anchor.add_duration(&duration, self.provider)?
+        };
+        let previous = core::mem::replace(&mut self.boundary, next.clone());
+        self.index = index;
+        let form = if self.backward {
+            IntervalForm::StartEnd(next, previous)
+        } else {
+            IntervalForm::StartEnd(previous, next)
+        };
+        Ok(Interval { form })
+    }
+}
+
+/// Returns `duration` multiplied by `factor`.
+fn scale_duration(duration: &Duration, factor: i64) -> TemporalResult<Duration> {
+    let scale = |value: i64| {
+        value
+            .checked_mul(factor)
+            .ok_or_else(|| TemporalError::range().with_message("Duration is out of range."))
+    };
+    let scale_wide = |value: i128| {
+        value
+            .checked_mul(i128::from(factor))
+            .ok_or_else(|| TemporalError::range().with_message("Duration is out of range."))
+    };
+    Duration::new(
+        scale(duration.years())?,
+        scale(duration.months())?,
+        scale(duration.weeks())?,
+        scale(duration.days())?,
+        scale(duration.hours())?,
+        scale(duration.minutes())?,
+        scale(duration.seconds())?,
+        scale(duration.milliseconds())?,
+        scale_wide(duration.microseconds())?,
+        scale_wide(duration.nanoseconds())?,
+    )
+}
+
+impl<T: IntervalPoint, P: TimeZoneProvider> Iterator for IntervalOccurrences<'_, T, P> {
+    type Item = TemporalResult<Interval<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.remaining == Some(0) {
+            return None;
+        }
+        let result = self.step();
+        match &result {
+            Ok(_) => self.remaining = self.remaining.map(|n| n - 1),
+            Err(_) => self.failed = true,
+        }
+        Some(result)
+    }
+}
+
+#[cfg(all(test, feature = "compiled_data"))]
+mod tests {
+    use alloc::{
+        format,
+        string::{String, ToString},
+        vec::Vec,
+    };
+    use core::str::FromStr;
+    use writeable::assert_writeable_eq;
+
+    use super::{Interval, IntervalForm, RepeatingInterval};
+    use crate::{Duration, Instant, PlainDate, PlainDateTime, ZonedDateTime};
+
+    #[test]
+    fn interval_forms_round_trip() {
+        let interval =
+            Interval::<Instant>::from_str("2025-01-01T00:00Z/2025-02-01T00:00Z").unwrap();
+        assert!(matches!(interval.form(), IntervalForm::StartEnd(..)));
+        assert_eq!(
+            interval.to_ixdtf_string().unwrap(),
+            "2025-01-01T00:00:00Z/2025-02-01T00:00:00Z"
+        );
+        assert_eq!(
+            interval.duration().unwrap(),
+            Duration::from_str("PT2678400S").unwrap()
+        );
+
+        let interval = Interval::<PlainDate>::from_str("2025-01-31/P1M").unwrap();
+        assert!(matches!(interval.form(), IntervalForm::StartDuration(..)));
+        assert_writeable_eq!(interval, "2025-01-31/P1M");
+        assert_eq!(
+            interval.end().unwrap(),
+            Some(PlainDate::from_str("2025-02-28").unwrap())
+        );
+
+        let interval = Interval::<PlainDate>::from_str("P1D/2025-03-01").unwrap();
+        assert!(matches!(interval.form(), IntervalForm::DurationEnd(..)));
+        assert_writeable_eq!(interval, "P1D/2025-03-01");
+        assert_eq!(
+            interval.start().unwrap(),
+            Some(PlainDate::from_str("2025-02-28").unwrap())
+        );
+
+        let interval = Interval::<PlainDateTime>::from_str("PT36H").unwrap();
+        assert!(matches!(interval.form(), IntervalForm::Duration(_)));
+        assert_writeable_eq!(interval, "PT36H");
+        assert_eq!(interval.start().unwrap(), None);
+        assert_eq!(interval.end().unwrap(), None);
+
+        let source = "2025-03-29T12:00:00+01:00[Europe/Paris]/P1D";
+        let interval = Interval::<ZonedDateTime>::from_str(source).unwrap();
+        assert_writeable_eq!(interval, source);
+        let end = interval.end().unwrap().unwrap();
+        assert_eq!(end.to_string(), "2025-03-30T12:00:00+02:00[Europe/Paris]");
+    }
+
+    #[test]
+    fn invalid_intervals() {
+        for invalid in [
+            "2025-02-01/2025-01-01",
+            "P1D/P2D",
+            "2025-01-01/-P1D",
+            "2025-01-01/",
+            "2025-01-01/2025-02-01/P1D",
+        ] {
+            assert!(
+                Interval::<PlainDate>::from_str(invalid).is_err(),
+                "{invalid}"
+            );
+        }
+        for invalid in [
+            "5/2025-01-01/P1D",
+            "R-1/2025-01-01/P1D",
+            "R5",
+            "R2/2025-01-01/PT0S",
+        ] {
+            assert!(
+                RepeatingInterval::<PlainDate>::from_str(invalid).is_err(),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn repeating_interval_expansion() {
+        let collect = |repeating: &RepeatingInterval<Instant>| {
+            repeating
+                .occurrences()
+                .unwrap()
+                .map(|interval| interval.unwrap().to_ixdtf_string().unwrap())
+                .collect::<Vec<String>>()
+        };
+
+        let repeating =
+            RepeatingInterval::<Instant>::from_str("R3/2025-01-01T09:00Z/PT1H").unwrap();
+        assert_eq!(repeating.repetitions(), Some(3));
+        assert_eq!(
+            repeating.to_ixdtf_string().unwrap(),
+            "R3/2025-01-01T09:00:00Z/PT1H"
+        );
+        assert_eq!(
+            collect(&repeating),
+            [
+                "2025-01-01T09:00:00Z/2025-01-01T10:00:00Z",
+                "2025-01-01T10:00:00Z/2025-01-01T11:00:00Z",
+                "2025-01-01T11:00:00Z/2025-01-01T12:00:00Z",
+            ]
+        );
+
+        let repeating =
+            RepeatingInterval::<Instant>::from_str("R2/2025-01-01T09:00Z/2025-01-01T09:30Z")
+                .unwrap();
+        assert_eq!(
+            collect(&repeating),
+            [
+                "2025-01-01T09:00:00Z/2025-01-01T09:30:00Z",
+                "2025-01-01T09:30:00Z/2025-01-01T10:00:00Z",
+            ]
+        );
+        assert_writeable_eq!(repeating, "R2/2025-01-01T09:00:00Z/2025-01-01T09:30:00Z");
+
+        let repeating =
+            RepeatingInterval::<Instant>::from_str("R0/2025-01-01T09:00Z/PT1H").unwrap();
+        assert!(collect(&repeating).is_empty());
+
+        // Intervals given by their end are returned from the latest.
+        let repeating = RepeatingInterval::<PlainDate>::from_str("R/P1M/2025-03-31").unwrap();
+        assert_eq!(repeating.repetitions(), None);
+        assert_eq!(repeating.to_ixdtf_string().unwrap(), "R/P1M/2025-03-31");
+        assert_writeable_eq!(repeating, "R/P1M/2025-03-31");
+        let starts = repeating
+            .occurrences()
+            .unwrap()
+            .take(3)
+            .map(|interval| interval.unwrap().start().unwrap().unwrap().to_string())
+            .collect::<Vec<String>>();
+        assert_eq!(starts, ["2025-02-28", "2025-01-31", "2024-12-31"]);
+
+        let repeating = RepeatingInterval::<PlainDate>::from_str("R2/P1D").unwrap();
+        assert!(repeating.occurrences().is_err());
+    }
+}
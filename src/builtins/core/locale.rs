@@ -0,0 +1,598 @@
+//! Locale-aware formatting through ICU4X datetime formatting.
+//!
+//! Dates are formatted in their own calendar, or in the locale's calendar
+//! when they use the ISO 8601 calendar. Zoned values are formatted with
+//! their local time and time zone name. Durations and relative times are
+//! formatted as `Intl.DurationFormat` and `Intl.RelativeTimeFormat` do.
+
+use alloc::{boxed::Box, string::String};
+use core::fmt;
+
+use icu_calendar::{preferences::CalendarAlgorithm, Iso};
+use icu_datetime::{
+    fieldsets::{
+        builder::FieldSetBuilder,
+        enums::{CompositeDateTimeFieldSet, CompositeFieldSet},
+    },
+    DateTimeFormatter, DateTimeFormatterPreferences,
+};
+use icu_locale::{extensions::unicode::Value, Locale};
+use icu_time::{
+    zone::{models, IanaParser, UtcOffset as IcuUtcOffset, VariantOffsetsCalculator},
+    DateTime as IcuDateTime, Time as IcuTime, TimeZone as IcuTimeZone, TimeZoneInfo,
+    ZonedDateTime as IcuZonedDateTime,
+};
+use writeable::{impl_display_with_writeable, LengthHint, Writeable};
+
+mod duration;
+mod relative_time;
+
+pub use duration::{
+    DurationDisplay, DurationFormatOptions, DurationStyle, DurationUnitOptions, DurationUnitStyle,
+    LocaleFormattedDuration,
+};
+pub use icu_datetime::{
+    fieldsets::builder::{DateFields, ZoneStyle},
+    options::{Length, TimePrecision, YearStyle},
+};
+pub use relative_time::{
+    LocaleFormattedRelativeTime, RelativeTimeFormatOptions, RelativeTimeStyle,
+};
+
+use crate::{
+    iso::{IsoDate, IsoDateTime, IsoTime},
+    provider::TimeZoneProvider,
+    Calendar, Instant, PlainDate, PlainDateTime, PlainMonthDay, PlainTime, PlainYearMonth,
+    TemporalError, TemporalResult, TimeZone, ZonedDateTime,
+};
+
+/// The fields and style of a locale-formatted value.
+///
+/// Fields left as `None` use the default of the formatted type. Setting a
+/// field that the type does not have, such as a time precision for a
+/// `PlainDate`, is an error.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LocaleFormatOptions {
+    /// The length of the formatted string, [`Length::Medium`] by default.
+    pub length: Option<Length>,
+    /// The date fields to display.
+    pub date_fields: Option<DateFields>,
+    /// The precision of the time of day.
+    pub time_precision: Option<TimePrecision>,
+    /// The style of the time zone name.
+    pub zone_style: Option<ZoneStyle>,
+    /// How to display the year and era.
+    pub year_style: Option<YearStyle>,
+}
+
+/// The fields a type can display, and its defaults.
+struct FieldRules {
+    date: &'static [DateFields],
+    default_date: Option<DateFields>,
+    time: bool,
+    default_time: Option<TimePrecision>,
+    zone: bool,
+    default_zone: Option<ZoneStyle>,
+}
+
+const ALL_DATE_FIELDS: &[DateFields] = DateFields::VALUES;
+
+impl LocaleFormatOptions {
+    fn to_builder(self, rules: &FieldRules) -> TemporalResult<FieldSetBuilder> {
+        if self
+            .date_fields
+            .is_some_and(|fields| !rules.date.contains(&fields))
+        {
+            return Err(TemporalError::r#type()
+                .with_message("The date fields are not available on this value."));
+        }
+        if self.time_precision.is_some() && !rules.time {
+            return Err(TemporalError::r#type().with_message("This value has no time of day."));
+        }
+        if self.zone_style.is_some() && !rules.zone {
+            return Err(TemporalError::r#type().with_message("This value has no time zone."));
+        }
+        let mut builder = FieldSetBuilder::new();
+        builder.length = self.length;
+        builder.date_fields = self.date_fields.or(rules.default_date);
+        builder.time_precision = self.time_precision.or(rules.default_time);
+        builder.zone_style = self.zone_style.or(rules.default_zone);
+        builder.year_style = self.year_style;
+        Ok(builder)
+    }
+}
+
+fn preferences(
+    locale: &Locale,
+    calendar: &Calendar,
+) -> TemporalResult<DateTimeFormatterPreferences> {
+    let mut preferences = DateTimeFormatterPreferences::from(locale);
+    // ISO 8601 dates are shown in the calendar of the locale.
+    if calendar.is_iso() {
+        return Ok(preferences);
+    }
+    if calendar.any_calendar_kind().is_none() {
+        return Err(TemporalError::range()
+            .with_message("Only calendars supported by ICU4X can be formatted for a locale."));
+    }
+    let algorithm = Value::try_from_str(calendar.identifier())
+        .ok()
+        .and_then(|value| CalendarAlgorithm::try_from(&value).ok())
+        .ok_or_else(|| {
+            TemporalError::range().with_message("The calendar cannot be formatted for a locale.")
+        })?;
+    preferences.calendar_algorithm = Some(algorithm);
+    Ok(preferences)
+}
+
+fn load_error(error: impl fmt::Display) -> TemporalError {
+    TemporalError::general(alloc::format!(
+        "Locale formatting data could not be loaded: {error}"
+    ))
+}
+
+fn builder_error(error: impl fmt::Display) -> TemporalError {
+    TemporalError::range().with_message(alloc::format!("Invalid locale format options: {error}"))
+}
+
+fn to_icu_datetime(date: IsoDate, time: IsoTime) -> TemporalResult<IcuDateTime<Iso>> {
+    let nanosecond = u32::from(time.millisecond) * 1_000_000
+        + u32::from(time.microsecond) * 1_000
+        + u32::from(time.nanosecond);
+    let time = IcuTime::try_new(time.hour, time.minute, time.second, nanosecond)
+        .map_err(|_| TemporalError::range().with_message("The time is out of range."))?;
+    Ok(IcuDateTime {
+        date: date.to_icu4x(),
+        time,
+    })
+}
+
+// The formatters are boxed to keep `LocaleFormatted` small.
+#[derive(Debug)]
+enum FormattedInner {
+    DateTime(
+        Box<DateTimeFormatter<CompositeDateTimeFieldSet>>,
+        IcuDateTime<Iso>,
+    ),
+    Zoned(
+        Box<DateTimeFormatter<CompositeFieldSet>>,
+        IcuZonedDateTime<Iso, TimeZoneInfo<models::Full>>,
+    ),
+}
+
+/// A locale-formatted value, returned by the `to_locale_writeable` methods.
+#[derive(Debug)]
+pub struct LocaleFormatted(FormattedInner);
+
+impl LocaleFormatted {
+    fn try_new_plain(
+        locale: &Locale,
+        calendar: &Calendar,
+        datetime: IsoDateTime,
+        options: LocaleFormatOptions,
+        rules: &FieldRules,
+    ) -> TemporalResult<Self> {
+        let field_set = options
+            .to_builder(rules)?
+            .build_composite_datetime()
+            .map_err(builder_error)?;
+        let formatter = DateTimeFormatter::try_new(preferences(locale, calendar)?, field_set)
+            .map_err(load_error)?;
+        let input = to_icu_datetime(datetime.date, datetime.time)?;
+        Ok(Self(FormattedInner::DateTime(Box::new(formatter), input)))
+    }
+
+    /// Formats a local date-time with its offset and, for named time zones,
+    /// the time zone's names.
+    fn try_new_zoned(
+        locale: &Locale,
+        calendar: &Calendar,
+        datetime: IsoDateTime,
+        offset_nanoseconds: i64,
+        time_zone: &TimeZone,
+        options: LocaleFormatOptions,
+        rules: &FieldRules,
+    ) -> TemporalResult<Self> {
+        let field_set = options
+            .to_builder(rules)?
+            .build_composite()
+            .map_err(builder_error)?;
+        let formatter = DateTimeFormatter::try_new(preferences(locale, calendar)?, field_set)
+            .map_err(load_error)?;
+        let input = to_icu_datetime(datetime.date, datetime.time)?;
+        let id = match time_zone {
+            TimeZone::IanaIdentifier(id) => IanaParser::new().parse(id),
+            TimeZone::UtcOffset(_) => IcuTimeZone::UNKNOWN,
+        };
+        // Sub-second offsets are not representable and are truncated.
+        let offset = IcuUtcOffset::try_from_seconds((offset_nanoseconds / 1_000_000_000) as i32)
+            .map_err(|_| TemporalError::range().with_message("The offset is out of range."))?;
+        let zone = id
+            .with_offset(Some(offset))
+            .at_date_time_iso(input)
+            .infer_variant(VariantOffsetsCalculator::new());
+        let input = IcuZonedDateTime {
+            date: input.date,
+            time: input.time,
+            zone,
+        };
+        Ok(Self(FormattedInner::Zoned(Box::new(formatter), input)))
+    }
+}
+
+impl Writeable for LocaleFormatted {
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        match &self.0 {
+            FormattedInner::DateTime(formatter, input) => formatter.format(input).write_to(sink),
+            FormattedInner::Zoned(formatter, input) => formatter.format(input).write_to(sink),
+        }
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        match &self.0 {
+            FormattedInner::DateTime(formatter, input) => {
+                formatter.format(input).writeable_length_hint()
+            }
+            FormattedInner::Zoned(formatter, input) => {
+                formatter.format(input).writeable_length_hint()
+            }
+        }
+    }
+}
+
+impl_display_with_writeable!(LocaleFormatted);
+
+const DATE_RULES: FieldRules = FieldRules {
+    date: ALL_DATE_FIELDS,
+    default_date: Some(DateFields::YMD),
+    time: false,
+    default_time: None,
+    zone: false,
+    default_zone: None,
+};
+
+const DATE_TIME_RULES: FieldRules = FieldRules {
+    time: true,
+    default_time: Some(TimePrecision::Second),
+    ..DATE_RULES
+};
+
+// ==== Locale formatting API ====
+
+impl PlainDate {
+    /// Formats this date for `locale`, by default with the year, month and day.
+    pub fn to_locale_string(
+        &self,
+        locale: &Locale,
+        options: LocaleFormatOptions,
+    ) -> TemporalResult<String> {
+        self.to_locale_writeable(locale, options)
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Formats this date for `locale` into a [`LocaleFormatted`] that can be
+    /// written without allocating.
+    pub fn to_locale_writeable(
+        &self,
+        locale: &Locale,
+        options: LocaleFormatOptions,
+    ) -> TemporalResult<LocaleFormatted> {
+        let datetime = IsoDateTime::new_unchecked(self.iso, IsoTime::default());
+        LocaleFormatted::try_new_plain(locale, self.calendar(), datetime, options, &DATE_RULES)
+    }
+}
+
+impl PlainTime {
+    /// Formats this time for `locale`, by default with seconds.
+    pub fn to_locale_string(
+        &self,
+        locale: &Locale,
+        options: LocaleFormatOptions,
+    ) -> TemporalResult<String> {
+        self.to_locale_writeable(locale, options)
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Returns the [`LocaleFormatted`] time behind [`Self::to_locale_string`].
+    pub fn to_locale_writeable(
+        &self,
+        locale: &Locale,
+        options: LocaleFormatOptions,
+    ) -> TemporalResult<LocaleFormatted> {
+        const RULES: FieldRules = FieldRules {
+            date: &[],
+            default_date: None,
+            ..DATE_TIME_RULES
+        };
+        // The date is not displayed.
+        let datetime = IsoDateTime::new_unchecked(IsoDate::new_unchecked(1970, 1, 1), self.iso);
+        LocaleFormatted::try_new_plain(locale, &Calendar::ISO, datetime, options, &RULES)
+    }
+}
+
+impl PlainDateTime {
+    /// Formats this date-time for `locale`, by default with the year, month,
+    /// day and time with seconds.
+    pub fn to_locale_string(
+        &self,
+        locale: &Locale,
+        options: LocaleFormatOptions,
+    ) -> TemporalResult<String> {
+        self.to_locale_writeable(locale, options)
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Returns the [`LocaleFormatted`] date-time behind [`Self::to_locale_string`].
+    pub fn to_locale_writeable(
+        &self,
+        locale: &Locale,
+        options: LocaleFormatOptions,
+    ) -> TemporalResult<LocaleFormatted> {
+        LocaleFormatted::try_new_plain(locale, self.calendar(), self.iso, options, &DATE_TIME_RULES)
+    }
+}
+
+impl PlainYearMonth {
+    /// Formats this year and month for `locale`.
+    ///
+    /// Only the year and month fields may be displayed.
+    pub fn to_locale_string(
+        &self,
+        locale: &Locale,
+        options: LocaleFormatOptions,
+    ) -> TemporalResult<String> {
+        self.to_locale_writeable(locale, options)
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Returns the [`LocaleFormatted`] year and month behind
+    /// [`Self::to_locale_string`].
+    pub fn to_locale_writeable(
+        &self,
+        locale: &Locale,
+        options: LocaleFormatOptions,
+    ) -> TemporalResult<LocaleFormatted> {
+        const RULES: FieldRules = FieldRules {
+            date: &[DateFields::YM, DateFields::Y, DateFields::M],
+            default_date: Some(DateFields::YM),
+            ..DATE_RULES
+        };
+        let datetime = IsoDateTime::new_unchecked(self.iso, IsoTime::default());
+        LocaleFormatted::try_new_plain(locale, self.calendar(), datetime, options, &RULES)
+    }
+}
+
+impl PlainMonthDay {
+    /// Formats this month and day for `locale`.
+    ///
+    /// Only the month and day fields may be displayed.
+    pub fn to_locale_string(
+        &self,
+        locale: &Locale,
+        options: LocaleFormatOptions,
+    ) -> TemporalResult<String> {
+        self.to_locale_writeable(locale, options)
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Returns the [`LocaleFormatted`] month and day behind
+    /// [`Self::to_locale_string`].
+    pub fn to_locale_writeable(
+        &self,
+        locale: &Locale,
+        options: LocaleFormatOptions,
+    ) -> TemporalResult<LocaleFormatted> {
+        const RULES: FieldRules = FieldRules {
+            date: &[DateFields::MD, DateFields::M, DateFields::D],
+            default_date: Some(DateFields::MD),
+            ..DATE_RULES
+        };
+        let datetime = IsoDateTime::new_unchecked(self.iso, IsoTime::default());
+        LocaleFormatted::try_new_plain(locale, self.calendar(), datetime, options, &RULES)
+    }
+}
+
+impl Instant {
+    /// Formats this instant for `locale` in `time_zone`, or in UTC when
+    /// `time_zone` is `None`.
+    ///
+    /// By default, the date and time are shown without a time zone name.
+    pub fn to_locale_string_with_provider(
+        &self,
+        locale: &Locale,
+        time_zone: Option<&TimeZone>,
+        options: LocaleFormatOptions,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<String> {
+        self.to_locale_writeable_with_provider(locale, time_zone, options, provider)
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Returns the [`LocaleFormatted`] instant behind
+    /// [`Self::to_locale_string_with_provider`], resolving offsets with `provider`.
+    pub fn to_locale_writeable_with_provider(
+        &self,
+        locale: &Locale,
+        time_zone: Option<&TimeZone>,
+        options: LocaleFormatOptions,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<LocaleFormatted> {
+        const RULES: FieldRules = FieldRules {
+            zone: true,
+            ..DATE_TIME_RULES
+        };
+        let utc = TimeZone::default();
+        let time_zone = time_zone.unwrap_or(&utc);
+        let offset = time_zone.get_offset_nanos_for(self.as_i128(), provider)?;
+        let datetime = IsoDateTime::from_epoch_nanos(self.epoch_nanoseconds(), offset as i64);
+        LocaleFormatted::try_new_zoned(
+            locale,
+            &Calendar::ISO,
+            datetime,
+            offset as i64,
+            time_zone,
+            options,
+            &RULES,
+        )
+    }
+}
+
+impl ZonedDateTime {
+    /// Formats this date-time for `locale` in its calendar and time zone, by
+    /// default with the date, the time with seconds and the short specific
+    /// time zone name, e.g. `PDT`.
+    pub fn to_locale_string(
+        &self,
+        locale: &Locale,
+        options: LocaleFormatOptions,
+    ) -> TemporalResult<String> {
+        self.to_locale_writeable(locale, options)
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Returns the [`LocaleFormatted`] date-time behind [`Self::to_locale_string`],
+    /// including its time zone.
+    pub fn to_locale_writeable(
+        &self,
+        locale: &Locale,
+        options: LocaleFormatOptions,
+    ) -> TemporalResult<LocaleFormatted> {
+        const RULES: FieldRules = FieldRules {
+            zone: true,
+            default_zone: Some(ZoneStyle::SpecificShort),
+            ..DATE_TIME_RULES
+        };
+        LocaleFormatted::try_new_zoned(
+            locale,
+            self.calendar(),
+            self.get_iso_datetime(),
+            self.offset_nanoseconds(),
+            self.timezone(),
+            options,
+            &RULES,
+        )
+    }
+}
+
+#[cfg(all(test, feature = "tzdb"))]
+mod tests {
+    use core::str::FromStr;
+
+    use icu_locale::locale;
+
+    use super::{DateFields, Length, LocaleFormatOptions, TimePrecision, ZoneStyle};
+    use crate::{
+        error::ErrorKind,
+        options::{Disambiguation, OffsetDisambiguation},
+        tzdb::FsTzdbProvider,
+        Instant, PlainDate, PlainDateTime, PlainMonthDay, PlainTime, PlainYearMonth, TimeZone,
+        ZonedDateTime,
+    };
+
+    #[test]
+    fn plain_types() {
+        let en = &locale!("en-US");
+        let defaults = LocaleFormatOptions::default();
+        let date = PlainDate::from_str("2025-01-15").unwrap();
+        assert_eq!(date.to_locale_string(en, defaults).unwrap(), "Jan 15, 2025");
+        let long = LocaleFormatOptions {
+            length: Some(Length::Long),
+            date_fields: Some(DateFields::YMDE),
+            ..Default::default()
+        };
+        assert_eq!(
+            date.to_locale_string(&locale!("de"), long).unwrap(),
+            "Mittwoch, 15. Januar 2025"
+        );
+
+        let datetime = PlainDateTime::from_str("2025-01-15T16:09:35").unwrap();
+        assert_eq!(
+            datetime.to_locale_string(en, defaults).unwrap(),
+            "Jan 15, 2025, 4:09:35\u{202f}PM"
+        );
+        let time = PlainTime::from_str("16:09:35").unwrap();
+        let minutes = LocaleFormatOptions {
+            time_precision: Some(TimePrecision::Minute),
+            ..Default::default()
+        };
+        assert_eq!(
+            time.to_locale_string(&locale!("en-GB"), minutes).unwrap(),
+            "16:09"
+        );
+
+        let year_month = PlainYearMonth::from_str("2025-01").unwrap();
+        assert_eq!(
+            year_month.to_locale_string(en, defaults).unwrap(),
+            "Jan 2025"
+        );
+        let month_day = PlainMonthDay::from_str("01-15").unwrap();
+        assert_eq!(month_day.to_locale_string(en, defaults).unwrap(), "Jan 15");
+
+        // Dates keep their own calendar.
+        let hebrew = PlainDate::from_str("2025-01-15[u-ca=hebrew]").unwrap();
+        assert_eq!(
+            hebrew.to_locale_string(en, defaults).unwrap(),
+            "15 Tevet 5785"
+        );
+
+        assert_eq!(
+            date.to_locale_string(en, minutes).unwrap_err().kind(),
+            ErrorKind::Type
+        );
+        let day = LocaleFormatOptions {
+            date_fields: Some(DateFields::YMD),
+            ..Default::default()
+        };
+        assert_eq!(
+            year_month.to_locale_string(en, day).unwrap_err().kind(),
+            ErrorKind::Type
+        );
+    }
+
+    #[test]
+    fn zoned_types() {
+        let provider = &FsTzdbProvider::default();
+        let en = &locale!("en-US");
+        let zdt = ZonedDateTime::from_utf8_with_provider(
+            b"2025-07-04T09:30:00[America/Los_Angeles]",
+            Disambiguation::Compatible,
+            OffsetDisambiguation::Reject,
+            provider,
+        )
+        .unwrap();
+        assert_eq!(
+            zdt.to_locale_string(en, LocaleFormatOptions::default())
+                .unwrap(),
+            "Jul 4, 2025, 9:30:00\u{202f}AM PDT"
+        );
+        let generic = LocaleFormatOptions {
+            zone_style: Some(ZoneStyle::GenericLong),
+            time_precision: Some(TimePrecision::Minute),
+            ..Default::default()
+        };
+        assert_eq!(
+            zdt.to_locale_string(en, generic).unwrap(),
+            "Jul 4, 2025, 9:30\u{202f}AM Pacific Time"
+        );
+
+        let instant = Instant::from_str("2025-07-04T16:30:00Z").unwrap();
+        let tokyo = TimeZone::try_from_str_with_provider("Asia/Tokyo", provider).unwrap();
+        assert_eq!(
+            instant
+                .to_locale_string_with_provider(
+                    &locale!("ja"),
+                    Some(&tokyo),
+                    LocaleFormatOptions::default(),
+                    provider
+                )
+                .unwrap(),
+            "2025/07/05 1:30:00"
+        );
+        assert_eq!(
+            instant
+                .to_locale_string_with_provider(en, None, LocaleFormatOptions::default(), provider)
+                .unwrap(),
+            "Jul 4, 2025, 4:30:00\u{202f}PM"
+        );
+    }
+}
@@ -0,0 +1,642 @@
+//! Locale-aware duration formatting, following ECMA-402's `Intl.DurationFormat`.
+//!
+//! Each unit is displayed with its own style. Units before the first numeric
+//! unit are written with localized unit names and joined as a list, and the
+//! numeric hours, minutes and seconds are written together as a clock.
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::fmt;
+
+use fixed_decimal::{Decimal, Sign as DecimalSign};
+use icu_decimal::{DecimalFormatter, DecimalFormatterPreferences};
+use icu_experimental::{
+    dimension::units::{
+        formatter::{UnitsFormatter, UnitsFormatterPreferences},
+        options::Width,
+    },
+    duration::provider::DigitalDurationDataV1,
+    provider::Baked,
+};
+use icu_list::{
+    options::{ListFormatterOptions, ListLength},
+    ListFormatter, ListFormatterPreferences,
+};
+use icu_locale::{preferences::LocalePreferences, Locale};
+use icu_provider::prelude::*;
+use writeable::{impl_display_with_writeable, LengthHint, Writeable};
+
+use super::load_error;
+use crate::{Duration, Sign, TemporalError, TemporalResult};
+
+/// The base style of a locale-formatted duration.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DurationStyle {
+    /// Full unit names, e.g. "1 hour, 30 minutes".
+    Long,
+    /// Abbreviated unit names, e.g. "1 hr, 30 min".
+    #[default]
+    Short,
+    /// The most compact unit names, e.g. "1h 30m".
+    Narrow,
+    /// Hours, minutes and seconds as a clock, e.g. "1:30:00".
+    Digital,
+}
+
+/// The style of a single unit of a locale-formatted duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationUnitStyle {
+    /// The full unit name.
+    Long,
+    /// The abbreviated unit name.
+    Short,
+    /// The most compact unit name.
+    Narrow,
+    /// A number without a unit name, for hours and smaller units.
+    ///
+    /// Numeric subsecond units are written as a fraction of the preceding unit.
+    Numeric,
+    /// A number with at least two digits, for hours, minutes and seconds.
+    TwoDigit,
+}
+
+/// Whether a unit of a locale-formatted duration is displayed when it is zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationDisplay {
+    /// Only display the unit when it is not zero.
+    Auto,
+    /// Always display the unit.
+    Always,
+}
+
+/// The style and display of a single duration unit.
+///
+/// Fields left as `None` are derived from the base style and the style of the
+/// preceding unit, as in `Intl.DurationFormat`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DurationUnitOptions {
+    /// The style of the unit.
+    pub style: Option<DurationUnitStyle>,
+    /// Whether the unit is displayed when it is zero.
+    pub display: Option<DurationDisplay>,
+}
+
+/// The options of a locale-formatted duration.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DurationFormatOptions {
+    /// The base style of all units, [`DurationStyle::Short`] by default.
+    pub style: DurationStyle,
+    /// The years unit.
+    pub years: DurationUnitOptions,
+    /// The months unit.
+    pub months: DurationUnitOptions,
+    /// The weeks unit.
+    pub weeks: DurationUnitOptions,
+    /// The days unit.
+    pub days: DurationUnitOptions,
+    /// The hours unit.
+    pub hours: DurationUnitOptions,
+    /// The minutes unit.
+    pub minutes: DurationUnitOptions,
+    /// The seconds unit.
+    pub seconds: DurationUnitOptions,
+    /// The milliseconds unit.
+    pub milliseconds: DurationUnitOptions,
+    /// The microseconds unit.
+    pub microseconds: DurationUnitOptions,
+    /// The nanoseconds unit.
+    pub nanoseconds: DurationUnitOptions,
+    /// The number of fractional digits, from 0 to 9, of a unit followed by
+    /// fractional units.
+    ///
+    /// By default, as many digits as needed are displayed. Extra digits are
+    /// truncated.
+    pub fractional_digits: Option<u8>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UnitKind {
+    Date,
+    Time,
+    Subsecond,
+}
+
+const UNIT_NAMES: [&str; 10] = [
+    "year",
+    "month",
+    "week",
+    "day",
+    "hour",
+    "minute",
+    "second",
+    "millisecond",
+    "microsecond",
+    "nanosecond",
+];
+
+const HOUR: usize = 4;
+const MINUTE: usize = 5;
+const SECOND: usize = 6;
+
+const fn unit_kind(unit: usize) -> UnitKind {
+    match unit {
+        0..HOUR => UnitKind::Date,
+        HOUR..=SECOND => UnitKind::Time,
+        _ => UnitKind::Subsecond,
+    }
+}
+
+/// A resolved unit style, which adds fractional subsecond units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Unit(Width),
+    Numeric,
+    TwoDigit,
+    Fractional,
+}
+
+impl Style {
+    fn is_numeric(self) -> bool {
+        matches!(self, Self::Numeric | Self::TwoDigit | Self::Fractional)
+    }
+}
+
+impl DurationFormatOptions {
+    fn units(&self) -> [DurationUnitOptions; 10] {
+        [
+            self.years,
+            self.months,
+            self.weeks,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+            self.milliseconds,
+            self.microseconds,
+            self.nanoseconds,
+        ]
+    }
+
+    /// Resolves the style and display of each unit.
+    ///
+    /// This is `GetDurationUnitOptions` of ECMA-402, applied to every unit.
+    fn resolve(&self, two_digit_hours: bool) -> TemporalResult<[(Style, DurationDisplay); 10]> {
+        if self.fractional_digits.is_some_and(|digits| digits > 9) {
+            return Err(TemporalError::range()
+                .with_message("fractionalDigits must be in the range 0 to 9."));
+        }
+        let base = match self.style {
+            DurationStyle::Long => Some(Width::Long),
+            DurationStyle::Short => Some(Width::Short),
+            DurationStyle::Narrow => Some(Width::Narrow),
+            DurationStyle::Digital => None,
+        };
+        let mut resolved = [(Style::Unit(Width::Short), DurationDisplay::Auto); 10];
+        let mut previous: Option<Style> = None;
+        for (unit, (options, slot)) in self.units().into_iter().zip(&mut resolved).enumerate() {
+            let kind = unit_kind(unit);
+            let mut default_display = DurationDisplay::Always;
+            let mut style = match (options.style, base) {
+                (Some(DurationUnitStyle::Long), _) => Style::Unit(Width::Long),
+                (Some(DurationUnitStyle::Short), _) => Style::Unit(Width::Short),
+                (Some(DurationUnitStyle::Narrow), _) => Style::Unit(Width::Narrow),
+                (Some(DurationUnitStyle::Numeric), _) if kind != UnitKind::Date => Style::Numeric,
+                (Some(DurationUnitStyle::TwoDigit), _) if kind == UnitKind::Time => Style::TwoDigit,
+                (Some(_), _) => {
+                    return Err(TemporalError::range().with_message(alloc::format!(
+                        "The {} style is not available for {}s.",
+                        match options.style {
+                            Some(DurationUnitStyle::TwoDigit) => "2-digit",
+                            _ => "numeric",
+                        },
+                        UNIT_NAMES.get(unit).copied().unwrap_or_default()
+                    )));
+                }
+                (None, None) => {
+                    if kind == UnitKind::Date {
+                        default_display = DurationDisplay::Auto;
+                        Style::Unit(Width::Short)
+                    } else {
+                        if kind == UnitKind::Subsecond {
+                            default_display = DurationDisplay::Auto;
+                        }
+                        Style::Numeric
+                    }
+                }
+                (None, Some(_)) if previous.is_some_and(Style::is_numeric) => {
+                    if kind != UnitKind::Time || unit == HOUR {
+                        default_display = DurationDisplay::Auto;
+                    }
+                    Style::Numeric
+                }
+                (None, Some(width)) => {
+                    default_display = DurationDisplay::Auto;
+                    Style::Unit(width)
+                }
+            };
+            if style == Style::Numeric && kind == UnitKind::Subsecond {
+                style = Style::Fractional;
+                default_display = DurationDisplay::Auto;
+            }
+            let display = options.display.unwrap_or(default_display);
+
+            if display == DurationDisplay::Always && style == Style::Fractional {
+                return Err(TemporalError::range().with_message(
+                    "A numeric subsecond unit cannot always be displayed, as it is a fraction.",
+                ));
+            }
+            match previous {
+                Some(Style::Fractional) if style != Style::Fractional => {
+                    return Err(TemporalError::range()
+                        .with_message("Units after a numeric subsecond unit must be numeric."));
+                }
+                Some(Style::Numeric | Style::TwoDigit) if !style.is_numeric() => {
+                    return Err(TemporalError::range()
+                        .with_message("Units after a numeric unit must be numeric."));
+                }
+                Some(Style::Numeric | Style::TwoDigit) if unit == MINUTE || unit == SECOND => {
+                    style = Style::TwoDigit;
+                }
+                _ => {}
+            }
+            if unit == HOUR && style == Style::Numeric && two_digit_hours {
+                style = Style::TwoDigit;
+            }
+            *slot = (style, display);
+            previous = Some(style);
+        }
+        Ok(resolved)
+    }
+}
+
+/// A single element of the formatted list.
+enum Part {
+    /// A value with a localized unit name.
+    Unit(Box<UnitsFormatter>, Decimal),
+    /// Numeric hours, minutes and seconds, joined by the locale's separator.
+    Digital(Vec<Decimal>),
+}
+
+/// A locale-formatted duration, returned by [`Duration::to_locale_writeable`].
+pub struct LocaleFormattedDuration {
+    parts: Vec<Part>,
+    list: ListFormatter,
+    decimal: DecimalFormatter,
+    separator: String,
+}
+
+impl fmt::Debug for LocaleFormattedDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocaleFormattedDuration")
+            .field("value", &self.write_to_string())
+            .finish()
+    }
+}
+
+/// The state of `PartitionDurationFormatPattern` while the parts are collected.
+struct Partition {
+    units: [u128; 10],
+    styles: [(Style, DurationDisplay); 10],
+    negative: bool,
+    fractional_digits: Option<u8>,
+    sign_displayed: bool,
+}
+
+impl Partition {
+    /// Returns the value of `unit` with the values of the following
+    /// fractional units added, as in `AddFractionalDigits`.
+    fn value(&self, unit: usize) -> Decimal {
+        let mut value = self.units.get(unit).copied().unwrap_or_default();
+        let mut exponent = 0;
+        for (next, (style, _)) in self.units.iter().zip(self.styles).skip(unit + 1) {
+            if style != Style::Fractional {
+                break;
+            }
+            value = value * 1_000 + next;
+            exponent += 3;
+        }
+        let mut decimal = Decimal::from(value);
+        if exponent > 0 {
+            decimal.absolute.multiply_pow10(-exponent);
+            let digits = self.fractional_digits.map_or(9, i16::from);
+            decimal.trunc(-digits);
+            decimal
+                .absolute
+                .pad_end(-self.fractional_digits.map_or(0, i16::from));
+        }
+        decimal
+    }
+
+    /// Gives the first displayed value the sign of the duration, even when it
+    /// is zero.
+    fn sign(&mut self, mut decimal: Decimal) -> Decimal {
+        if self.sign_displayed && self.negative {
+            decimal.set_sign(DecimalSign::Negative);
+        }
+        self.sign_displayed = false;
+        decimal
+    }
+
+    /// `FormatNumericUnits`: formats the hours, minutes and seconds from
+    /// `first` as a clock.
+    fn numeric(&mut self, first: usize) -> Vec<Decimal> {
+        let unit = |unit: usize| {
+            let value = self.units.get(unit).copied().unwrap_or_default();
+            let (style, display) = self
+                .styles
+                .get(unit)
+                .copied()
+                .unwrap_or((Style::Numeric, DurationDisplay::Auto));
+            (value, style, display == DurationDisplay::Always)
+        };
+        let (hours, hours_style, hours_always) = unit(HOUR);
+        let (minutes, minutes_style, minutes_always) = unit(MINUTE);
+        let (_, seconds_style, seconds_always) = unit(SECOND);
+        let seconds = self.value(SECOND);
+
+        let hours_formatted = first == HOUR && (hours != 0 || hours_always);
+        let seconds_formatted = !seconds.absolute.is_zero() || seconds_always;
+        let minutes_formatted = first <= MINUTE
+            && ((hours_formatted && seconds_formatted) || minutes != 0 || minutes_always);
+
+        let mut numbers = Vec::with_capacity(3);
+        for (formatted, value, style) in [
+            (hours_formatted, Decimal::from(hours), hours_style),
+            (minutes_formatted, Decimal::from(minutes), minutes_style),
+            (seconds_formatted, seconds, seconds_style),
+        ] {
+            if formatted {
+                let mut value = self.sign(value);
+                if style == Style::TwoDigit {
+                    value.absolute.pad_start(2);
+                }
+                numbers.push(value);
+            }
+        }
+        numbers
+    }
+}
+
+/// How a locale writes the numeric hours, minutes and seconds of a duration.
+struct DigitalFormat {
+    /// Whether hours are written with two digits.
+    pads_hours: bool,
+    /// The separator between the numeric units.
+    separator: String,
+}
+
+impl DigitalFormat {
+    /// `icu_experimental` has no stable API for this, so this is the one place
+    /// that reads its duration data directly.
+    fn try_new(locale: &Locale) -> TemporalResult<Self> {
+        let data_locale = DigitalDurationDataV1::make_locale(LocalePreferences::from(locale));
+        let digital: DataResponse<DigitalDurationDataV1> = Baked
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&data_locale),
+                ..Default::default()
+            })
+            .map_err(load_error)?;
+        let digital = digital.payload.get();
+        Ok(Self {
+            pads_hours: digital.hms_padding.h == 2,
+            separator: String::from(&*digital.separator),
+        })
+    }
+}
+
+impl LocaleFormattedDuration {
+    fn try_new(
+        duration: &Duration,
+        locale: &Locale,
+        options: DurationFormatOptions,
+    ) -> TemporalResult<Self> {
+        let digital = DigitalFormat::try_new(locale)?;
+        let styles = options.resolve(digital.pads_hours)?;
+
+        let mut partition = Partition {
+            units: [
+                u128::from(duration.years().unsigned_abs()),
+                u128::from(duration.months().unsigned_abs()),
+                u128::from(duration.weeks().unsigned_abs()),
+                u128::from(duration.days().unsigned_abs()),
+                u128::from(duration.hours().unsigned_abs()),
+                u128::from(duration.minutes().unsigned_abs()),
+                u128::from(duration.seconds().unsigned_abs()),
+                u128::from(duration.milliseconds().unsigned_abs()),
+                duration.microseconds().unsigned_abs(),
+                duration.nanoseconds().unsigned_abs(),
+            ],
+            styles,
+            negative: duration.sign() == Sign::Negative,
+            fractional_digits: options.fractional_digits,
+            sign_displayed: true,
+        };
+
+        // PartitionDurationFormatPattern
+        let mut parts = Vec::new();
+        for (unit, (style, display)) in styles.into_iter().enumerate() {
+            let width = match style {
+                Style::Numeric | Style::TwoDigit => {
+                    let numbers = partition.numeric(unit);
+                    if !numbers.is_empty() {
+                        parts.push(Part::Digital(numbers));
+                    }
+                    break;
+                }
+                Style::Unit(width) => width,
+                // Fractional units are part of the preceding unit.
+                Style::Fractional => break,
+            };
+            let value = partition.value(unit);
+            if value.absolute.is_zero() && display == DurationDisplay::Auto {
+                continue;
+            }
+            let value = partition.sign(value);
+            let name = UNIT_NAMES.get(unit).copied().unwrap_or_default();
+            let formatter = UnitsFormatter::try_new(
+                UnitsFormatterPreferences::from(locale),
+                name,
+                width.into(),
+            )
+            .map_err(load_error)?;
+            parts.push(Part::Unit(Box::new(formatter), value));
+        }
+
+        let length = match options.style {
+            DurationStyle::Long => ListLength::Wide,
+            DurationStyle::Short | DurationStyle::Digital => ListLength::Short,
+            DurationStyle::Narrow => ListLength::Narrow,
+        };
+        let list = ListFormatter::try_new_unit(
+            ListFormatterPreferences::from(locale),
+            ListFormatterOptions::default().with_length(length),
+        )
+        .map_err(load_error)?;
+        let decimal = DecimalFormatter::try_new(
+            DecimalFormatterPreferences::from(locale),
+            Default::default(),
+        )
+        .map_err(load_error)?;
+        Ok(Self {
+            parts,
+            list,
+            decimal,
+            separator: digital.separator,
+        })
+    }
+}
+
+struct FormattedPart<'a> {
+    part: &'a Part,
+    decimal: &'a DecimalFormatter,
+    separator: &'a str,
+}
+
+impl Writeable for FormattedPart<'_> {
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        match self.part {
+            Part::Unit(formatter, value) => formatter.format_fixed_decimal(value).write_to(sink),
+            Part::Digital(values) => {
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        sink.write_str(self.separator)?;
+                    }
+                    self.decimal.format(value).write_to(sink)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Writeable for LocaleFormattedDuration {
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.list
+            .format(self.parts.iter().map(|part| FormattedPart {
+                part,
+                decimal: &self.decimal,
+                separator: &self.separator,
+            }))
+            .write_to(sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        LengthHint::undefined()
+    }
+}
+
+impl_display_with_writeable!(LocaleFormattedDuration);
+
+impl Duration {
+    /// Formats this duration for `locale`, as `Intl.DurationFormat` does.
+    pub fn to_locale_string(
+        &self,
+        locale: &Locale,
+        options: DurationFormatOptions,
+    ) -> TemporalResult<String> {
+        self.to_locale_writeable(locale, options)
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Returns the [`LocaleFormattedDuration`] behind [`Self::to_locale_string`].
+    pub fn to_locale_writeable(
+        &self,
+        locale: &Locale,
+        options: DurationFormatOptions,
+    ) -> TemporalResult<LocaleFormattedDuration> {
+        LocaleFormattedDuration::try_new(self, locale, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use icu_locale::locale;
+
+    use super::{DurationDisplay, DurationFormatOptions, DurationStyle, DurationUnitStyle};
+    use crate::{error::ErrorKind, Duration};
+
+    fn format(duration: &str, options: DurationFormatOptions) -> alloc::string::String {
+        Duration::from_str(duration)
+            .unwrap()
+            .to_locale_string(&locale!("en"), options)
+            .unwrap()
+    }
+
+    #[test]
+    fn styles() {
+        let style = |style| DurationFormatOptions {
+            style,
+            ..Default::default()
+        };
+        let duration = "P1DT1H30M5S";
+        assert_eq!(
+            format(duration, style(DurationStyle::Long)),
+            "1 day, 1 hour, 30 minutes, 5 seconds"
+        );
+        assert_eq!(
+            format(duration, style(DurationStyle::Short)),
+            "1 day, 1 hr, 30 min, 5 sec"
+        );
+        assert_eq!(
+            format(duration, style(DurationStyle::Narrow)),
+            "1d 1h 30m 5s"
+        );
+        assert_eq!(
+            format(duration, style(DurationStyle::Digital)),
+            "1 day, 1:30:05"
+        );
+        assert_eq!(format("PT1H30M", style(DurationStyle::Digital)), "1:30:00");
+        assert_eq!(format("-PT1H30M", Default::default()), "-1 hr, 30 min");
+        assert_eq!(format("-PT5S", style(DurationStyle::Digital)), "-0:00:05");
+        assert_eq!(
+            Duration::from_str("PT1H30M")
+                .unwrap()
+                .to_locale_string(&locale!("de"), style(DurationStyle::Long))
+                .unwrap(),
+            "1 Stunde, 30 Minuten"
+        );
+    }
+
+    #[test]
+    fn unit_options() {
+        let mut options = DurationFormatOptions {
+            style: DurationStyle::Digital,
+            fractional_digits: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(format("PT5.129S", options), "0:00:05.12");
+        options.hours.display = Some(DurationDisplay::Auto);
+        assert_eq!(format("PT5.129S", options), "00:05.12");
+
+        let mut options = DurationFormatOptions::default();
+        options.milliseconds.style = Some(DurationUnitStyle::Numeric);
+        assert_eq!(format("PT1M1.5S", options), "1 min, 1.5 sec");
+        options.days.display = Some(DurationDisplay::Always);
+        assert_eq!(format("PT1M1.5S", options), "0 days, 1 min, 1.5 sec");
+
+        let error = |options| {
+            Duration::from_str("PT1H")
+                .unwrap()
+                .to_locale_string(&locale!("en"), options)
+                .unwrap_err()
+                .kind()
+        };
+        let mut options = DurationFormatOptions::default();
+        options.years.style = Some(DurationUnitStyle::Numeric);
+        assert_eq!(error(options), ErrorKind::Range);
+        let mut options = DurationFormatOptions::default();
+        options.hours.style = Some(DurationUnitStyle::Numeric);
+        options.minutes.style = Some(DurationUnitStyle::Long);
+        assert_eq!(error(options), ErrorKind::Range);
+        let mut options = DurationFormatOptions::default();
+        options.milliseconds.style = Some(DurationUnitStyle::Numeric);
+        options.milliseconds.display = Some(DurationDisplay::Always);
+        assert_eq!(error(options), ErrorKind::Range);
+        let options = DurationFormatOptions {
+            fractional_digits: Some(10),
+            ..Default::default()
+        };
+        assert_eq!(error(options), ErrorKind::Range);
+    }
+}
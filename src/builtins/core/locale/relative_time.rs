@@ -0,0 +1,188 @@
+//! Locale-aware formatting of relative times, as `Intl.RelativeTimeFormat` does.
+
+use alloc::string::String;
+use core::fmt;
+
+use fixed_decimal::Decimal;
+use icu_experimental::relativetime::{
+    options::Numeric, RelativeTimeFormatter, RelativeTimeFormatterOptions,
+    RelativeTimeFormatterPreferences,
+};
+use icu_locale::Locale;
+use icu_provider::DataError;
+use writeable::{impl_display_with_writeable, LengthHint, Writeable};
+
+use super::load_error;
+use crate::{
+    builtins::core::relative_time::RelativeTime, options::Unit, TemporalError, TemporalResult,
+};
+
+/// The length of a locale-formatted relative time.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RelativeTimeStyle {
+    /// Full unit names, e.g. "in 3 months".
+    #[default]
+    Long,
+    /// Abbreviated unit names, e.g. "in 3 mo.".
+    Short,
+    /// The most compact unit names, e.g. "in 3mo".
+    Narrow,
+}
+
+/// The options of a locale-formatted relative time.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RelativeTimeFormatOptions {
+    /// The length of the phrase.
+    pub style: RelativeTimeStyle,
+    /// Whether to use phrases such as "yesterday" or "now" instead of
+    /// numbers where the locale has them.
+    pub use_names: bool,
+}
+
+type Constructor = fn(
+    RelativeTimeFormatterPreferences,
+    RelativeTimeFormatterOptions,
+) -> Result<RelativeTimeFormatter, DataError>;
+
+fn constructor(style: RelativeTimeStyle, unit: Unit) -> Option<Constructor> {
+    use RelativeTimeStyle::{Long, Narrow, Short};
+    let constructor: Constructor = match (style, unit) {
+        (Long, Unit::Second) => RelativeTimeFormatter::try_new_long_second,
+        (Long, Unit::Minute) => RelativeTimeFormatter::try_new_long_minute,
+        (Long, Unit::Hour) => RelativeTimeFormatter::try_new_long_hour,
+        (Long, Unit::Day) => RelativeTimeFormatter::try_new_long_day,
+        (Long, Unit::Week) => RelativeTimeFormatter::try_new_long_week,
+        (Long, Unit::Month) => RelativeTimeFormatter::try_new_long_month,
+        (Long, Unit::Year) => RelativeTimeFormatter::try_new_long_year,
+        (Short, Unit::Second) => RelativeTimeFormatter::try_new_short_second,
+        (Short, Unit::Minute) => RelativeTimeFormatter::try_new_short_minute,
+        (Short, Unit::Hour) => RelativeTimeFormatter::try_new_short_hour,
+        (Short, Unit::Day) => RelativeTimeFormatter::try_new_short_day,
+        (Short, Unit::Week) => RelativeTimeFormatter::try_new_short_week,
+        (Short, Unit::Month) => RelativeTimeFormatter::try_new_short_month,
+        (Short, Unit::Year) => RelativeTimeFormatter::try_new_short_year,
+        (Narrow, Unit::Second) => RelativeTimeFormatter::try_new_narrow_second,
+        (Narrow, Unit::Minute) => RelativeTimeFormatter::try_new_narrow_minute,
+        (Narrow, Unit::Hour) => RelativeTimeFormatter::try_new_narrow_hour,
+        (Narrow, Unit::Day) => RelativeTimeFormatter::try_new_narrow_day,
+        (Narrow, Unit::Week) => RelativeTimeFormatter::try_new_narrow_week,
+        (Narrow, Unit::Month) => RelativeTimeFormatter::try_new_narrow_month,
+        (Narrow, Unit::Year) => RelativeTimeFormatter::try_new_narrow_year,
+        _ => return None,
+    };
+    Some(constructor)
+}
+
+/// A locale-formatted relative time, returned by
+/// [`RelativeTime::to_locale_writeable`].
+pub struct LocaleFormattedRelativeTime {
+    formatter: RelativeTimeFormatter,
+    value: i64,
+}
+
+impl fmt::Debug for LocaleFormattedRelativeTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocaleFormattedRelativeTime")
+            .field("value", &self.write_to_string())
+            .finish()
+    }
+}
+
+impl Writeable for LocaleFormattedRelativeTime {
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.formatter
+            .format(Decimal::from(self.value))
+            .write_to(sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        LengthHint::undefined()
+    }
+}
+
+impl_display_with_writeable!(LocaleFormattedRelativeTime);
+
+impl RelativeTime {
+    /// Formats this relative time for `locale`, e.g. "3 days ago".
+    pub fn to_locale_string(
+        &self,
+        locale: &Locale,
+        options: RelativeTimeFormatOptions,
+    ) -> TemporalResult<String> {
+        self.to_locale_writeable(locale, options)
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Returns the [`LocaleFormattedRelativeTime`] behind [`Self::to_locale_string`].
+    pub fn to_locale_writeable(
+        &self,
+        locale: &Locale,
+        options: RelativeTimeFormatOptions,
+    ) -> TemporalResult<LocaleFormattedRelativeTime> {
+        let constructor = constructor(options.style, self.unit())
+            .ok_or_else(|| TemporalError::assert().with_message("Invalid relative time unit."))?;
+        let numeric = if options.use_names {
+            Numeric::Auto
+        } else {
+            Numeric::Always
+        };
+        let formatter = constructor(
+            RelativeTimeFormatterPreferences::from(locale),
+            RelativeTimeFormatterOptions { numeric },
+        )
+        .map_err(load_error)?;
+        Ok(LocaleFormattedRelativeTime {
+            formatter,
+            value: self.value(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use icu_locale::locale;
+
+    use super::{RelativeTimeFormatOptions, RelativeTimeStyle};
+    use crate::{builtins::core::relative_time::RelativeTime, options::Unit};
+
+    #[test]
+    fn phrases() {
+        let format = |value, unit, style, use_names, locale| {
+            RelativeTime::try_new(value, unit)
+                .unwrap()
+                .to_locale_string(locale, RelativeTimeFormatOptions { style, use_names })
+                .unwrap()
+        };
+        let en = &locale!("en");
+        assert_eq!(
+            format(-3, Unit::Day, RelativeTimeStyle::Long, false, en),
+            "3 days ago"
+        );
+        assert_eq!(
+            format(2, Unit::Hour, RelativeTimeStyle::Long, false, en),
+            "in 2 hours"
+        );
+        assert_eq!(
+            format(-1, Unit::Day, RelativeTimeStyle::Long, true, en),
+            "yesterday"
+        );
+        assert_eq!(
+            format(0, Unit::Second, RelativeTimeStyle::Long, true, en),
+            "now"
+        );
+        assert_eq!(
+            format(3, Unit::Month, RelativeTimeStyle::Short, false, en),
+            "in 3 mo."
+        );
+        assert_eq!(
+            format(
+                -2,
+                Unit::Week,
+                RelativeTimeStyle::Long,
+                false,
+                &locale!("de")
+            ),
+            "vor 2 Wochen"
+        );
+    }
+}
@@ -6,8 +6,15 @@
 
 // TODO: Expand upon above introduction.
 
+pub mod business;
 pub mod calendar;
 pub mod duration;
+pub mod icalendar;
+pub mod interval;
+#[cfg(feature = "locale")]
+pub mod locale;
+pub mod recurrence;
+pub mod relative_time;
 pub mod timezone;
 
 mod date;
@@ -15,6 +22,7 @@ mod datetime;
 mod instant;
 mod month_day;
 mod time;
+mod week_date;
 mod year_month;
 pub(crate) mod zoneddatetime;
 
@@ -36,6 +44,8 @@ pub use month_day::PlainMonthDay;
 #[doc(inline)]
 pub use time::{PartialTime, PlainTime};
 #[doc(inline)]
+pub use week_date::PlainWeekDate;
+#[doc(inline)]
 pub use year_month::{PartialYearMonth, PlainYearMonth};
 #[doc(inline)]
 pub use zoneddatetime::{PartialZonedDateTime, ZonedDateTime, ZonedDateTimeFields};
@@ -15,8 +15,7 @@ use crate::{
 };
 
 use super::{PartialDate, PlainDate};
-use icu_calendar::AnyCalendarKind;
-use writeable::Writeable;
+use writeable::{impl_display_with_writeable, LengthHint, Writeable};
 
 /// The native Rust implementation of `Temporal.PlainMonthDay`.
 ///
@@ -163,12 +162,20 @@ pub struct PlainMonthDay {
     calendar: Calendar,
 }
 
-impl core::fmt::Display for PlainMonthDay {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str(&self.to_ixdtf_string(DisplayCalendar::Auto))
+impl Writeable for PlainMonthDay {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        self.to_ixdtf_writeable(DisplayCalendar::Auto)
+            .write_to(sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        self.to_ixdtf_writeable(DisplayCalendar::Auto)
+            .writeable_length_hint()
     }
 }
 
+impl_display_with_writeable!(PlainMonthDay);
+
 impl PlainMonthDay {
     /// Creates a new unchecked `PlainMonthDay`
     #[inline]
@@ -198,13 +205,19 @@ impl PlainMonthDay {
         Self::from_parsed(parsed)
     }
 
+    // Converts a UTF-16 encoded string into a `PlainMonthDay`.
+    pub fn from_utf16(s: &[u16]) -> TemporalResult<Self> {
+        let parsed = ParsedDate::month_day_from_utf16(s)?;
+        Self::from_parsed(parsed)
+    }
+
     // Converts a ParsedDate into a `PlainMonthDay`.
     //
     // Be sure to parse this using [`ParsedDate::month_day_from_utf8()`]~
     pub fn from_parsed(parsed: ParsedDate) -> TemporalResult<Self> {
-        let calendar = Calendar::new(parsed.calendar);
+        let calendar = parsed.calendar;
         // 10. If calendar is "iso8601", then
-        if parsed.calendar == AnyCalendarKind::Iso {
+        if calendar.is_iso() {
             // a. Let referenceISOYear be 1972 (the first ISO 8601 leap year after the epoch).
             // b. Let isoDate be CreateISODateRecord(referenceISOYear, result.[[Month]], result.[[Day]]).
             // c. Return !CreateTemporalMonthDay(isoDate, calendar).
@@ -222,7 +235,7 @@ impl PlainMonthDay {
 
         // 13. Set result to ISODateToFields(calendar, isoDate, month-day).
 
-        let intermediate = Self::new_unchecked(iso, Calendar::new(parsed.calendar));
+        let intermediate = Self::new_unchecked(iso, calendar);
         let fields = CalendarFields::from_month_day(&intermediate);
         // 14. NOTE: The following operation is called with constrain regardless of the value of overflow, in
         // order for the calendar to store a canonical value in the [[Year]] field of the [[ISODate]] internal slot of the result.
@@ -405,6 +418,7 @@ impl FromStr for PlainMonthDay {
 mod tests {
     use super::*;
     use crate::Calendar;
+    use icu_calendar::AnyCalendarKind;
     use tinystr::tinystr;
 
     #[test]
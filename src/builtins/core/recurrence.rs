@@ -0,0 +1,1497 @@
+//! Recurrence rules as defined by [RFC 5545][rfc].
+//!
+//! A [`RecurrenceRule`] describes a repeating pattern (`RRULE`), and a
+//! [`RecurrenceSet`] anchors it to a `DTSTART` [`ZonedDateTime`] together with
+//! additional (`RDATE`) and excluded (`EXDATE`) occurrences.
+//!
+//! Occurrences are computed on the wall-clock date and time of `DTSTART` and
+//! then resolved in its time zone, so a daily rule at 09:00 stays at 09:00
+//! across DST transitions. Wall-clock times that fall into a gap or an overlap
+//! are resolved with the set's [`Disambiguation`].
+//!
+//! [rfc]: https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.10
+
+use alloc::{collections::VecDeque, format, string::String, vec::Vec};
+use core::{fmt, str::FromStr};
+
+use icu_calendar::types::Weekday;
+
+use crate::{
+    builtins::core::{
+        icalendar::{format_date_time, parse_date_time},
+        DateDuration, Duration, Instant, PlainDate, PlainDateTime, PlainTime, ZonedDateTime,
+    },
+    iso::{IsoDate, IsoDateTime, IsoTime},
+    options::{ArithmeticOverflow, Disambiguation},
+    parsers::parse_ascii_digits,
+    provider::TimeZoneProvider,
+    Calendar, MonthCode, TemporalError, TemporalResult,
+};
+
+/// The `FREQ` of a [`RecurrenceRule`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Frequency {
+    /// `SECONDLY`
+    Secondly,
+    /// `MINUTELY`
+    Minutely,
+    /// `HOURLY`
+    Hourly,
+    /// `DAILY`
+    Daily,
+    /// `WEEKLY`
+    Weekly,
+    /// `MONTHLY`
+    Monthly,
+    /// `YEARLY`
+    Yearly,
+}
+
+impl Frequency {
+    /// Returns the `FREQ` value of this frequency.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Secondly => "SECONDLY",
+            Self::Minutely => "MINUTELY",
+            Self::Hourly => "HOURLY",
+            Self::Daily => "DAILY",
+            Self::Weekly => "WEEKLY",
+            Self::Monthly => "MONTHLY",
+            Self::Yearly => "YEARLY",
+        }
+    }
+
+    fn from_utf8(value: &[u8]) -> Option<Self> {
+        [
+            Self::Secondly,
+            Self::Minutely,
+            Self::Hourly,
+            Self::Daily,
+            Self::Weekly,
+            Self::Monthly,
+            Self::Yearly,
+        ]
+        .into_iter()
+        .find(|f| f.as_str().as_bytes() == value)
+    }
+}
+
+impl fmt::Display for Frequency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A `BYDAY` entry: a weekday with an optional ordinal, e.g. `MO` or `-1FR`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekdayNum {
+    /// The weekday.
+    pub weekday: Weekday,
+    /// The occurrence of the weekday within the month or year, counting from
+    /// the end when negative. `None` matches every occurrence.
+    pub ordinal: Option<i8>,
+}
+
+impl WeekdayNum {
+    /// Matches every occurrence of `weekday`.
+    pub const fn every(weekday: Weekday) -> Self {
+        Self {
+            weekday,
+            ordinal: None,
+        }
+    }
+
+    /// Matches the `n`th occurrence of `weekday`.
+    pub const fn nth(n: i8, weekday: Weekday) -> Self {
+        Self {
+            weekday,
+            ordinal: Some(n),
+        }
+    }
+
+    fn from_utf8(value: &[u8]) -> Option<Self> {
+        let (ordinal, code) = value.split_at_checked(value.len().checked_sub(2)?)?;
+        let weekday = weekday_from_code(code)?;
+        if ordinal.is_empty() {
+            return Some(Self::every(weekday));
+        }
+        let n = parse_signed(ordinal)?;
+        Some(Self::nth(i8::try_from(n).ok()?, weekday))
+    }
+}
+
+impl fmt::Display for WeekdayNum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(n) = self.ordinal {
+            write!(f, "{n}")?;
+        }
+        f.write_str(weekday_code(self.weekday))
+    }
+}
+
+/// The `UNTIL` bound of a [`RecurrenceRule`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecurrenceUntil {
+    /// A date bound, compared against the local date of each occurrence.
+    Date(PlainDate),
+    /// A floating date-time bound, compared against the local date-time.
+    DateTime(PlainDateTime),
+    /// A UTC bound, compared against the exact time.
+    Instant(Instant),
+}
+
+impl RecurrenceUntil {
+    fn from_utf8(value: &[u8]) -> Option<Self> {
+        let value = parse_date_time(value)?;
+        match (value.time, value.utc) {
+            (None, _) => Some(Self::Date(PlainDate::new_unchecked(
+                value.date,
+                Calendar::default(),
+            ))),
+            (Some(time), false) => PlainDate::new_unchecked(value.date, Calendar::default())
+                .to_plain_date_time(Some(PlainTime::new_unchecked(time)))
+                .ok()
+                .map(Self::DateTime),
+            (Some(time), true) => {
+                let ns = IsoDateTime::new(value.date, time).ok()?.as_nanoseconds();
+                Instant::try_new(ns.0).ok().map(Self::Instant)
+            }
+        }
+    }
+
+    /// Returns whether an occurrence at `local` and `epoch_ns` is past this bound.
+    fn is_exceeded_by(&self, local: &IsoDateTime, epoch_ns: i128) -> bool {
+        match self {
+            Self::Date(date) => local.date > date.iso,
+            Self::DateTime(date_time) => *local > date_time.iso,
+            Self::Instant(instant) => epoch_ns > instant.epoch_nanoseconds().0,
+        }
+    }
+
+    /// Returns the `UNTIL` value of this bound, e.g. `20240220T100000Z`.
+    ///
+    /// Returns a `RangeError` if the year is not between 0 and 9999.
+    pub fn to_icalendar_string(&self) -> TemporalResult<String> {
+        match self {
+            Self::Date(date) => date.to_icalendar_string(),
+            Self::DateTime(date_time) => date_time.to_icalendar_string(),
+            Self::Instant(instant) => format_date_time(
+                &IsoDateTime::from_epoch_nanos(instant.epoch_nanoseconds(), 0),
+                true,
+            ),
+        }
+    }
+
+    /// Returns the last local date this bound can include.
+    fn last_date(&self) -> Option<IsoDate> {
+        match self {
+            Self::Date(date) => Some(date.iso),
+            Self::DateTime(date_time) => Some(date_time.iso.date),
+            // The local date of a UTC bound depends on the offset, so allow a day of slack.
+            Self::Instant(instant) => {
+                let date = IsoDateTime::from_epoch_nanos(instant.epoch_nanoseconds(), 0).date;
+                Some(add_days(&date, 1))
+            }
+        }
+    }
+}
+
+/// An RFC 5545 recurrence rule (`RRULE`).
+///
+/// The supported rule parts are `FREQ`, `INTERVAL`, `COUNT`, `UNTIL`,
+/// `BYMONTH`, `BYMONTHDAY`, `BYDAY`, `BYSETPOS` and `WKST`.
+///
+/// Months and days are interpreted in the calendar of the `DTSTART` date, so
+/// `BYMONTH=13` selects the thirteenth month of a Hebrew leap year.
+///
+/// ```
+/// use core::str::FromStr;
+/// use temporal_rs::recurrence::{Frequency, RecurrenceRule};
+///
+/// let rule = RecurrenceRule::from_str("RRULE:FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1").unwrap();
+/// assert_eq!(rule.frequency, Frequency::Monthly);
+/// assert_eq!(
+///     rule.to_icalendar_string().unwrap(),
+///     "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1"
+/// );
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    /// `FREQ`
+    pub frequency: Frequency,
+    /// `INTERVAL`, at least 1.
+    pub interval: u32,
+    /// `COUNT`
+    pub count: Option<u32>,
+    /// `UNTIL`
+    pub until: Option<RecurrenceUntil>,
+    /// `BYMONTH`
+    pub by_month: Vec<u8>,
+    /// `BYMONTHDAY`
+    pub by_month_day: Vec<i8>,
+    /// `BYDAY`
+    pub by_day: Vec<WeekdayNum>,
+    /// `BYSETPOS`
+    pub by_set_pos: Vec<i16>,
+    /// `WKST`
+    pub week_start: Weekday,
+}
+
+impl RecurrenceRule {
+    /// Creates a rule with the given frequency and no other rule parts.
+    pub fn new(frequency: Frequency) -> Self {
+        Self {
+            frequency,
+            interval: 1,
+            count: None,
+            until: None,
+            by_month: Vec::new(),
+            by_month_day: Vec::new(),
+            by_day: Vec::new(),
+            by_set_pos: Vec::new(),
+            week_start: Weekday::Monday,
+        }
+    }
+
+    /// Parses an `RRULE` value, with or without the `RRULE:` property name.
+    pub fn from_utf8(s: &[u8]) -> TemporalResult<Self> {
+        let s = match s.split_at_checked(6) {
+            Some((name, rest)) if name.eq_ignore_ascii_case(b"RRULE:") => rest,
+            _ => s,
+        };
+
+        let mut frequency = None;
+        let mut interval = None;
+        let mut count = None;
+        let mut until = None;
+        let mut by_month = None;
+        let mut by_month_day = None;
+        let mut by_day = None;
+        let mut by_set_pos = None;
+        let mut week_start = None;
+
+        for part in s.split(|b| *b == b';') {
+            let mut split = part.splitn(2, |b| *b == b'=');
+            let name = split.next().unwrap_or_default().to_ascii_uppercase();
+            let value = split
+                .next()
+                .ok_or_else(|| {
+                    invalid_rule("Recurrence rule parts must have the form NAME=VALUE.")
+                })?
+                .to_ascii_uppercase();
+            let value = value.as_slice();
+            match name.as_slice() {
+                b"FREQ" => set_once(&mut frequency, Frequency::from_utf8(value), "FREQ")?,
+                b"INTERVAL" => set_once(
+                    &mut interval,
+                    parse_ascii_digits(value).filter(|n| *n > 0),
+                    "INTERVAL",
+                )?,
+                b"COUNT" => set_once(&mut count, parse_ascii_digits(value), "COUNT")?,
+                b"UNTIL" => set_once(&mut until, RecurrenceUntil::from_utf8(value), "UNTIL")?,
+                b"BYMONTH" => set_once(
+                    &mut by_month,
+                    parse_list(value, |v| {
+                        parse_ascii_digits(v)
+                            .filter(|m| (1..=13).contains(m))
+                            .map(|m| m as u8)
+                    }),
+                    "BYMONTH",
+                )?,
+                b"BYMONTHDAY" => set_once(
+                    &mut by_month_day,
+                    parse_list(value, |v| {
+                        parse_signed(v)
+                            .filter(|d| d.unsigned_abs() <= 31)
+                            .map(|d| d as i8)
+                    }),
+                    "BYMONTHDAY",
+                )?,
+                b"BYDAY" => set_once(
+                    &mut by_day,
+                    parse_list(value, WeekdayNum::from_utf8),
+                    "BYDAY",
+                )?,
+                b"BYSETPOS" => set_once(
+                    &mut by_set_pos,
+                    parse_list(value, |v| {
+                        parse_signed(v)
+                            .filter(|p| p.unsigned_abs() <= 366)
+                            .map(|p| p as i16)
+                    }),
+                    "BYSETPOS",
+                )?,
+                b"WKST" => set_once(&mut week_start, weekday_from_code(value), "WKST")?,
+                b"BYSECOND" | b"BYMINUTE" | b"BYHOUR" | b"BYYEARDAY" | b"BYWEEKNO" => {
+                    return Err(TemporalError::range().with_message(format!(
+                        "The recurrence rule part {} is not supported.",
+                        String::from_utf8_lossy(&name)
+                    )))
+                }
+                _ => return Err(invalid_rule("Unknown recurrence rule part.")),
+            }
+        }
+
+        let rule = Self {
+            frequency: frequency.ok_or_else(|| invalid_rule("A recurrence rule requires FREQ."))?,
+            interval: interval.unwrap_or(1),
+            count,
+            until,
+            by_month: by_month.unwrap_or_default(),
+            by_month_day: by_month_day.unwrap_or_default(),
+            by_day: by_day.unwrap_or_default(),
+            by_set_pos: by_set_pos.unwrap_or_default(),
+            week_start: week_start.unwrap_or(Weekday::Monday),
+        };
+        rule.validate()?;
+        Ok(rule)
+    }
+
+    /// Checks the constraints RFC 5545 places on combinations of rule parts.
+    pub fn validate(&self) -> TemporalResult<()> {
+        if self.interval == 0 {
+            return Err(invalid_rule("INTERVAL must be a positive integer."));
+        }
+        if self.count.is_some() && self.until.is_some() {
+            return Err(invalid_rule("COUNT and UNTIL must not both be specified."));
+        }
+        if self.by_month.iter().any(|m| !(1..=13).contains(m)) {
+            return Err(invalid_rule("BYMONTH values must be between 1 and 13."));
+        }
+        if self
+            .by_month_day
+            .iter()
+            .any(|d| *d == 0 || d.unsigned_abs() > 31)
+        {
+            return Err(invalid_rule(
+                "BYMONTHDAY values must be between 1 and 31 or -31 and -1.",
+            ));
+        }
+        if self.frequency == Frequency::Weekly && !self.by_month_day.is_empty() {
+            return Err(invalid_rule(
+                "BYMONTHDAY must not be specified with FREQ=WEEKLY.",
+            ));
+        }
+        for day in &self.by_day {
+            match day.ordinal {
+                None => {}
+                Some(n) if n == 0 || n.unsigned_abs() > 53 => {
+                    return Err(invalid_rule(
+                        "BYDAY ordinals must be between 1 and 53 or -53 and -1.",
+                    ))
+                }
+                Some(_) if !matches!(self.frequency, Frequency::Monthly | Frequency::Yearly) => {
+                    return Err(invalid_rule(
+                        "BYDAY ordinals are only allowed with FREQ=MONTHLY or FREQ=YEARLY.",
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+        if self
+            .by_set_pos
+            .iter()
+            .any(|p| *p == 0 || p.unsigned_abs() > 366)
+        {
+            return Err(invalid_rule(
+                "BYSETPOS values must be between 1 and 366 or -366 and -1.",
+            ));
+        }
+        if !self.by_set_pos.is_empty()
+            && self.by_month.is_empty()
+            && self.by_month_day.is_empty()
+            && self.by_day.is_empty()
+        {
+            return Err(invalid_rule(
+                "BYSETPOS must be used together with another BYxxx rule part.",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns whether `day` is selected by the `BYxxx` rule parts.
+    fn matches(&self, day: &CandidateDay, index: usize, len: usize, start: &CandidateDay) -> bool {
+        let has_month = !self.by_month.is_empty();
+        let has_month_day = !self.by_month_day.is_empty();
+        let has_day = !self.by_day.is_empty();
+
+        if has_month && !self.by_month.contains(&day.month) {
+            return false;
+        }
+        if has_month_day
+            && !self.by_month_day.iter().any(|d| {
+                let d = i32::from(*d);
+                let from_start = if d > 0 {
+                    d
+                } else {
+                    i32::from(day.days_in_month) + 1 + d
+                };
+                from_start == i32::from(day.day)
+            })
+        {
+            return false;
+        }
+        if has_day {
+            // Ordinals count within the year unless the rule is monthly or limited by BYMONTH.
+            let (nth, nth_last) = if self.frequency == Frequency::Yearly && !has_month {
+                (index / 7 + 1, (len - 1 - index) / 7 + 1)
+            } else {
+                let day_index = usize::from(day.day - 1);
+                let remaining = usize::from(day.days_in_month) - usize::from(day.day);
+                (day_index / 7 + 1, remaining / 7 + 1)
+            };
+            let matches_weekday = self.by_day.iter().any(|w| {
+                w.weekday == day.weekday
+                    && match w.ordinal {
+                        None => true,
+                        Some(n) if n > 0 => n.unsigned_abs() as usize == nth,
+                        Some(n) => n.unsigned_abs() as usize == nth_last,
+                    }
+            });
+            if !matches_weekday {
+                return false;
+            }
+        }
+
+        // Without explicit BYxxx parts, occurrences repeat the corresponding fields of DTSTART.
+        match self.frequency {
+            Frequency::Yearly if !has_month && !has_month_day && !has_day => {
+                day.month_code == start.month_code && day.day == start.day
+            }
+            Frequency::Yearly | Frequency::Monthly if !has_month_day && !has_day => {
+                day.day == start.day
+            }
+            Frequency::Weekly if !has_day => day.weekday == start.weekday,
+            _ => true,
+        }
+    }
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = TemporalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_utf8(s.as_bytes())
+    }
+}
+
+impl RecurrenceRule {
+    /// Returns the `RRULE` value of this rule, without the `RRULE:` prefix.
+    ///
+    /// Returns a `RangeError` if the year of `UNTIL` is not between 0 and 9999.
+    pub fn to_icalendar_string(&self) -> TemporalResult<String> {
+        let until = self
+            .until
+            .as_ref()
+            .map(RecurrenceUntil::to_icalendar_string)
+            .transpose()?;
+        let mut result = String::new();
+        self.write_parts(&mut result, until.as_deref())
+            .map_err(|_| TemporalError::assert())?;
+        Ok(result)
+    }
+
+    fn write_parts<W: fmt::Write + ?Sized>(&self, f: &mut W, until: Option<&str>) -> fmt::Result {
+        fn write_list<T: fmt::Display, W: fmt::Write + ?Sized>(
+            f: &mut W,
+            name: &str,
+            values: &[T],
+        ) -> fmt::Result {
+            if values.is_empty() {
+                return Ok(());
+            }
+            write!(f, ";{name}=")?;
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(",")?;
+                }
+                write!(f, "{value}")?;
+            }
+            Ok(())
+        }
+
+        write!(f, "FREQ={}", self.frequency)?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={count}")?;
+        }
+        if let Some(until) = until {
+            write!(f, ";UNTIL={until}")?;
+        }
+        write_list(f, "BYMONTH", &self.by_month)?;
+        write_list(f, "BYMONTHDAY", &self.by_month_day)?;
+        write_list(f, "BYDAY", &self.by_day)?;
+        write_list(f, "BYSETPOS", &self.by_set_pos)?;
+        if self.week_start != Weekday::Monday {
+            write!(f, ";WKST={}", weekday_code(self.week_start))?;
+        }
+        Ok(())
+    }
+}
+
+/// A recurrence set: a `DTSTART`, an optional [`RecurrenceRule`], and lists of
+/// additional (`RDATE`) and excluded (`EXDATE`) occurrences.
+///
+/// `DTSTART` is always the first occurrence of the set, and `COUNT` includes
+/// it. Occurrences are yielded in ascending order of their exact time, without
+/// duplicates.
+#[derive(Debug, Clone)]
+pub struct RecurrenceSet {
+    dtstart: ZonedDateTime,
+    rule: Option<RecurrenceRule>,
+    rdates: Vec<ZonedDateTime>,
+    exdates: Vec<ZonedDateTime>,
+    disambiguation: Disambiguation,
+}
+
+impl RecurrenceSet {
+    /// Creates a recurrence set whose only occurrence is `dtstart`.
+    pub fn new(dtstart: ZonedDateTime) -> Self {
+        Self {
+            dtstart,
+            rule: None,
+            rdates: Vec::new(),
+            exdates: Vec::new(),
+            disambiguation: Disambiguation::Compatible,
+        }
+    }
+
+    /// Sets the recurrence rule of this set.
+    pub fn with_rule(mut self, rule: RecurrenceRule) -> TemporalResult<Self> {
+        rule.validate()?;
+        self.rule = Some(rule);
+        Ok(self)
+    }
+
+    /// Adds an `RDATE` occurrence.
+    pub fn with_rdate(mut self, rdate: ZonedDateTime) -> Self {
+        let index = self
+            .rdates
+            .partition_point(|d| d.epoch_nanoseconds() <= rdate.epoch_nanoseconds());
+        self.rdates.insert(index, rdate);
+        self
+    }
+
+    /// Adds an `EXDATE`, removing any occurrence at the same exact time.
+    pub fn with_exdate(mut self, exdate: ZonedDateTime) -> Self {
+        self.exdates.push(exdate);
+        self
+    }
+
+    /// Sets how wall-clock occurrences in DST gaps and overlaps are resolved.
+    ///
+    /// The default is [`Disambiguation::Compatible`].
+    pub fn with_disambiguation(mut self, disambiguation: Disambiguation) -> Self {
+        self.disambiguation = disambiguation;
+        self
+    }
+
+    /// Returns the `DTSTART` of this set.
+    pub fn dtstart(&self) -> &ZonedDateTime {
+        &self.dtstart
+    }
+
+    /// Returns the recurrence rule of this set.
+    pub fn rule(&self) -> Option<&RecurrenceRule> {
+        self.rule.as_ref()
+    }
+
+    /// Returns the `RDATE` occurrences, in ascending order.
+    pub fn rdates(&self) -> &[ZonedDateTime] {
+        &self.rdates
+    }
+
+    /// Returns the `EXDATE` values.
+    pub fn exdates(&self) -> &[ZonedDateTime] {
+        &self.exdates
+    }
+
+    /// Returns an iterator over the occurrences of this set.
+    ///
+    /// The iterator is unbounded for rules without `COUNT` or `UNTIL`. It
+    /// yields a `RangeError` when the rule selects no occurrence for 400
+    /// years, as it would then never select another one.
+    pub fn iter_with_provider<'a, P: TimeZoneProvider>(
+        &'a self,
+        provider: &'a P,
+    ) -> Occurrences<'a, P> {
+        Occurrences::new(self, provider, None)
+    }
+
+    /// Returns the occurrences at or after `start` and before `end`.
+    pub fn between_with_provider(
+        &self,
+        start: &Instant,
+        end: &Instant,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Vec<ZonedDateTime>> {
+        let end_ns = end.epoch_nanoseconds().0;
+        // Stop expanding once whole periods lie after `end`.
+        let end_date = ZonedDateTime::try_new_with_provider(
+            end_ns,
+            self.dtstart.calendar().clone(),
+            self.dtstart.timezone().clone(),
+            provider,
+        )?
+        .get_iso_datetime()
+        .date;
+        let mut result = Vec::new();
+        for occurrence in Occurrences::new(self, provider, Some(add_days(&end_date, 1))) {
+            let occurrence = occurrence?;
+            let ns = occurrence.epoch_nanoseconds().0;
+            if ns >= end_ns {
+                break;
+            }
+            if ns >= start.epoch_nanoseconds().0 {
+                result.push(occurrence);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Returns an iterator over the occurrences of this set.
+    #[cfg(feature = "compiled_data")]
+    pub fn iter(&self) -> Occurrences<'_, crate::tzdb::CompiledTzdbProvider> {
+        self.iter_with_provider(&*crate::builtins::TZ_PROVIDER)
+    }
+
+    /// Returns the occurrences at or after `start` and before `end`.
+    #[cfg(feature = "compiled_data")]
+    pub fn between(&self, start: &Instant, end: &Instant) -> TemporalResult<Vec<ZonedDateTime>> {
+        self.between_with_provider(start, end, &*crate::builtins::TZ_PROVIDER)
+    }
+}
+
+/// An iterator over the occurrences of a [`RecurrenceSet`].
+///
+/// Created by [`RecurrenceSet::iter_with_provider`]. Iteration stops after the
+/// first error.
+#[derive(Debug)]
+pub struct Occurrences<'a, P> {
+    set: &'a RecurrenceSet,
+    provider: &'a P,
+    rule: RuleExpansion<'a>,
+    next_rule: Option<ZonedDateTime>,
+    next_rdate: usize,
+    last: Option<i128>,
+    failed: bool,
+}
+
+impl<'a, P: TimeZoneProvider> Occurrences<'a, P> {
+    fn new(set: &'a RecurrenceSet, provider: &'a P, horizon: Option<IsoDate>) -> Self {
+        Self {
+            set,
+            provider,
+            rule: RuleExpansion::new(set, horizon),
+            next_rule: None,
+            next_rdate: 0,
+            last: None,
+            failed: false,
+        }
+    }
+}
+
+impl<P: TimeZoneProvider> Iterator for Occurrences<'_, P> {
+    type Item = TemporalResult<ZonedDateTime>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.failed {
+                return None;
+            }
+            if self.next_rule.is_none() {
+                match self.rule.next(self.provider) {
+                    Ok(next) => self.next_rule = next,
+                    Err(e) => {
+                        self.failed = true;
+                        return Some(Err(e));
+                    }
+                }
+            }
+
+            let rdate = self.set.rdates.get(self.next_rdate);
+            let next = match (&self.next_rule, rdate) {
+                (None, None) => return None,
+                (Some(rule), Some(rdate))
+                    if rdate.epoch_nanoseconds() < rule.epoch_nanoseconds() =>
+                {
+                    self.next_rdate += 1;
+                    rdate.clone()
+                }
+                (_, Some(rdate)) if self.next_rule.is_none() => {
+                    self.next_rdate += 1;
+                    rdate.clone()
+                }
+                _ => self.next_rule.take()?,
+            };
+
+            let ns = next.epoch_nanoseconds().0;
+            if self.last == Some(ns) {
+                continue;
+            }
+            self.last = Some(ns);
+            if self
+                .set
+                .exdates
+                .iter()
+                .any(|exdate| exdate.epoch_nanoseconds().0 == ns)
+            {
+                continue;
+            }
+            return Some(Ok(next));
+        }
+    }
+}
+
+/// The wall-clock fields of a day considered for a recurrence.
+#[derive(Debug, Clone, Copy)]
+struct CandidateDay {
+    iso: IsoDate,
+    month: u8,
+    month_code: MonthCode,
+    day: u8,
+    days_in_month: u16,
+    weekday: Weekday,
+}
+
+impl CandidateDay {
+    fn new(set: &RecurrenceSet, iso: IsoDate) -> Self {
+        let calendar = set.dtstart.calendar();
+        Self {
+            iso,
+            month: calendar.month(&iso),
+            month_code: calendar.month_code(&iso),
+            day: calendar.day(&iso),
+            days_in_month: calendar.days_in_month(&iso),
+            weekday: iso.to_icu4x().day_of_week(),
+        }
+    }
+}
+
+/// The expansion state of the `RRULE` of a [`RecurrenceSet`].
+#[derive(Debug)]
+struct RuleExpansion<'a> {
+    set: &'a RecurrenceSet,
+    start: IsoDateTime,
+    start_day: CandidateDay,
+    horizon: Option<IsoDate>,
+    period: i64,
+    pending: VecDeque<(IsoDateTime, ZonedDateTime)>,
+    /// The number of periods expanded since the latest queued occurrence.
+    empty_periods: u32,
+    emitted: u32,
+    done: bool,
+}
+
+/// Returns the number of periods without an occurrence after which a rule is
+/// assumed to never select another one.
+///
+/// This covers 400 years, after which the Gregorian calendar repeats. Periods
+/// shorter than a day skip to the next day when they select nothing, so they
+/// are bounded by days.
+const fn max_empty_periods(frequency: Frequency) -> u32 {
+    match frequency {
+        Frequency::Yearly => 400,
+        // Lunisolar calendars have up to 13 months in a year.
+        Frequency::Monthly => 400 * 13,
+        Frequency::Weekly => 400 * 53,
+        _ => 146_097,
+    }
+}
+
+impl<'a> RuleExpansion<'a> {
+    fn new(set: &'a RecurrenceSet, horizon: Option<IsoDate>) -> Self {
+        let start = set.dtstart.get_iso_datetime();
+        let until_date = set
+            .rule
+            .as_ref()
+            .and_then(|rule| rule.until.as_ref())
+            .and_then(RecurrenceUntil::last_date);
+        let horizon = match (horizon, until_date) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        Self {
+            set,
+            start,
+            start_day: CandidateDay::new(set, start.date),
+            horizon,
+            period: 0,
+            pending: VecDeque::from([(start, set.dtstart.clone())]),
+            empty_periods: 0,
+            emitted: 0,
+            done: false,
+        }
+    }
+
+    fn next(&mut self, provider: &impl TimeZoneProvider) -> TemporalResult<Option<ZonedDateTime>> {
+        let rule = self.set.rule.as_ref();
+        loop {
+            if self.done
+                || rule
+                    .and_then(|r| r.count)
+                    .is_some_and(|c| self.emitted >= c)
+            {
+                self.done = true;
+                return Ok(None);
+            }
+            if let Some((local, zdt)) = self.pending.pop_front() {
+                let until = rule.and_then(|r| r.until.as_ref());
+                if until.is_some_and(|u| u.is_exceeded_by(&local, zdt.epoch_nanoseconds().0)) {
+                    self.done = true;
+                    return Ok(None);
+                }
+                self.emitted += 1;
+                return Ok(Some(zdt));
+            }
+            let Some(rule) = rule else {
+                self.done = true;
+                return Ok(None);
+            };
+            self.expand_period(rule, provider)?;
+            self.period += 1;
+        }
+    }
+
+    /// Queues the occurrences of the current period that follow `DTSTART`.
+    fn expand_period(
+        &mut self,
+        rule: &RecurrenceRule,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<()> {
+        let set = self.set;
+        let step = self.period * i64::from(rule.interval);
+
+        let (days, sub_daily) = match rule.frequency {
+            Frequency::Hourly | Frequency::Minutely | Frequency::Secondly => {
+                let duration = match rule.frequency {
+                    Frequency::Hourly => Duration::new(0, 0, 0, 0, step, 0, 0, 0, 0, 0),
+                    Frequency::Minutely => Duration::new(0, 0, 0, 0, 0, step, 0, 0, 0, 0),
+                    _ => Duration::new(0, 0, 0, 0, 0, 0, step, 0, 0, 0),
+                }?;
+                let zdt = set.dtstart.add_with_provider(&duration, None, provider)?;
+                let local = zdt.get_iso_datetime();
+                (
+                    Vec::from([CandidateDay::new(set, local.date)]),
+                    Some((local, zdt)),
+                )
+            }
+            frequency => {
+                let duration = match frequency {
+                    Frequency::Yearly => DateDuration::new(step, 0, 0, 0),
+                    Frequency::Monthly => DateDuration::new(0, step, 0, 0),
+                    Frequency::Weekly => DateDuration::new(0, 0, step, 0),
+                    _ => DateDuration::new(0, 0, 0, step),
+                }?;
+                let anchor = set
+                    .dtstart
+                    .calendar()
+                    .date_add(&self.start.date, &duration, ArithmeticOverflow::Constrain)?
+                    .iso;
+                (self.period_days(rule, &anchor), None)
+            }
+        };
+
+        let Some(first) = days.first() else {
+            return Ok(());
+        };
+        if self.horizon.is_some_and(|horizon| first.iso > horizon) {
+            self.done = true;
+            return Ok(());
+        }
+        self.empty_periods += 1;
+        if self.empty_periods > max_empty_periods(rule.frequency) {
+            self.done = true;
+            return Err(TemporalError::range()
+                .with_message("RRULE selects no occurrence within 400 years."));
+        }
+
+        let selected: Vec<&CandidateDay> = days
+            .iter()
+            .enumerate()
+            .filter(|(i, day)| rule.matches(day, *i, days.len(), &self.start_day))
+            .map(|(_, day)| day)
+            .collect();
+        let selected: Vec<&CandidateDay> = if rule.by_set_pos.is_empty() {
+            selected
+        } else {
+            let len = selected.len() as i64;
+            let mut indices: Vec<usize> = rule
+                .by_set_pos
+                .iter()
+                .filter_map(|pos| {
+                    let pos = i64::from(*pos);
+                    let index = if pos > 0 { pos - 1 } else { len + pos };
+                    usize::try_from(index).ok()
+                })
+                .collect();
+            indices.sort_unstable();
+            indices.dedup();
+            indices
+                .into_iter()
+                .filter_map(|i| selected.get(i).copied())
+                .collect()
+        };
+
+        if let (Some((local, zdt)), true) = (&sub_daily, selected.is_empty()) {
+            // The rule parts only select days, so the rest of the day is rejected as well.
+            self.skip_to_next_day(rule, local, zdt, provider)?;
+            return Ok(());
+        }
+
+        let start_ns = set.dtstart.epoch_nanoseconds().0;
+        for day in selected {
+            let (local, zdt) = match &sub_daily {
+                Some(occurrence) => occurrence.clone(),
+                None => {
+                    let local = IsoDateTime::new_unchecked(day.iso, self.start.time);
+                    if !local.is_within_limits() {
+                        continue;
+                    }
+                    let resolved = set.dtstart.timezone().get_epoch_nanoseconds_for(
+                        local,
+                        set.disambiguation,
+                        provider,
+                    )?;
+                    let zdt = ZonedDateTime::try_new_with_cached_offset(
+                        resolved.ns.0,
+                        set.dtstart.calendar().clone(),
+                        set.dtstart.timezone().clone(),
+                        resolved.offset,
+                    )?;
+                    (local, zdt)
+                }
+            };
+            if zdt.epoch_nanoseconds().0 > start_ns {
+                self.empty_periods = 0;
+                self.pending.push_back((local, zdt));
+            }
+        }
+        self.pending
+            .make_contiguous()
+            .sort_by_key(|(_, zdt)| zdt.epoch_nanoseconds().0);
+        Ok(())
+    }
+
+    /// Advances a sub-daily expansion so that the next period is the first one
+    /// after the local day of `local`.
+    fn skip_to_next_day(
+        &mut self,
+        rule: &RecurrenceRule,
+        local: &IsoDateTime,
+        zdt: &ZonedDateTime,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<()> {
+        let next_day = IsoDateTime::new_unchecked(add_days(&local.date, 1), IsoTime::default());
+        if !next_day.is_within_limits() {
+            return Ok(());
+        }
+        let next_ns = self
+            .set
+            .dtstart
+            .timezone()
+            .get_epoch_nanoseconds_for(next_day, Disambiguation::Compatible, provider)?
+            .ns
+            .0;
+        let unit_ns: i128 = match rule.frequency {
+            Frequency::Hourly => 3_600_000_000_000,
+            Frequency::Minutely => 60_000_000_000,
+            _ => 1_000_000_000,
+        };
+        let step_ns = unit_ns * i128::from(rule.interval);
+        let remaining = next_ns - zdt.epoch_nanoseconds().0;
+        // The number of periods until the first one at or after `next_ns`,
+        // less the one added by `next`.
+        let skipped = (remaining + step_ns - 1) / step_ns - 1;
+        self.period = self
+            .period
+            .saturating_add(i64::try_from(skipped.max(0)).unwrap_or(i64::MAX));
+        Ok(())
+    }
+
+    /// Returns the days of the period containing `anchor`.
+    fn period_days(&self, rule: &RecurrenceRule, anchor: &IsoDate) -> Vec<CandidateDay> {
+        let set = self.set;
+        let calendar = set.dtstart.calendar();
+        match rule.frequency {
+            Frequency::Yearly => calendar
+                .month_codes_in_year(anchor)
+                .iter()
+                .flat_map(|month| {
+                    let start = month.start();
+                    (0..month.days_in_month()).map(move |i| CandidateDay {
+                        iso: add_days(&start, i32::from(i)),
+                        month: month.month(),
+                        month_code: month.month_code(),
+                        day: (i + 1) as u8,
+                        days_in_month: month.days_in_month(),
+                        weekday: add_days(&start, i32::from(i)).to_icu4x().day_of_week(),
+                    })
+                })
+                .collect(),
+            Frequency::Monthly => {
+                let anchor_day = CandidateDay::new(set, *anchor);
+                let start = add_days(anchor, 1 - i32::from(anchor_day.day));
+                (0..anchor_day.days_in_month)
+                    .map(|i| {
+                        let iso = add_days(&start, i32::from(i));
+                        CandidateDay {
+                            iso,
+                            day: (i + 1) as u8,
+                            weekday: iso.to_icu4x().day_of_week(),
+                            ..anchor_day
+                        }
+                    })
+                    .collect()
+            }
+            Frequency::Weekly => {
+                let weekday = anchor.to_icu4x().day_of_week() as i32;
+                let offset = (weekday - rule.week_start as i32).rem_euclid(7);
+                (0..7)
+                    .map(|i| CandidateDay::new(set, add_days(anchor, i - offset)))
+                    .collect()
+            }
+            _ => Vec::from([CandidateDay::new(set, *anchor)]),
+        }
+    }
+}
+
+fn add_days(date: &IsoDate, days: i32) -> IsoDate {
+    IsoDate::balance(date.year, i32::from(date.month), i32::from(date.day) + days)
+}
+
+fn invalid_rule(message: &'static str) -> TemporalError {
+    TemporalError::range().with_message(message)
+}
+
+fn set_once<T>(slot: &mut Option<T>, value: Option<T>, name: &str) -> TemporalResult<()> {
+    if slot.is_some() {
+        return Err(TemporalError::range()
+            .with_message(format!("The recurrence rule part {name} is repeated.")));
+    }
+    let value = value.ok_or_else(|| {
+        TemporalError::range().with_message(format!(
+            "Invalid value for the recurrence rule part {name}."
+        ))
+    })?;
+    *slot = Some(value);
+    Ok(())
+}
+
+fn parse_list<T>(value: &[u8], parse: impl Fn(&[u8]) -> Option<T>) -> Option<Vec<T>> {
+    value.split(|b| *b == b',').map(parse).collect()
+}
+
+fn parse_signed(value: &[u8]) -> Option<i32> {
+    match value {
+        [b'-', digits @ ..] => parse_ascii_digits(digits).map(|n| -(n as i32)),
+        [b'+', digits @ ..] => parse_ascii_digits(digits).map(|n| n as i32),
+        digits => parse_ascii_digits(digits).map(|n| n as i32),
+    }
+}
+
+const WEEKDAYS: [(Weekday, &str); 7] = [
+    (Weekday::Monday, "MO"),
+    (Weekday::Tuesday, "TU"),
+    (Weekday::Wednesday, "WE"),
+    (Weekday::Thursday, "TH"),
+    (Weekday::Friday, "FR"),
+    (Weekday::Saturday, "SA"),
+    (Weekday::Sunday, "SU"),
+];
+
+fn weekday_code(weekday: Weekday) -> &'static str {
+    WEEKDAYS
+        .iter()
+        .find(|(w, _)| *w == weekday)
+        .map_or("", |(_, code)| code)
+}
+
+fn weekday_from_code(code: &[u8]) -> Option<Weekday> {
+    WEEKDAYS
+        .iter()
+        .find(|(_, c)| c.as_bytes() == code)
+        .map(|(w, _)| *w)
+}
+
+#[cfg(all(test, feature = "tzdb"))]
+mod tests {
+    use alloc::{string::String, vec::Vec};
+    use core::str::FromStr;
+
+    use icu_calendar::types::Weekday;
+
+    use super::{Frequency, RecurrenceRule, RecurrenceSet, RecurrenceUntil, WeekdayNum};
+    use crate::{
+        options::{
+            Disambiguation, DisplayCalendar, DisplayOffset, DisplayTimeZone, OffsetDisambiguation,
+            ToStringRoundingOptions,
+        },
+        tzdb::FsTzdbProvider,
+        Instant, PlainDate, TemporalResult, ZonedDateTime,
+    };
+
+    fn zdt(s: &str, provider: &FsTzdbProvider) -> ZonedDateTime {
+        ZonedDateTime::from_utf8_with_provider(
+            s.as_bytes(),
+            Disambiguation::Compatible,
+            OffsetDisambiguation::Reject,
+            provider,
+        )
+        .unwrap()
+    }
+
+    fn expand(set: &RecurrenceSet, provider: &FsTzdbProvider, limit: usize) -> Vec<String> {
+        set.iter_with_provider(provider)
+            .take(limit)
+            .map(|occurrence| {
+                occurrence.and_then(|zdt| {
+                    zdt.to_ixdtf_string_with_provider(
+                        DisplayOffset::Auto,
+                        DisplayTimeZone::Never,
+                        DisplayCalendar::Never,
+                        ToStringRoundingOptions::default(),
+                        provider,
+                    )
+                })
+            })
+            .collect::<TemporalResult<Vec<_>>>()
+            .unwrap()
+    }
+
+    fn rule_set(dtstart: &str, rule: &str, provider: &FsTzdbProvider) -> RecurrenceSet {
+        RecurrenceSet::new(zdt(dtstart, provider))
+            .with_rule(RecurrenceRule::from_str(rule).unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn parse_rule() {
+        let rule = RecurrenceRule::from_str(
+            "RRULE:freq=weekly;interval=2;byday=tu,th;until=20240220T100000Z;wkst=su",
+        )
+        .unwrap();
+        assert_eq!(rule.frequency, Frequency::Weekly);
+        assert_eq!(rule.interval, 2);
+        assert_eq!(
+            rule.by_day,
+            [
+                WeekdayNum::every(Weekday::Tuesday),
+                WeekdayNum::every(Weekday::Thursday)
+            ]
+        );
+        assert_eq!(rule.week_start, Weekday::Sunday);
+        assert!(matches!(rule.until, Some(RecurrenceUntil::Instant(_))));
+        assert_eq!(
+            rule.to_icalendar_string().unwrap(),
+            "FREQ=WEEKLY;INTERVAL=2;UNTIL=20240220T100000Z;BYDAY=TU,TH;WKST=SU"
+        );
+
+        let rule = RecurrenceRule::from_str("FREQ=MONTHLY;BYDAY=-1FR,2MO;UNTIL=20241231").unwrap();
+        assert_eq!(
+            rule.by_day,
+            [
+                WeekdayNum::nth(-1, Weekday::Friday),
+                WeekdayNum::nth(2, Weekday::Monday)
+            ]
+        );
+        assert_eq!(
+            RecurrenceRule::from_str(&rule.to_icalendar_string().unwrap()).unwrap(),
+            rule
+        );
+
+        let mut rule = RecurrenceRule::new(Frequency::Daily);
+        for year in [-1, 10000] {
+            let until = PlainDate::try_new_iso(year, 1, 1).unwrap();
+            rule.until = Some(RecurrenceUntil::Date(until));
+            assert!(rule.to_icalendar_string().is_err());
+        }
+
+        for invalid in [
+            "",
+            "INTERVAL=2",
+            "FREQ=FORTNIGHTLY",
+            "FREQ=DAILY;FREQ=DAILY",
+            "FREQ=DAILY;INTERVAL=0",
+            "FREQ=DAILY;COUNT=2;UNTIL=20240101",
+            "FREQ=DAILY;BYSETPOS=1",
+            "FREQ=DAILY;BYDAY=1MO",
+            "FREQ=WEEKLY;BYMONTHDAY=1",
+            "FREQ=MONTHLY;BYMONTHDAY=0",
+            "FREQ=MONTHLY;BYMONTHDAY=32",
+            "FREQ=YEARLY;BYMONTH=14",
+            "FREQ=DAILY;BYHOUR=9",
+            "FREQ=DAILY;UNTIL=20240230",
+            "FREQ=DAILY;X-NAME=1",
+            "FREQ=DAILY;COUNT",
+        ] {
+            assert!(RecurrenceRule::from_str(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn monthly_rules() {
+        let provider = &FsTzdbProvider::default();
+
+        // Months without a 31st are skipped rather than constrained.
+        let set = rule_set(
+            "2024-01-31T09:00[America/New_York]",
+            "FREQ=MONTHLY;COUNT=4",
+            provider,
+        );
+        assert_eq!(
+            expand(&set, provider, 10),
+            [
+                "2024-01-31T09:00:00-05:00",
+                "2024-03-31T09:00:00-04:00",
+                "2024-05-31T09:00:00-04:00",
+                "2024-07-31T09:00:00-04:00",
+            ]
+        );
+
+        // The last weekday of each month.
+        let set = rule_set(
+            "2024-01-31T09:00[America/New_York]",
+            "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1;COUNT=4",
+            provider,
+        );
+        assert_eq!(
+            expand(&set, provider, 10),
+            [
+                "2024-01-31T09:00:00-05:00",
+                "2024-02-29T09:00:00-05:00",
+                "2024-03-29T09:00:00-04:00",
+                "2024-04-30T09:00:00-04:00",
+            ]
+        );
+
+        // Occurrences before DTSTART in its first period are not included.
+        let set = rule_set(
+            "2024-01-15T12:00[UTC]",
+            "FREQ=MONTHLY;BYMONTHDAY=1,-1;COUNT=4",
+            provider,
+        );
+        assert_eq!(
+            expand(&set, provider, 10),
+            [
+                "2024-01-15T12:00:00+00:00",
+                "2024-01-31T12:00:00+00:00",
+                "2024-02-01T12:00:00+00:00",
+                "2024-02-29T12:00:00+00:00",
+            ]
+        );
+    }
+
+    #[test]
+    fn yearly_and_weekly_rules() {
+        let provider = &FsTzdbProvider::default();
+
+        // US Thanksgiving.
+        let set = rule_set(
+            "2023-11-23T18:00[America/Chicago]",
+            "FREQ=YEARLY;BYMONTH=11;BYDAY=4TH;COUNT=3",
+            provider,
+        );
+        assert_eq!(
+            expand(&set, provider, 10),
+            [
+                "2023-11-23T18:00:00-06:00",
+                "2024-11-28T18:00:00-06:00",
+                "2025-11-27T18:00:00-06:00",
+            ]
+        );
+
+        // Leap days only recur in leap years.
+        let set = rule_set("2024-02-29T00:00[UTC]", "FREQ=YEARLY;COUNT=2", provider);
+        assert_eq!(
+            expand(&set, provider, 10),
+            ["2024-02-29T00:00:00+00:00", "2028-02-29T00:00:00+00:00"]
+        );
+
+        // The first Monday of the year.
+        let set = rule_set(
+            "2024-01-01T08:00[UTC]",
+            "FREQ=YEARLY;BYDAY=1MO;COUNT=3",
+            provider,
+        );
+        assert_eq!(
+            expand(&set, provider, 10),
+            [
+                "2024-01-01T08:00:00+00:00",
+                "2025-01-06T08:00:00+00:00",
+                "2026-01-05T08:00:00+00:00",
+            ]
+        );
+
+        let set = rule_set(
+            "2024-02-01T10:00[Europe/London]",
+            "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH;UNTIL=20240227",
+            provider,
+        );
+        assert_eq!(
+            expand(&set, provider, 10),
+            [
+                "2024-02-01T10:00:00+00:00",
+                "2024-02-13T10:00:00+00:00",
+                "2024-02-15T10:00:00+00:00",
+                "2024-02-27T10:00:00+00:00",
+            ]
+        );
+    }
+
+    #[test]
+    fn dst_transitions() {
+        let provider = &FsTzdbProvider::default();
+
+        let set = rule_set(
+            "2024-03-09T02:30[America/New_York]",
+            "FREQ=DAILY;COUNT=3",
+            provider,
+        );
+        assert_eq!(
+            expand(&set, provider, 10),
+            [
+                "2024-03-09T02:30:00-05:00",
+                "2024-03-10T03:30:00-04:00",
+                "2024-03-11T02:30:00-04:00",
+            ]
+        );
+        let set = set.with_disambiguation(Disambiguation::Earlier);
+        assert_eq!(expand(&set, provider, 10)[1], "2024-03-10T01:30:00-05:00");
+        let set = set.with_disambiguation(Disambiguation::Reject);
+        assert!(set
+            .iter_with_provider(provider)
+            .nth(1)
+            .is_some_and(|r| r.is_err()));
+
+        // Repeated wall-clock times resolve to the earlier offset by default.
+        let set = rule_set(
+            "2024-11-02T01:30[America/New_York]",
+            "FREQ=DAILY;COUNT=2",
+            provider,
+        );
+        assert_eq!(
+            expand(&set, provider, 10),
+            ["2024-11-02T01:30:00-04:00", "2024-11-03T01:30:00-04:00"]
+        );
+
+        // Sub-daily frequencies step in exact time.
+        let set = rule_set(
+            "2024-03-10T01:00[America/New_York]",
+            "FREQ=HOURLY;INTERVAL=1;COUNT=3",
+            provider,
+        );
+        assert_eq!(
+            expand(&set, provider, 10),
+            [
+                "2024-03-10T01:00:00-05:00",
+                "2024-03-10T03:00:00-04:00",
+                "2024-03-10T04:00:00-04:00",
+            ]
+        );
+    }
+
+    #[test]
+    fn rdates_and_exdates() {
+        let provider = &FsTzdbProvider::default();
+        let set = rule_set("2024-01-01T10:00[UTC]", "FREQ=DAILY;COUNT=3", provider)
+            .with_exdate(zdt("2024-01-02T10:00[UTC]", provider))
+            .with_rdate(zdt("2024-01-10T10:00[UTC]", provider))
+            .with_rdate(zdt("2024-01-03T10:00[UTC]", provider))
+            .with_rdate(zdt("2023-12-25T10:00[UTC]", provider));
+        // EXDATE does not change COUNT, and duplicate RDATEs are merged.
+        assert_eq!(
+            expand(&set, provider, 10),
+            [
+                "2023-12-25T10:00:00+00:00",
+                "2024-01-01T10:00:00+00:00",
+                "2024-01-03T10:00:00+00:00",
+                "2024-01-10T10:00:00+00:00",
+            ]
+        );
+
+        let single = RecurrenceSet::new(zdt("2024-01-01T10:00[UTC]", provider));
+        assert_eq!(expand(&single, provider, 10), ["2024-01-01T10:00:00+00:00"]);
+    }
+
+    #[test]
+    fn between() {
+        let provider = &FsTzdbProvider::default();
+        let set = rule_set(
+            "2024-01-01T00:00[UTC]",
+            "FREQ=MONTHLY;BYMONTH=2;BYMONTHDAY=30",
+            provider,
+        );
+        let start = Instant::from_str("2024-01-01T00:00Z").unwrap();
+        let end = Instant::from_str("2030-01-01T00:00Z").unwrap();
+        // Only DTSTART matches, and expansion stops at `end`.
+        assert_eq!(
+            set.between_with_provider(&start, &end, provider)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let set = rule_set("2024-01-01T09:00[Asia/Tokyo]", "FREQ=WEEKLY", provider);
+        let start = Instant::from_str("2024-01-10T00:00Z").unwrap();
+        let end = Instant::from_str("2024-02-01T00:00Z").unwrap();
+        let occurrences = set.between_with_provider(&start, &end, provider).unwrap();
+        assert_eq!(
+            occurrences
+                .iter()
+                .map(|zdt| zdt.day().unwrap())
+                .collect::<Vec<_>>(),
+            [15, 22, 29]
+        );
+    }
+
+    #[test]
+    fn rules_without_occurrences() {
+        let provider = &FsTzdbProvider::default();
+        // A daily period has at most one day, so BYSETPOS=2 never selects it.
+        let set = rule_set(
+            "2024-01-01T00:00[UTC]",
+            "FREQ=DAILY;BYDAY=MO;BYSETPOS=2;COUNT=2",
+            provider,
+        );
+        let mut occurrences = set.iter_with_provider(provider);
+        assert!(occurrences.next().unwrap().is_ok());
+        assert!(occurrences.next().unwrap().is_err());
+        assert!(occurrences.next().is_none());
+
+        // February never has a 30th day; each empty day is skipped at once.
+        let set = rule_set(
+            "2024-01-01T00:00[UTC]",
+            "FREQ=SECONDLY;BYMONTH=2;BYMONTHDAY=30",
+            provider,
+        );
+        let mut occurrences = set.iter_with_provider(provider);
+        assert!(occurrences.next().unwrap().is_ok());
+        assert!(occurrences.next().unwrap().is_err());
+
+        // Sub-daily rules resume at the start of the next selected day.
+        let set = rule_set(
+            "2024-01-31T23:59:58[UTC]",
+            "FREQ=SECONDLY;INTERVAL=7;BYMONTH=2;COUNT=3",
+            provider,
+        );
+        assert_eq!(
+            expand(&set, provider, 3)[1..],
+            ["2024-02-01T00:00:05+00:00", "2024-02-01T00:00:12+00:00"]
+        );
+
+        // Leap days on a Monday are decades apart.
+        let set = rule_set(
+            "2024-01-01T00:00[UTC]",
+            "FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=29;BYDAY=MO",
+            provider,
+        );
+        assert_eq!(
+            expand(&set, provider, 3)[1..],
+            ["2044-02-29T00:00:00+00:00", "2072-02-29T00:00:00+00:00"]
+        );
+    }
+}
@@ -0,0 +1,398 @@
+//! Relative time phrases, such as "3 days ago" or "in 2 hours".
+//!
+//! A [`RelativeTime`] is the difference between two values expressed in a
+//! single unit. The unit is the smallest one whose rounded value stays below
+//! its [`RelativeTimeThresholds`] entry, so 50 seconds becomes 1 minute and
+//! 23 hours becomes 1 day. The value is locale-independent; with the `locale`
+//! feature it can also be formatted for a locale.
+
+use crate::{
+    options::{DifferenceSettings, RelativeTo, RoundingMode, RoundingOptions, Unit},
+    provider::{NeverProvider, TimeZoneProvider},
+    Duration, Instant, PlainDate, PlainDateTime, PlainTime, TemporalError, TemporalResult,
+    ZonedDateTime,
+};
+
+/// The units a relative time can be expressed in, from smallest to largest.
+const UNITS: [Unit; 7] = [
+    Unit::Second,
+    Unit::Minute,
+    Unit::Hour,
+    Unit::Day,
+    Unit::Week,
+    Unit::Month,
+    Unit::Year,
+];
+
+/// A signed amount of a single unit, e.g. -3 days for "3 days ago".
+///
+/// Positive values are in the future and negative values are in the past.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelativeTime {
+    value: i64,
+    unit: Unit,
+}
+
+impl RelativeTime {
+    /// Creates a relative time of `value` seconds, minutes, hours, days,
+    /// weeks, months or years.
+    pub fn try_new(value: i64, unit: Unit) -> TemporalResult<Self> {
+        if !UNITS.contains(&unit) {
+            return Err(TemporalError::range()
+                .with_message("A relative time unit must be a second or larger."));
+        }
+        Ok(Self { value, unit })
+    }
+
+    /// Returns the signed number of units.
+    pub const fn value(&self) -> i64 {
+        self.value
+    }
+
+    /// Returns the unit of the value.
+    pub const fn unit(&self) -> Unit {
+        self.unit
+    }
+
+    /// Returns whether this relative time is in the past.
+    pub const fn is_past(&self) -> bool {
+        self.value < 0
+    }
+}
+
+/// The rounded values below which each unit is used.
+///
+/// The defaults are 45 seconds, 45 minutes, 22 hours, 26 days and 11 months;
+/// larger values are expressed in years. Weeks are skipped unless a week
+/// threshold is set, in which case the day threshold should be at most 7.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelativeTimeThresholds {
+    /// The number of seconds from which minutes are used.
+    pub second: u32,
+    /// The number of minutes from which hours are used.
+    pub minute: u32,
+    /// The number of hours from which days are used.
+    pub hour: u32,
+    /// The number of days from which weeks, or months, are used.
+    pub day: u32,
+    /// The number of weeks from which months are used.
+    pub week: Option<u32>,
+    /// The number of months from which years are used.
+    pub month: u32,
+}
+
+impl Default for RelativeTimeThresholds {
+    fn default() -> Self {
+        Self {
+            second: 45,
+            minute: 45,
+            hour: 22,
+            day: 26,
+            week: None,
+            month: 11,
+        }
+    }
+}
+
+impl RelativeTimeThresholds {
+    fn get(&self, unit: Unit) -> Option<u32> {
+        match unit {
+            Unit::Second => Some(self.second),
+            Unit::Minute => Some(self.minute),
+            Unit::Hour => Some(self.hour),
+            Unit::Day => Some(self.day),
+            Unit::Week => self.week,
+            Unit::Month => Some(self.month),
+            _ => None,
+        }
+    }
+}
+
+/// Options for selecting the unit of a [`RelativeTime`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RelativeTimeOptions {
+    /// The thresholds at which the next larger unit is used.
+    pub thresholds: RelativeTimeThresholds,
+    /// The smallest unit to use. Defaults to seconds, or days for dates.
+    pub smallest_unit: Option<Unit>,
+    /// The largest unit to use. Defaults to years, or hours for times and
+    /// instants.
+    pub largest_unit: Option<Unit>,
+    /// How the value is rounded to the selected unit, [`RoundingMode::HalfExpand`]
+    /// by default.
+    pub rounding_mode: Option<RoundingMode>,
+}
+
+impl RelativeTimeOptions {
+    /// Selects the unit of a relative time, where `difference` returns the
+    /// difference rounded to a single unit.
+    fn select(
+        &self,
+        default_smallest: Unit,
+        default_largest: Unit,
+        mut difference: impl FnMut(Unit, RoundingMode) -> TemporalResult<Duration>,
+    ) -> TemporalResult<RelativeTime> {
+        let smallest = self.smallest_unit.unwrap_or(default_smallest);
+        let largest = self.largest_unit.unwrap_or(default_largest.max(smallest));
+        if !UNITS.contains(&smallest) || !UNITS.contains(&largest) {
+            return Err(TemporalError::range()
+                .with_message("Relative time units must be from seconds to years."));
+        }
+        if smallest > largest {
+            return Err(TemporalError::range()
+                .with_message("smallestUnit must not be larger than largestUnit."));
+        }
+        let rounding_mode = self.rounding_mode.unwrap_or(RoundingMode::HalfExpand);
+        for unit in UNITS {
+            if unit < smallest {
+                continue;
+            }
+            let threshold = self.thresholds.get(unit);
+            // Weeks are only used when they have a threshold, or are requested.
+            if threshold.is_none() && unit != smallest && unit != largest {
+                continue;
+            }
+            let duration = difference(unit, rounding_mode)?;
+            let value = match unit {
+                Unit::Second => duration.seconds(),
+                Unit::Minute => duration.minutes(),
+                Unit::Hour => duration.hours(),
+                Unit::Day => duration.days(),
+                Unit::Week => duration.weeks(),
+                Unit::Month => duration.months(),
+                _ => duration.years(),
+            };
+            if unit == largest || threshold.is_some_and(|t| value.unsigned_abs() < u64::from(t)) {
+                return Ok(RelativeTime { value, unit });
+            }
+        }
+        // The largest unit is always reached, as smallest <= largest.
+        Err(TemporalError::assert())
+    }
+
+    fn difference_settings(unit: Unit, rounding_mode: RoundingMode) -> DifferenceSettings {
+        DifferenceSettings {
+            largest_unit: Some(unit),
+            smallest_unit: Some(unit),
+            rounding_mode: Some(rounding_mode),
+            increment: None,
+        }
+    }
+
+    fn rounding_options(unit: Unit, rounding_mode: RoundingMode) -> RoundingOptions {
+        RoundingOptions {
+            largest_unit: Some(unit),
+            smallest_unit: Some(unit),
+            rounding_mode: Some(rounding_mode),
+            increment: None,
+        }
+    }
+}
+
+// ==== Relative time API ====
+
+impl Duration {
+    /// Returns this duration as a [`RelativeTime`] of a single unit.
+    ///
+    /// Durations with calendar units, and relative times in weeks, months or
+    /// years, require `relative_to`. Without it, the largest unit defaults to
+    /// days.
+    pub fn to_relative_time_with_provider(
+        &self,
+        relative_to: Option<RelativeTo>,
+        options: RelativeTimeOptions,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<RelativeTime> {
+        let default_largest = if relative_to.is_some() {
+            Unit::Year
+        } else {
+            Unit::Day
+        };
+        options.select(Unit::Second, default_largest, |unit, rounding_mode| {
+            self.round_with_provider(
+                RelativeTimeOptions::rounding_options(unit, rounding_mode),
+                relative_to.clone(),
+                provider,
+            )
+        })
+    }
+}
+
+impl PlainDate {
+    /// Returns the time from this date until `other` as a [`RelativeTime`]
+    /// in days or larger units.
+    pub fn relative_time_until(
+        &self,
+        other: &Self,
+        options: RelativeTimeOptions,
+    ) -> TemporalResult<RelativeTime> {
+        options.select(Unit::Day, Unit::Year, |unit, rounding_mode| {
+            self.until(
+                other,
+                RelativeTimeOptions::difference_settings(unit, rounding_mode),
+            )
+        })
+    }
+}
+
+impl PlainDateTime {
+    /// Returns the time from this date-time until `other` as a [`RelativeTime`].
+    pub fn relative_time_until(
+        &self,
+        other: &Self,
+        options: RelativeTimeOptions,
+    ) -> TemporalResult<RelativeTime> {
+        options.select(Unit::Second, Unit::Year, |unit, rounding_mode| {
+            self.until(
+                other,
+                RelativeTimeOptions::difference_settings(unit, rounding_mode),
+            )
+        })
+    }
+}
+
+impl PlainTime {
+    /// Returns the time from this time until `other` as a [`RelativeTime`]
+    /// in hours or smaller units.
+    pub fn relative_time_until(
+        &self,
+        other: &Self,
+        options: RelativeTimeOptions,
+    ) -> TemporalResult<RelativeTime> {
+        options.select(Unit::Second, Unit::Hour, |unit, rounding_mode| {
+            self.until(
+                other,
+                RelativeTimeOptions::difference_settings(unit, rounding_mode),
+            )
+        })
+    }
+}
+
+impl Instant {
+    /// Returns the time from this instant until `other` as a [`RelativeTime`].
+    ///
+    /// Instants have no calendar, so the largest unit defaults to hours and
+    /// may be at most days of 24 hours. Use [`ZonedDateTime`] for calendar
+    /// units.
+    pub fn relative_time_until(
+        &self,
+        other: &Self,
+        options: RelativeTimeOptions,
+    ) -> TemporalResult<RelativeTime> {
+        let duration = self.until(other, DifferenceSettings::default())?;
+        options.select(Unit::Second, Unit::Hour, |unit, rounding_mode| {
+            duration.round_with_provider(
+                RelativeTimeOptions::rounding_options(unit, rounding_mode),
+                None,
+                &NeverProvider,
+            )
+        })
+    }
+}
+
+impl ZonedDateTime {
+    /// Returns the time from this date-time until `other` as a [`RelativeTime`].
+    pub fn relative_time_until_with_provider(
+        &self,
+        other: &Self,
+        options: RelativeTimeOptions,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<RelativeTime> {
+        options.select(Unit::Second, Unit::Year, |unit, rounding_mode| {
+            self.until_with_provider(
+                other,
+                RelativeTimeOptions::difference_settings(unit, rounding_mode),
+                provider,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use super::{RelativeTime, RelativeTimeOptions, RelativeTimeThresholds};
+    use crate::{
+        options::{RelativeTo, RoundingMode, Unit},
+        provider::NeverProvider,
+        Duration, Instant, PlainDate, PlainDateTime, PlainTime,
+    };
+
+    #[test]
+    fn unit_selection() {
+        let now = PlainDateTime::from_str("2025-03-10T12:00:00").unwrap();
+        let relative = |other: &str, options| {
+            let other = PlainDateTime::from_str(other).unwrap();
+            let relative = now.relative_time_until(&other, options).unwrap();
+            (relative.value(), relative.unit())
+        };
+        let default = RelativeTimeOptions::default();
+        assert_eq!(relative("2025-03-10T12:00:30", default), (30, Unit::Second));
+        assert_eq!(relative("2025-03-10T12:00:50", default), (1, Unit::Minute));
+        assert_eq!(relative("2025-03-10T09:00:00", default), (-3, Unit::Hour));
+        assert_eq!(relative("2025-03-11T10:30:00", default), (1, Unit::Day));
+        assert_eq!(relative("2025-03-01T00:00:00", default), (-10, Unit::Day));
+        assert_eq!(relative("2025-04-30T00:00:00", default), (2, Unit::Month));
+        assert_eq!(relative("2023-12-25T00:00:00", default), (-1, Unit::Year));
+
+        let weeks = RelativeTimeOptions {
+            thresholds: RelativeTimeThresholds {
+                day: 7,
+                week: Some(4),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(relative("2025-03-01T00:00:00", weeks), (-1, Unit::Week));
+        let largest = RelativeTimeOptions {
+            largest_unit: Some(Unit::Hour),
+            rounding_mode: Some(RoundingMode::Trunc),
+            ..Default::default()
+        };
+        assert_eq!(relative("2025-03-12T11:59:00", largest), (47, Unit::Hour));
+
+        let today = PlainDate::from_str("2025-03-10").unwrap();
+        let tomorrow = PlainDate::from_str("2025-03-11").unwrap();
+        let relative = today.relative_time_until(&tomorrow, default).unwrap();
+        assert_eq!(relative, RelativeTime::try_new(1, Unit::Day).unwrap());
+        assert!(tomorrow
+            .relative_time_until(&today, default)
+            .unwrap()
+            .is_past());
+
+        let start = PlainTime::from_str("08:00").unwrap();
+        let end = PlainTime::from_str("07:10").unwrap();
+        let relative = start.relative_time_until(&end, default).unwrap();
+        assert_eq!((relative.value(), relative.unit()), (-1, Unit::Hour));
+
+        assert!(RelativeTime::try_new(1, Unit::Millisecond).is_err());
+        let invalid = RelativeTimeOptions {
+            smallest_unit: Some(Unit::Day),
+            largest_unit: Some(Unit::Hour),
+            ..Default::default()
+        };
+        assert!(now.relative_time_until(&now, invalid).is_err());
+    }
+
+    #[test]
+    fn durations_and_instants() {
+        let duration = Duration::from_str("PT36H").unwrap();
+        let relative = duration
+            .to_relative_time_with_provider(None, Default::default(), &NeverProvider)
+            .unwrap();
+        assert_eq!((relative.value(), relative.unit()), (2, Unit::Day));
+
+        let duration = Duration::from_str("-P40D").unwrap();
+        let relative_to = RelativeTo::PlainDate(PlainDate::from_str("2025-01-31").unwrap());
+        let relative = duration
+            .to_relative_time_with_provider(Some(relative_to), Default::default(), &NeverProvider)
+            .unwrap();
+        assert_eq!((relative.value(), relative.unit()), (-1, Unit::Month));
+
+        let start = Instant::from_str("2025-03-10T12:00:00Z").unwrap();
+        let end = Instant::from_str("2025-03-12T00:00:00Z").unwrap();
+        let relative = start.relative_time_until(&end, Default::default()).unwrap();
+        assert_eq!((relative.value(), relative.unit()), (36, Unit::Hour));
+    }
+}
@@ -7,12 +7,12 @@ use crate::{
         ArithmeticOverflow, DifferenceOperation, DifferenceSettings, ResolvedRoundingOptions,
         RoundingIncrement, RoundingMode, ToStringRoundingOptions, Unit, UnitGroup,
     },
-    parsers::{parse_time, IxdtfStringBuilder},
+    parsers::{parse_time, Encoded, FormattableStrftime, IxdtfStringBuilder},
     DateDuration, TemporalError, TemporalResult,
 };
 use alloc::string::String;
 use core::str::FromStr;
-use writeable::Writeable;
+use writeable::{impl_display_with_writeable, LengthHint, Writeable};
 
 use super::{duration::normalized::TimeDuration, PlainDateTime};
 
@@ -409,7 +409,14 @@ impl PlainTime {
 
     // Converts a UTF-8 encoded string into a `PlainTime`.
     pub fn from_utf8(s: &[u8]) -> TemporalResult<Self> {
-        let result = parse_time(s)?;
+        let result = parse_time(Encoded::Utf8(s))?;
+        let iso = IsoTime::from_time_record(result)?;
+        Ok(Self::new_unchecked(iso))
+    }
+
+    // Converts a UTF-16 encoded string into a `PlainTime`.
+    pub fn from_utf16(s: &[u16]) -> TemporalResult<Self> {
+        let result = parse_time(Encoded::Utf16(s))?;
         let iso = IsoTime::from_time_record(result)?;
         Ok(Self::new_unchecked(iso))
     }
@@ -569,6 +576,25 @@ impl PlainTime {
         let builder = IxdtfStringBuilder::default().with_time(result, resolved.precision);
         Ok(builder)
     }
+
+    /// Formats this time with a strftime-style pattern, e.g. `%I:%M %p`.
+    ///
+    /// See [`FormattableStrftime`] for the supported directives. Date and
+    /// offset directives are an error.
+    pub fn to_strftime_string(&self, pattern: &str) -> TemporalResult<String> {
+        self.to_strftime_writeable(pattern)
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Returns a [`Writeable`] that formats this time with `pattern`.
+    ///
+    /// Fails like [`PlainTime::to_strftime_string`] for an invalid pattern.
+    pub fn to_strftime_writeable<'a>(
+        &self,
+        pattern: &'a str,
+    ) -> TemporalResult<impl Writeable + 'a> {
+        FormattableStrftime::try_new(pattern, None, Some(self.iso), None, None)
+    }
 }
 
 impl From<PlainDateTime> for PlainTime {
@@ -577,6 +603,25 @@ impl From<PlainDateTime> for PlainTime {
     }
 }
 
+impl Writeable for PlainTime {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        let writeable = self.to_ixdtf_writeable(ToStringRoundingOptions::default());
+        debug_assert!(
+            writeable.is_ok(),
+            "PlainTime must return a valid string with default options."
+        );
+        writeable.map_err(|_| core::fmt::Error)?.write_to(sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        self.to_ixdtf_writeable(ToStringRoundingOptions::default())
+            .map(|x| x.writeable_length_hint())
+            .unwrap_or(LengthHint::undefined())
+    }
+}
+
+impl_display_with_writeable!(PlainTime);
+
 impl FromStr for PlainTime {
     type Err = TemporalError;
 
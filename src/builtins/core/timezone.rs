@@ -1,24 +1,35 @@
 //! This module implements the Temporal `TimeZone` and components.
 
-use alloc::string::{String, ToString};
+use alloc::string::String;
+use alloc::vec::Vec;
 
-use ixdtf::encoding::Utf8;
 use ixdtf::{
     parsers::TimeZoneParser,
-    records::{MinutePrecisionOffset, TimeZoneRecord, UtcOffsetRecord},
+    records::{MinutePrecisionOffset, UtcOffsetRecord},
+    ParseError,
 };
 use num_traits::ToPrimitive;
+use tinystr::TinyAsciiStr;
+use writeable::{impl_display_with_writeable, LengthHint, Writeable};
 
 use crate::error::ErrorMessage;
 use crate::parsers::{
-    parse_allowed_timezone_formats, parse_identifier, FormattableOffset, FormattableTime, Precision,
+    ixdtf_error, parse_allowed_timezone_formats, parse_identifier, Encoded, FormattableOffset,
+    FormattableTime, Precision, TimeZoneIdentifier,
 };
-use crate::provider::{CandidateEpochNanoseconds, TimeZoneProvider};
+use crate::provider::{
+    CandidateEpochNanoseconds, GapEntryOffsets, TimeZoneProvider, TransitionDirection,
+};
+use crate::unix_time::EpochNanoseconds;
 use crate::Sign;
 use crate::{
-    builtins::core::{duration::normalized::TimeDuration, Instant},
+    builtins::core::{
+        calendar::Calendar,
+        duration::{normalized::InternalDurationRecord, normalized::TimeDuration, DateDuration},
+        Duration, Instant, PlainDateTime,
+    },
     iso::{IsoDate, IsoDateTime, IsoTime},
-    options::Disambiguation,
+    options::{Disambiguation, Unit},
     TemporalError, TemporalResult, TemporalUnwrap, ZonedDateTime,
 };
 
@@ -66,12 +77,23 @@ impl UtcOffset {
     pub fn from_utf8(source: &[u8]) -> TemporalResult<Self> {
         let record = TimeZoneParser::from_utf8(source)
             .parse_offset()
-            .map_err(|e| TemporalError::range().with_message(e.to_string()))?;
+            .map_err(|e| ixdtf_error(e, Encoded::Utf8(source), reparse_offset))?;
+        Self::from_ixdtf_record(record)
+    }
+
+    pub fn from_utf16(source: &[u16]) -> TemporalResult<Self> {
+        let record = TimeZoneParser::from_utf16(source)
+            .parse_offset()
+            .map_err(|e| ixdtf_error(e, Encoded::Utf16(source), reparse_offset))?;
         Self::from_ixdtf_record(record)
     }
 
     #[allow(clippy::inherent_to_string)]
     pub fn to_string(&self) -> String {
+        self.to_formattable().write_to_string().into()
+    }
+
+    fn to_formattable(self) -> FormattableOffset {
         let sign = if self.0 < 0 {
             Sign::Negative
         } else {
@@ -93,7 +115,7 @@ impl UtcOffset {
         } else {
             Precision::Auto
         };
-        let formattable_offset = FormattableOffset {
+        FormattableOffset {
             sign,
             time: FormattableTime {
                 hour,
@@ -103,8 +125,7 @@ impl UtcOffset {
                 precision,
                 include_sep: true,
             },
-        };
-        formattable_offset.to_string()
+        }
     }
 
     pub fn from_minutes(minutes: i16) -> Self {
@@ -137,6 +158,24 @@ impl UtcOffset {
     }
 }
 
+// Reparses a prefix of a failed offset string to locate the error.
+fn reparse_offset(source: Encoded<'_>) -> Result<(), ParseError> {
+    match source {
+        Encoded::Utf8(s) => TimeZoneParser::from_utf8(s).parse_offset().map(|_| ()),
+        Encoded::Utf16(s) => TimeZoneParser::from_utf16(s).parse_offset().map(|_| ()),
+    }
+}
+
+impl Writeable for UtcOffset {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        self.to_formattable().write_to(sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        self.to_formattable().writeable_length_hint()
+    }
+}
+
 impl core::str::FromStr for UtcOffset {
     type Err = TemporalError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -144,6 +183,97 @@ impl core::str::FromStr for UtcOffset {
     }
 }
 
+/// The result of comparing the offsets of two time zones over a range of instants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeZoneEquivalence {
+    /// Both time zones have identical offsets over the entire range.
+    Equivalent,
+    /// The time zones first have different offsets at the contained `Instant`.
+    DivergesAt(Instant),
+}
+
+/// The change in wall-clock time caused by a time zone transition.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WallClockShift {
+    /// The wall-clock time jumps forward, skipping the contained duration.
+    Gap(Duration),
+    /// The wall-clock time jumps backward, repeating the contained duration.
+    Overlap(Duration),
+}
+
+/// `TimeZoneTransition` describes a change of offset in a time zone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeZoneTransition {
+    /// The exact time of the transition.
+    pub instant: Instant,
+    /// The offset in effect before the transition.
+    pub offset_before: UtcOffset,
+    /// The offset in effect from the transition onward.
+    pub offset_after: UtcOffset,
+    /// Whether the offset before the transition is daylight saving time.
+    pub is_dst_before: bool,
+    /// Whether the offset after the transition is daylight saving time.
+    pub is_dst_after: bool,
+    /// The time zone abbreviation before the transition, if available.
+    pub abbreviation_before: Option<TinyAsciiStr<8>>,
+    /// The time zone abbreviation after the transition, if available.
+    pub abbreviation_after: Option<TinyAsciiStr<8>>,
+    /// The change in wall-clock time caused by the transition.
+    pub shift: WallClockShift,
+}
+
+/// The possible exact times of a wall-clock time in a time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PossibleInstants {
+    /// The wall-clock time does not exist, because it falls into a gap.
+    Zero(GapEntryOffsets),
+    /// The wall-clock time exists exactly once.
+    One(Instant),
+    /// The wall-clock time is ambiguous, because it falls into an overlap.
+    Two([Instant; 2]),
+}
+
+impl PossibleInstants {
+    /// Returns the possible instants as a slice, which is empty for a gap.
+    pub fn as_slice(&self) -> &[Instant] {
+        match self {
+            Self::Zero(_) => &[],
+            Self::One(one) => core::slice::from_ref(one),
+            Self::Two(two) => &two[..],
+        }
+    }
+
+    /// Returns the earliest possible instant, if any.
+    pub fn first(&self) -> Option<Instant> {
+        self.as_slice().first().copied()
+    }
+
+    /// Returns the latest possible instant, if any.
+    pub fn last(&self) -> Option<Instant> {
+        self.as_slice().last().copied()
+    }
+
+    /// Returns the offsets around the gap, if the wall-clock time falls into a gap.
+    pub fn gap(&self) -> Option<&GapEntryOffsets> {
+        match self {
+            Self::Zero(gap) => Some(gap),
+            _ => None,
+        }
+    }
+}
+
+impl From<CandidateEpochNanoseconds> for PossibleInstants {
+    fn from(value: CandidateEpochNanoseconds) -> Self {
+        match value {
+            CandidateEpochNanoseconds::Zero(gap) => Self::Zero(gap),
+            CandidateEpochNanoseconds::One(one) => Self::One(Instant::from(one.ns)),
+            CandidateEpochNanoseconds::Two([one, two]) => {
+                Self::Two([Instant::from(one.ns), Instant::from(two.ns)])
+            }
+        }
+    }
+}
+
 // TODO: Potentially migrate to Cow<'a, str>
 // TODO: There may be an argument to have Offset minutes be a (Cow<'a, str>,, i16) to
 // prevent allocations / writing, TBD
@@ -153,26 +283,43 @@ pub enum TimeZone {
     UtcOffset(UtcOffset),
 }
 
+/// Writes the time zone's identifier.
+impl Writeable for TimeZone {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        match self {
+            TimeZone::IanaIdentifier(s) => sink.write_str(s),
+            TimeZone::UtcOffset(offset) => offset.write_to(sink),
+        }
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        match self {
+            TimeZone::IanaIdentifier(s) => LengthHint::exact(s.len()),
+            TimeZone::UtcOffset(offset) => offset.writeable_length_hint(),
+        }
+    }
+}
+
+impl_display_with_writeable!(TimeZone);
+
 impl TimeZone {
-    // Create a `TimeZone` from an ixdtf `TimeZoneRecord`.
+    // Create a `TimeZone` from a parsed time zone annotation.
     #[inline]
     pub(crate) fn from_time_zone_record(
-        record: TimeZoneRecord<Utf8>,
+        record: TimeZoneIdentifier<'_>,
         provider: &impl TimeZoneProvider,
     ) -> TemporalResult<Self> {
-        let timezone = match record {
-            TimeZoneRecord::Name(name) => {
-                TimeZone::IanaIdentifier(provider.normalize_identifier(name)?.into())
-            }
-            TimeZoneRecord::Offset(offset_record) => {
+        match record {
+            TimeZoneIdentifier::Name(name) => name.with_ascii(|name| {
+                Ok(TimeZone::IanaIdentifier(
+                    provider.normalize_identifier(name)?.into(),
+                ))
+            }),
+            TimeZoneIdentifier::Offset(offset_record) => {
                 let offset = UtcOffset::from_ixdtf_minute_record(offset_record);
-                TimeZone::UtcOffset(offset)
+                Ok(TimeZone::UtcOffset(offset))
             }
-            // TimeZoneRecord is non_exhaustive, but all current branches are matching.
-            _ => return Err(TemporalError::assert()),
-        };
-
-        Ok(timezone)
+        }
     }
 
     /// Parses a `TimeZone` from a provided `&str`.
@@ -180,15 +327,8 @@ impl TimeZone {
         identifier: &str,
         provider: &impl TimeZoneProvider,
     ) -> TemporalResult<Self> {
-        parse_identifier(identifier).map(|tz| match tz {
-            TimeZoneRecord::Name(name) => Ok(TimeZone::IanaIdentifier(
-                provider.normalize_identifier(name)?.into(),
-            )),
-            TimeZoneRecord::Offset(minute_precision_offset) => Ok(TimeZone::UtcOffset(
-                UtcOffset::from_ixdtf_minute_record(minute_precision_offset),
-            )),
-            _ => Err(TemporalError::range().with_message("Invalid TimeZone Identifier")),
-        })?
+        parse_identifier(Encoded::Utf8(identifier.as_bytes()))
+            .and_then(|tz| Self::from_time_zone_record(tz, provider))
     }
 
     #[cfg(feature = "compiled_data")]
@@ -202,11 +342,7 @@ impl TimeZone {
         src: &str,
         provider: &impl TimeZoneProvider,
     ) -> TemporalResult<Self> {
-        if let Ok(timezone) = Self::try_from_identifier_str_with_provider(src, provider) {
-            return Ok(timezone);
-        }
-        parse_allowed_timezone_formats(src, provider)
-            .ok_or_else(|| TemporalError::range().with_message("Not a valid time zone string"))
+        Self::try_from_encoded(Encoded::Utf8(src.as_bytes()), provider)
     }
 
     #[cfg(feature = "compiled_data")]
@@ -214,6 +350,34 @@ impl TimeZone {
         Self::try_from_str_with_provider(src, &*crate::builtins::TZ_PROVIDER)
     }
 
+    /// Parse a `TimeZone` from a UTF-16 string
+    ///
+    /// This is the UTF-16 equivalent of [`TimeZone::try_from_str_with_provider`].
+    pub fn try_from_utf16_with_provider(
+        src: &[u16],
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Self> {
+        Self::try_from_encoded(Encoded::Utf16(src), provider)
+    }
+
+    #[cfg(feature = "compiled_data")]
+    pub fn try_from_utf16(src: &[u16]) -> TemporalResult<Self> {
+        Self::try_from_utf16_with_provider(src, &*crate::builtins::TZ_PROVIDER)
+    }
+
+    fn try_from_encoded(
+        source: Encoded<'_>,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Self> {
+        if let Ok(timezone) =
+            parse_identifier(source).and_then(|tz| Self::from_time_zone_record(tz, provider))
+        {
+            return Ok(timezone);
+        }
+        parse_allowed_timezone_formats(source, provider)
+            .ok_or_else(|| TemporalError::range().with_message("Not a valid time zone string"))
+    }
+
     /// Returns the current `TimeZoneSlot`'s identifier.
     pub fn identifier(&self) -> String {
         match self {
@@ -241,6 +405,223 @@ impl TimeZone {
         self.primary_identifier_with_provider(&*crate::builtins::TZ_PROVIDER)
     }
 
+    /// Get the primary identifier this time zone links to, or `None` if
+    /// this time zone is not a link.
+    pub fn link_target_with_provider(
+        &self,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Option<Self>> {
+        match self {
+            TimeZone::IanaIdentifier(s) => Ok(provider
+                .get_identifier_link(s.as_bytes())?
+                .map(|link| TimeZone::IanaIdentifier(link.target.into()))),
+            TimeZone::UtcOffset(_) => Ok(None),
+        }
+    }
+
+    /// Get the primary identifier this time zone links to, or `None` if
+    /// this time zone is not a link.
+    #[cfg(feature = "compiled_data")]
+    pub fn link_target(&self) -> TemporalResult<Option<Self>> {
+        self.link_target_with_provider(&*crate::builtins::TZ_PROVIDER)
+    }
+
+    /// Returns whether this time zone's identifier is a deprecated link
+    /// kept only for backward compatibility, e.g. `US/Pacific`.
+    pub fn is_deprecated_with_provider(
+        &self,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<bool> {
+        match self {
+            TimeZone::IanaIdentifier(s) => Ok(provider
+                .get_identifier_link(s.as_bytes())?
+                .is_some_and(|link| link.deprecated)),
+            TimeZone::UtcOffset(_) => Ok(false),
+        }
+    }
+
+    /// Returns whether this time zone's identifier is a deprecated link
+    /// kept only for backward compatibility, e.g. `US/Pacific`.
+    #[cfg(feature = "compiled_data")]
+    pub fn is_deprecated(&self) -> TemporalResult<bool> {
+        self.is_deprecated_with_provider(&*crate::builtins::TZ_PROVIDER)
+    }
+
+    /// Returns the identifiers that are aliases of this time zone's primary identifier.
+    pub fn aliases_with_provider(
+        &self,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Vec<String>> {
+        match self {
+            TimeZone::IanaIdentifier(s) => Ok(provider
+                .get_identifier_aliases(s.as_bytes())?
+                .into_iter()
+                .map(Into::into)
+                .collect()),
+            TimeZone::UtcOffset(_) => Ok(Vec::default()),
+        }
+    }
+
+    /// Returns the identifiers that are aliases of this time zone's primary identifier.
+    #[cfg(feature = "compiled_data")]
+    pub fn aliases(&self) -> TemporalResult<Vec<String>> {
+        self.aliases_with_provider(&*crate::builtins::TZ_PROVIDER)
+    }
+
+    /// Compares the offsets of this time zone and `other` over the range `[start, end)`.
+    ///
+    /// Returns [`TimeZoneEquivalence::Equivalent`] if both time zones produce the same
+    /// offset for every instant in the range, otherwise returns the first instant where
+    /// the offsets differ.
+    pub fn equivalence_over_range_with_provider(
+        &self,
+        other: &Self,
+        start: &Instant,
+        end: &Instant,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<TimeZoneEquivalence> {
+        if end < start {
+            return Err(TemporalError::range().with_message("range end must not be before start"));
+        }
+        let end = end.as_i128();
+        let mut current = start.as_i128();
+        while current < end {
+            let offset = self.get_offset_nanos_for(current, provider)?;
+            let other_offset = other.get_offset_nanos_for(current, provider)?;
+            if offset != other_offset {
+                return Ok(TimeZoneEquivalence::DivergesAt(Instant::from(
+                    EpochNanoseconds::from(current),
+                )));
+            }
+            // Offsets can only change at a transition in either time zone.
+            current = match (
+                self.get_next_transition_for(current, provider)?,
+                other.get_next_transition_for(current, provider)?,
+            ) {
+                (Some(one), Some(two)) => one.min(two),
+                (Some(next), None) | (None, Some(next)) => next,
+                (None, None) => break,
+            };
+        }
+        Ok(TimeZoneEquivalence::Equivalent)
+    }
+
+    /// Compares the offsets of this time zone and `other` over the range `[start, end)`.
+    ///
+    /// Returns [`TimeZoneEquivalence::Equivalent`] if both time zones produce the same
+    /// offset for every instant in the range, otherwise returns the first instant where
+    /// the offsets differ.
+    #[cfg(feature = "compiled_data")]
+    pub fn equivalence_over_range(
+        &self,
+        other: &Self,
+        start: &Instant,
+        end: &Instant,
+    ) -> TemporalResult<TimeZoneEquivalence> {
+        self.equivalence_over_range_with_provider(other, start, end, &*crate::builtins::TZ_PROVIDER)
+    }
+
+    /// Returns the possible instants of a wall-clock time in this time zone.
+    ///
+    /// A wall-clock time in a gap has no possible instants, while one in an
+    /// overlap has two.
+    pub fn possible_instants_with_provider(
+        &self,
+        datetime: &PlainDateTime,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<PossibleInstants> {
+        self.get_possible_epoch_ns_for(datetime.iso, provider)
+            .map(Into::into)
+    }
+
+    /// Returns the possible instants of a wall-clock time in this time zone.
+    ///
+    /// A wall-clock time in a gap has no possible instants, while one in an
+    /// overlap has two.
+    #[cfg(feature = "compiled_data")]
+    pub fn possible_instants(&self, datetime: &PlainDateTime) -> TemporalResult<PossibleInstants> {
+        self.possible_instants_with_provider(datetime, &*crate::builtins::TZ_PROVIDER)
+    }
+
+    /// Get the next or previous transition of this time zone relative to `instant`.
+    ///
+    /// Returns `None` for offset time zones, or if there is no transition in `direction`.
+    pub fn get_transition_with_provider(
+        &self,
+        instant: &Instant,
+        direction: TransitionDirection,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Option<TimeZoneTransition>> {
+        let TimeZone::IanaIdentifier(identifier) = self else {
+            return Ok(None);
+        };
+        let Some(transition) =
+            provider.get_named_tz_transition(identifier, instant.as_i128(), direction)?
+        else {
+            return Ok(None);
+        };
+        // Providers are not required to handle out-of-bounds instants.
+        if transition.check_validity().is_err() {
+            return Ok(None);
+        }
+
+        let before = provider.get_named_tz_offset_nanoseconds(identifier, transition.0 - 1)?;
+        let after = provider.get_named_tz_offset_nanoseconds(identifier, transition.0)?;
+
+        let difference = TimeDuration::from_nanosecond_difference(
+            i128::from(after.offset.0) * i128::from(NS_IN_S),
+            i128::from(before.offset.0) * i128::from(NS_IN_S),
+        )?;
+        let duration = Duration::from_internal(
+            InternalDurationRecord::combine(DateDuration::default(), difference),
+            Unit::Hour,
+        )?;
+        let shift = if duration.sign() == Sign::Negative {
+            WallClockShift::Overlap(duration.abs())
+        } else {
+            WallClockShift::Gap(duration)
+        };
+
+        Ok(Some(TimeZoneTransition {
+            instant: Instant::from(transition),
+            offset_before: before.offset.into(),
+            offset_after: after.offset.into(),
+            is_dst_before: before.is_dst,
+            is_dst_after: after.is_dst,
+            abbreviation_before: before.abbreviation,
+            abbreviation_after: after.abbreviation,
+            shift,
+        }))
+    }
+
+    /// Get the next or previous transition of this time zone relative to `instant`.
+    ///
+    /// Returns `None` for offset time zones, or if there is no transition in `direction`.
+    #[cfg(feature = "compiled_data")]
+    pub fn get_transition(
+        &self,
+        instant: &Instant,
+        direction: TransitionDirection,
+    ) -> TemporalResult<Option<TimeZoneTransition>> {
+        self.get_transition_with_provider(instant, direction, &*crate::builtins::TZ_PROVIDER)
+    }
+
+    /// Creates a [`TimeZoneConverter`] that converts many instants or wall-clock
+    /// times in this time zone using `provider`.
+    pub fn converter_with_provider<'p, P: TimeZoneProvider>(
+        &self,
+        provider: &'p P,
+    ) -> TimeZoneConverter<'p, P> {
+        TimeZoneConverter::new(self.clone(), provider)
+    }
+
+    /// Creates a [`TimeZoneConverter`] that converts many instants or wall-clock
+    /// times in this time zone.
+    #[cfg(feature = "compiled_data")]
+    pub fn converter(&self) -> TimeZoneConverter<'static, crate::tzdb::CompiledTzdbProvider> {
+        self.converter_with_provider(&*crate::builtins::TZ_PROVIDER)
+    }
+
     // TimeZoneEquals, which compares primary identifiers
     pub(crate) fn time_zone_equals_with_provider(
         &self,
@@ -306,6 +687,20 @@ impl TimeZone {
         }
     }
 
+    /// Get the epoch nanoseconds of the next transition after `utc_epoch`, if any.
+    pub(crate) fn get_next_transition_for(
+        &self,
+        utc_epoch: i128,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Option<i128>> {
+        match self {
+            Self::UtcOffset(_) => Ok(None),
+            Self::IanaIdentifier(identifier) => Ok(provider
+                .get_named_tz_transition(identifier, utc_epoch, TransitionDirection::Next)?
+                .map(|ns| ns.0)),
+        }
+    }
+
     /// Get the offset for this current `TimeZoneSlot` as a `UtcOffset`
     pub(crate) fn get_utc_offset_for(
         &self,
@@ -558,29 +953,179 @@ impl TimeZone {
     }
 }
 
+/// The largest possible difference between two UTC offsets, which are
+/// strictly less than 24 hours in either direction.
+const MAX_OFFSET_DIFFERENCE: i128 = 2 * 86_400 * NS_IN_S as i128;
+
+/// An interval of exact time `[start, end)` over which a time zone has a single offset.
+#[derive(Debug, Clone, Copy)]
+struct OffsetInterval {
+    start: i128,
+    end: i128,
+    offset: UtcOffset,
+}
+
+impl OffsetInterval {
+    fn contains(&self, epoch_ns: i128) -> bool {
+        (self.start..self.end).contains(&epoch_ns)
+    }
+
+    /// Returns whether `epoch_ns` is far enough from the edges of the interval
+    /// that no neighbouring offset could map a wall-clock time onto it.
+    fn contains_unambiguously(&self, epoch_ns: i128) -> bool {
+        self.start.saturating_add(MAX_OFFSET_DIFFERENCE) <= epoch_ns
+            && epoch_ns < self.end.saturating_sub(MAX_OFFSET_DIFFERENCE)
+    }
+}
+
+/// A converter between exact times and wall-clock times bound to one time zone.
+///
+/// The converter remembers the interval between the transitions surrounding the
+/// last converted value, so converting a sorted sequence of instants or wall-clock
+/// times only consults the provider once per offset change.
+///
+/// Results are the same as converting each value through [`ZonedDateTime`].
+#[derive(Debug)]
+pub struct TimeZoneConverter<'p, P: TimeZoneProvider> {
+    time_zone: TimeZone,
+    calendar: Calendar,
+    provider: &'p P,
+    interval: Option<OffsetInterval>,
+}
+
+impl<'p, P: TimeZoneProvider> TimeZoneConverter<'p, P> {
+    /// Creates a new converter for `time_zone`, producing ISO 8601 `PlainDateTime`s.
+    pub fn new(time_zone: TimeZone, provider: &'p P) -> Self {
+        Self {
+            time_zone,
+            calendar: Calendar::default(),
+            provider,
+            interval: None,
+        }
+    }
+
+    /// Sets the calendar of the `PlainDateTime`s produced by this converter.
+    pub fn with_calendar(mut self, calendar: Calendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// Returns the time zone of this converter.
+    pub fn time_zone(&self) -> &TimeZone {
+        &self.time_zone
+    }
+
+    /// Returns the calendar of this converter.
+    pub fn calendar(&self) -> &Calendar {
+        &self.calendar
+    }
+
+    /// Returns the UTC offset of the time zone at `instant`.
+    pub fn offset_for(&mut self, instant: &Instant) -> TemporalResult<UtcOffset> {
+        let epoch_ns = instant.as_i128();
+        match self.interval {
+            Some(interval) if interval.contains(epoch_ns) => Ok(interval.offset),
+            _ => Ok(self.locate(epoch_ns)?.offset),
+        }
+    }
+
+    /// Converts `instant` to the wall-clock time in this time zone.
+    pub fn to_plain_date_time(&mut self, instant: &Instant) -> TemporalResult<PlainDateTime> {
+        let offset = self.offset_for(instant)?;
+        let iso = offset.get_iso_datetime_for(instant);
+        Ok(PlainDateTime::new_unchecked(iso, self.calendar.clone()))
+    }
+
+    /// Converts the wall-clock time `datetime` to an exact time in this time zone,
+    /// resolving gaps and overlaps with `disambiguation`.
+    pub fn to_instant(
+        &mut self,
+        datetime: &PlainDateTime,
+        disambiguation: Disambiguation,
+    ) -> TemporalResult<Instant> {
+        if let (TimeZone::IanaIdentifier(_), Some(interval)) = (&self.time_zone, self.interval) {
+            datetime.iso.date.is_valid_day_range()?;
+            let local_ns = datetime.iso.as_nanoseconds().0;
+            let candidate = local_ns - i128::from(interval.offset.nanoseconds());
+            if interval.contains_unambiguously(candidate) {
+                let candidate = EpochNanoseconds::from(candidate);
+                candidate.check_validity()?;
+                return Ok(Instant::from(candidate));
+            }
+        }
+        let resolved = self.time_zone.get_epoch_nanoseconds_for(
+            datetime.iso,
+            disambiguation,
+            self.provider,
+        )?;
+        self.locate(resolved.ns.0)?;
+        Ok(Instant::from(resolved.ns))
+    }
+
+    /// Looks up the offset interval containing `epoch_ns` and caches it.
+    fn locate(&mut self, epoch_ns: i128) -> TemporalResult<OffsetInterval> {
+        let interval = match &self.time_zone {
+            TimeZone::UtcOffset(offset) => OffsetInterval {
+                start: i128::MIN,
+                end: i128::MAX,
+                offset: *offset,
+            },
+            TimeZone::IanaIdentifier(identifier) => {
+                let offset = self.time_zone.get_utc_offset_for(epoch_ns, self.provider)?;
+                // The previous transition of the next nanosecond is the latest
+                // transition at or before `epoch_ns`.
+                let start = self
+                    .provider
+                    .get_named_tz_transition(
+                        identifier,
+                        epoch_ns.saturating_add(1),
+                        TransitionDirection::Previous,
+                    )?
+                    .map_or(i128::MIN, |ns| ns.0);
+                let end = self
+                    .provider
+                    .get_named_tz_transition(identifier, epoch_ns, TransitionDirection::Next)?
+                    .map_or(i128::MAX, |ns| ns.0);
+                OffsetInterval { start, end, offset }
+            }
+        };
+        self.interval = Some(interval);
+        Ok(interval)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(feature = "compiled_data")]
-    use super::TimeZone;
+    use super::{PossibleInstants, TimeZone, TimeZoneEquivalence};
+    #[cfg(feature = "compiled_data")]
+    use crate::{options::Disambiguation, provider::UtcOffsetSeconds, Instant, PlainDateTime};
 
     #[test]
     #[cfg(feature = "compiled_data")]
     fn from_and_to_string() {
+        use alloc::format;
+        use writeable::assert_writeable_eq;
+
         let src = "+09:30";
         let tz = TimeZone::try_from_identifier_str(src).unwrap();
         assert_eq!(tz.identifier(), src);
+        assert_writeable_eq!(tz, src);
 
         let src = "-09:30";
         let tz = TimeZone::try_from_identifier_str(src).unwrap();
         assert_eq!(tz.identifier(), src);
+        assert_writeable_eq!(tz, src);
 
         let src = "-12:30";
         let tz = TimeZone::try_from_identifier_str(src).unwrap();
         assert_eq!(tz.identifier(), src);
+        assert_writeable_eq!(tz, src);
 
         let src = "America/New_York";
         let tz = TimeZone::try_from_identifier_str(src).unwrap();
         assert_eq!(tz.identifier(), src);
+        assert_writeable_eq!(tz, src);
     }
 
     #[test]
@@ -592,4 +1137,181 @@ mod tests {
             .time_zone_equals_with_provider(&kolkata, &*crate::builtins::TZ_PROVIDER)
             .unwrap());
     }
+
+    #[test]
+    #[cfg(feature = "compiled_data")]
+    fn link_metadata() {
+        let pacific = TimeZone::try_from_identifier_str("US/Pacific").unwrap();
+        let los_angeles = TimeZone::try_from_identifier_str("America/Los_Angeles").unwrap();
+        assert_eq!(pacific.link_target().unwrap(), Some(los_angeles.clone()));
+        assert!(pacific.is_deprecated().unwrap());
+
+        assert_eq!(los_angeles.link_target().unwrap(), None);
+        assert!(!los_angeles.is_deprecated().unwrap());
+        let aliases = los_angeles.aliases().unwrap();
+        assert!(aliases.iter().any(|id| id == "US/Pacific"));
+        assert_eq!(aliases, pacific.aliases().unwrap());
+
+        let utc = TimeZone::try_from_identifier_str("Etc/UTC").unwrap();
+        assert_eq!(
+            utc.link_target().unwrap(),
+            Some(TimeZone::IanaIdentifier("UTC".into()))
+        );
+        assert!(!utc.is_deprecated().unwrap());
+
+        let offset = TimeZone::try_from_identifier_str("+05:00").unwrap();
+        assert_eq!(offset.link_target().unwrap(), None);
+        assert!(offset.aliases().unwrap().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "compiled_data")]
+    fn possible_instants() {
+        let tz = TimeZone::try_from_identifier_str("America/New_York").unwrap();
+
+        let gap = PlainDateTime::from_utf8(b"2017-03-12T02:30").unwrap();
+        let possible = tz.possible_instants(&gap).unwrap();
+        assert!(possible.as_slice().is_empty());
+        let offsets = possible.gap().unwrap();
+        assert_eq!(offsets.offset_before, UtcOffsetSeconds(-5 * 3600));
+        assert_eq!(offsets.offset_after, UtcOffsetSeconds(-4 * 3600));
+        assert_eq!(
+            Instant::from(offsets.transition_epoch),
+            Instant::from_utf8(b"2017-03-12T07:00Z").unwrap()
+        );
+
+        let overlap = PlainDateTime::from_utf8(b"2017-11-05T01:30").unwrap();
+        let possible = tz.possible_instants(&overlap).unwrap();
+        assert_eq!(
+            possible.as_slice(),
+            &[
+                Instant::from_utf8(b"2017-11-05T05:30Z").unwrap(),
+                Instant::from_utf8(b"2017-11-05T06:30Z").unwrap(),
+            ]
+        );
+        assert!(possible.gap().is_none());
+
+        let single = PlainDateTime::from_utf8(b"2017-07-01T12:00").unwrap();
+        let possible = tz.possible_instants(&single).unwrap();
+        assert_eq!(
+            possible,
+            PossibleInstants::One(Instant::from_utf8(b"2017-07-01T16:00Z").unwrap())
+        );
+        assert_eq!(possible.first(), possible.last());
+    }
+
+    #[test]
+    #[cfg(feature = "compiled_data")]
+    fn converter_matches_time_zone() {
+        let provider = &*crate::builtins::TZ_PROVIDER;
+        let step = 30 * 60 * 1_000_000_000i128;
+        for id in [
+            "America/New_York",
+            "Australia/Lord_Howe",
+            "Pacific/Apia",
+            "+05:30",
+        ] {
+            let tz = TimeZone::try_from_str(id).unwrap();
+            let mut converter = tz.converter();
+            let start = Instant::from_utf8(b"2011-01-01T00:00Z").unwrap().as_i128();
+            let end = Instant::from_utf8(b"2013-01-01T00:00Z").unwrap().as_i128();
+            // Walk forwards and then backwards to exercise both cache directions.
+            let forwards = (start..end).step_by(step as usize);
+            let backwards = (start..end).rev().step_by(step as usize);
+            for ns in forwards.chain(backwards) {
+                let instant = Instant::try_new(ns).unwrap();
+                let datetime = converter.to_plain_date_time(&instant).unwrap();
+                assert_eq!(
+                    datetime.iso,
+                    tz.get_iso_datetime_for(&instant, provider).unwrap()
+                );
+                for disambiguation in [Disambiguation::Earlier, Disambiguation::Later] {
+                    let expected = tz
+                        .get_epoch_nanoseconds_for(datetime.iso, disambiguation, provider)
+                        .unwrap();
+                    let result = converter.to_instant(&datetime, disambiguation).unwrap();
+                    assert_eq!(result.as_i128(), expected.ns.0, "{id} {datetime}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "compiled_data")]
+    fn converter_disambiguation() {
+        let tz = TimeZone::try_from_identifier_str("America/New_York").unwrap();
+        let mut converter = tz.converter();
+
+        let gap = PlainDateTime::from_utf8(b"2017-03-12T02:30").unwrap();
+        assert_eq!(
+            converter.to_instant(&gap, Disambiguation::Earlier).unwrap(),
+            Instant::from_utf8(b"2017-03-12T06:30Z").unwrap()
+        );
+        assert_eq!(
+            converter.to_instant(&gap, Disambiguation::Later).unwrap(),
+            Instant::from_utf8(b"2017-03-12T07:30Z").unwrap()
+        );
+        assert!(converter.to_instant(&gap, Disambiguation::Reject).is_err());
+
+        let overlap = PlainDateTime::from_utf8(b"2017-11-05T01:30").unwrap();
+        assert_eq!(
+            converter
+                .to_instant(&overlap, Disambiguation::Compatible)
+                .unwrap(),
+            Instant::from_utf8(b"2017-11-05T05:30Z").unwrap()
+        );
+        assert_eq!(
+            converter
+                .to_instant(&overlap, Disambiguation::Later)
+                .unwrap(),
+            Instant::from_utf8(b"2017-11-05T06:30Z").unwrap()
+        );
+        assert!(converter
+            .to_instant(&overlap, Disambiguation::Reject)
+            .is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "compiled_data")]
+    fn equivalence_over_range() {
+        let start = Instant::from_utf8(b"2000-01-01T00:00Z").unwrap();
+        let end = Instant::from_utf8(b"2030-01-01T00:00Z").unwrap();
+
+        let berlin = TimeZone::try_from_identifier_str("Europe/Berlin").unwrap();
+        let paris = TimeZone::try_from_identifier_str("Europe/Paris").unwrap();
+        assert_eq!(
+            berlin.equivalence_over_range(&paris, &start, &end).unwrap(),
+            TimeZoneEquivalence::Equivalent
+        );
+
+        let plus_one = TimeZone::try_from_identifier_str("+01:00").unwrap();
+        assert_eq!(
+            berlin
+                .equivalence_over_range(&plus_one, &start, &end)
+                .unwrap(),
+            TimeZoneEquivalence::DivergesAt(Instant::from_utf8(b"2000-03-26T01:00Z").unwrap())
+        );
+
+        let start = Instant::from_utf8(b"2024-01-01T00:00Z").unwrap();
+        let phoenix = TimeZone::try_from_identifier_str("America/Phoenix").unwrap();
+        let denver = TimeZone::try_from_identifier_str("America/Denver").unwrap();
+        assert_eq!(
+            phoenix
+                .equivalence_over_range(&denver, &start, &end)
+                .unwrap(),
+            TimeZoneEquivalence::DivergesAt(Instant::from_utf8(b"2024-03-10T09:00Z").unwrap())
+        );
+
+        // The divergence is outside of the range.
+        let end = Instant::from_utf8(b"2024-03-10T09:00Z").unwrap();
+        assert_eq!(
+            phoenix
+                .equivalence_over_range(&denver, &start, &end)
+                .unwrap(),
+            TimeZoneEquivalence::Equivalent
+        );
+        assert!(phoenix
+            .equivalence_over_range(&denver, &end, &start)
+            .is_err());
+    }
 }
@@ -0,0 +1,420 @@
+//! This module implements `PlainWeekDate`, an ISO 8601 week date.
+
+use alloc::{format, string::String};
+use core::str::FromStr;
+
+use writeable::{impl_display_with_writeable, LengthHint, Writeable};
+
+use crate::{
+    builtins::core::{DateDuration, Duration, PlainDate},
+    iso::{is_valid_date, IsoDate},
+    options::ArithmeticOverflow,
+    parsers::{parse_ascii_digits, parse_iso_year_prefix, write_padded_u8, write_year},
+    Calendar, TemporalError, TemporalResult,
+};
+
+/// The representation of an ISO 8601 week date.
+///
+/// A week date identifies a day by its week-numbering year, its ISO week
+/// and its day of the week from 1 (Monday) to 7 (Sunday), e.g. `2025-W03-2`.
+///
+/// ```rust
+/// use temporal_rs::{PlainDate, PlainWeekDate};
+/// use core::str::FromStr;
+///
+/// let week_date = PlainWeekDate::from_str("2025-W01-1").unwrap();
+/// assert_eq!(week_date.to_plain_date().unwrap(), PlainDate::try_new_iso(2024, 12, 30).unwrap());
+/// assert_eq!(week_date.to_basic_string(), "2025W011");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PlainWeekDate {
+    year_of_week: i32,
+    week_of_year: u8,
+    day_of_week: u8,
+}
+
+impl PlainWeekDate {
+    /// Creates a new `PlainWeekDate`, rejecting weeks that do not exist in the
+    /// week-numbering year.
+    pub fn try_new(year_of_week: i32, week_of_year: u8, day_of_week: u8) -> TemporalResult<Self> {
+        if !(1..=7).contains(&day_of_week) {
+            return Err(TemporalError::range().with_message("Day of week must be between 1 and 7."));
+        }
+        let weeks = Self::weeks_in_year(year_of_week)?;
+        if !(1..=weeks).contains(&week_of_year) {
+            return Err(TemporalError::range().with_message(format!(
+                "Week {week_of_year} does not exist in the week-numbering year {year_of_week}; expected a week between 1 and {weeks}."
+            )));
+        }
+        let result = Self {
+            year_of_week,
+            week_of_year,
+            day_of_week,
+        };
+        result.to_iso_date().check_validity()?;
+        Ok(result)
+    }
+
+    /// Returns the number of ISO weeks in a week-numbering year, either 52 or 53.
+    ///
+    /// Years outside of the ISO date limits are rejected.
+    pub fn weeks_in_year(year_of_week: i32) -> TemporalResult<u8> {
+        if !(-271821..=275760).contains(&year_of_week) {
+            return Err(TemporalError::range().with_message("Week-numbering year out of range."));
+        }
+        // A year has 53 weeks if it starts on a Thursday, or if it is a leap year
+        // that starts on a Wednesday.
+        let jan_first = iso_day_of_week(&IsoDate::new_unchecked(year_of_week, 1, 1));
+        let is_leap = is_valid_date(year_of_week, 2, 29);
+        if jan_first == 4 || (is_leap && jan_first == 3) {
+            Ok(53)
+        } else {
+            Ok(52)
+        }
+    }
+
+    /// Creates a `PlainWeekDate` from the ISO date of a `PlainDate`.
+    pub fn from_plain_date(date: &PlainDate) -> Self {
+        Self::from_iso_date(&date.iso)
+    }
+
+    /// Converts this week date into a `PlainDate` with the ISO 8601 calendar.
+    pub fn to_plain_date(&self) -> TemporalResult<PlainDate> {
+        let iso = self.to_iso_date();
+        PlainDate::try_new(iso.year, iso.month, iso.day, Calendar::ISO)
+    }
+
+    /// Returns the week-numbering year.
+    pub fn year_of_week(&self) -> i32 {
+        self.year_of_week
+    }
+
+    /// Returns the ISO week of the week-numbering year.
+    pub fn week_of_year(&self) -> u8 {
+        self.week_of_year
+    }
+
+    /// Returns the day of the week from 1 (Monday) to 7 (Sunday).
+    pub fn day_of_week(&self) -> u8 {
+        self.day_of_week
+    }
+
+    /// Adds a duration of years, weeks and days to this week date.
+    ///
+    /// Years are added to the week-numbering year, keeping the week and day of
+    /// the week. Week 53 is constrained to week 52 in years that only have 52
+    /// weeks, or rejected with [`ArithmeticOverflow::Reject`]. Durations with
+    /// months or time units are rejected.
+    pub fn add(
+        &self,
+        duration: &Duration,
+        overflow: Option<ArithmeticOverflow>,
+    ) -> TemporalResult<Self> {
+        let date_duration = duration.date();
+        if date_duration.months != 0 || *duration != Duration::from(date_duration) {
+            return Err(TemporalError::range()
+                .with_message("Week dates only support years, weeks and days arithmetic."));
+        }
+        let year =
+            i32::try_from(i64::from(self.year_of_week) + date_duration.years).map_err(|_| {
+                TemporalError::range().with_message("Week-numbering year out of range.")
+            })?;
+        let weeks = Self::weeks_in_year(year)?;
+        let week = match overflow.unwrap_or_default() {
+            ArithmeticOverflow::Constrain => self.week_of_year.min(weeks),
+            ArithmeticOverflow::Reject if self.week_of_year > weeks => {
+                return Err(TemporalError::range().with_message(format!(
+                    "Week {} does not exist in the week-numbering year {year}.",
+                    self.week_of_year
+                )))
+            }
+            ArithmeticOverflow::Reject => self.week_of_year,
+        };
+        let intermediate = Self {
+            year_of_week: year,
+            week_of_year: week,
+            day_of_week: self.day_of_week,
+        }
+        .to_iso_date();
+        let iso = IsoDate::try_balance(
+            intermediate.year,
+            intermediate.month.into(),
+            i64::from(intermediate.day) + 7 * date_duration.weeks + date_duration.days,
+        )?;
+        iso.check_validity()?;
+        Ok(Self::from_iso_date(&iso))
+    }
+
+    /// Subtracts a duration of years, weeks and days from this week date.
+    pub fn subtract(
+        &self,
+        duration: &Duration,
+        overflow: Option<ArithmeticOverflow>,
+    ) -> TemporalResult<Self> {
+        self.add(&duration.negated(), overflow)
+    }
+
+    /// Returns the duration in weeks and days from this week date until `other`.
+    pub fn until(&self, other: &Self) -> TemporalResult<Duration> {
+        let days = other.to_iso_date().to_epoch_days() - self.to_iso_date().to_epoch_days();
+        let days = i64::from(days);
+        Ok(Duration::from(DateDuration::new(0, 0, days / 7, days % 7)?))
+    }
+
+    /// Returns the duration in weeks and days from `other` until this week date.
+    pub fn since(&self, other: &Self) -> TemporalResult<Duration> {
+        other.until(self)
+    }
+
+    /// Parses an ISO 8601 week date in the extended (`2025-W03-2`) or basic
+    /// (`2025W032`) format.
+    pub fn from_utf8(s: &[u8]) -> TemporalResult<Self> {
+        let invalid = || TemporalError::range().with_message("Invalid ISO 8601 week date.");
+        let (year, rest) = parse_iso_year_prefix(s).ok_or_else(invalid)?;
+        let (extended, rest) = match rest {
+            [b'-', b'W', rest @ ..] => (true, rest),
+            [b'W', rest @ ..] => (false, rest),
+            _ => return Err(invalid()),
+        };
+        let (week, day) = match (extended, rest) {
+            (true, [w1, w2, b'-', d]) | (false, [w1, w2, d]) => (
+                parse_ascii_digits(&[*w1, *w2]).ok_or_else(invalid)?,
+                parse_ascii_digits(&[*d]).ok_or_else(invalid)?,
+            ),
+            _ => return Err(invalid()),
+        };
+        Self::try_new(year, week as u8, day as u8)
+    }
+
+    /// Returns the week date in the extended format, e.g. `2025-W03-2`.
+    pub fn to_week_date_string(&self) -> String {
+        self.write_to_string().into()
+    }
+
+    /// Returns the week date in the basic format, e.g. `2025W032`.
+    pub fn to_basic_string(&self) -> String {
+        self.to_basic_writeable().write_to_string().into()
+    }
+
+    /// Returns a [`Writeable`] for the week date in the basic format.
+    pub fn to_basic_writeable(&self) -> impl Writeable + '_ {
+        FormattableWeekDate {
+            week_date: self,
+            extended: false,
+        }
+    }
+}
+
+/// A week date in the extended or basic format.
+struct FormattableWeekDate<'a> {
+    week_date: &'a PlainWeekDate,
+    extended: bool,
+}
+
+impl Writeable for FormattableWeekDate<'_> {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        write_year(self.week_date.year_of_week, sink)?;
+        if self.extended {
+            sink.write_char('-')?;
+        }
+        sink.write_char('W')?;
+        write_padded_u8(self.week_date.week_of_year, sink)?;
+        if self.extended {
+            sink.write_char('-')?;
+        }
+        self.week_date.day_of_week.write_to(sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        let year_length = if (0..=9999).contains(&self.week_date.year_of_week) {
+            4
+        } else {
+            7
+        };
+        let separators = if self.extended { 2 } else { 0 };
+        LengthHint::exact(year_length + separators + 4)
+    }
+}
+
+impl PlainWeekDate {
+    fn from_iso_date(iso: &IsoDate) -> Self {
+        let week = iso.to_icu4x().week_of_year();
+        Self {
+            year_of_week: week.iso_year,
+            week_of_year: week.week_number,
+            day_of_week: iso_day_of_week(iso),
+        }
+    }
+
+    fn to_iso_date(self) -> IsoDate {
+        // Week 1 is the week that contains January 4th.
+        let jan_fourth = IsoDate::new_unchecked(self.year_of_week, 1, 4);
+        let week_one_monday = 4 - i32::from(iso_day_of_week(&jan_fourth)) + 1;
+        IsoDate::balance(
+            self.year_of_week,
+            1,
+            week_one_monday + 7 * (i32::from(self.week_of_year) - 1) + i32::from(self.day_of_week)
+                - 1,
+        )
+    }
+}
+
+impl From<&PlainDate> for PlainWeekDate {
+    fn from(date: &PlainDate) -> Self {
+        Self::from_plain_date(date)
+    }
+}
+
+impl Writeable for PlainWeekDate {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        FormattableWeekDate {
+            week_date: self,
+            extended: true,
+        }
+        .write_to(sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        FormattableWeekDate {
+            week_date: self,
+            extended: true,
+        }
+        .writeable_length_hint()
+    }
+}
+
+impl_display_with_writeable!(PlainWeekDate);
+
+impl FromStr for PlainWeekDate {
+    type Err = TemporalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_utf8(s.as_bytes())
+    }
+}
+
+/// Returns the ISO day of week from 1 (Monday) to 7 (Sunday).
+fn iso_day_of_week(iso: &IsoDate) -> u8 {
+    iso.to_icu4x().day_of_week() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use super::PlainWeekDate;
+    use crate::{options::ArithmeticOverflow, Duration, PlainDate};
+
+    #[test]
+    fn week_date_conversion() {
+        let cases = [
+            ((2025, 1, 14), (2025, 3, 2)),
+            ((2024, 12, 30), (2025, 1, 1)),
+            ((2021, 1, 3), (2020, 53, 7)),
+            ((2020, 12, 31), (2020, 53, 4)),
+            ((2027, 1, 1), (2026, 53, 5)),
+            ((2008, 12, 29), (2009, 1, 1)),
+        ];
+        for ((year, month, day), (year_of_week, week, day_of_week)) in cases {
+            let date = PlainDate::try_new_iso(year, month, day).unwrap();
+            let week_date = PlainWeekDate::from_plain_date(&date);
+            assert_eq!(
+                (
+                    week_date.year_of_week(),
+                    week_date.week_of_year(),
+                    week_date.day_of_week()
+                ),
+                (year_of_week, week, day_of_week)
+            );
+            assert_eq!(week_date.to_plain_date().unwrap(), date);
+        }
+    }
+
+    #[test]
+    fn week_53() {
+        assert_eq!(PlainWeekDate::weeks_in_year(2020).unwrap(), 53);
+        assert_eq!(PlainWeekDate::weeks_in_year(2026).unwrap(), 53);
+        assert_eq!(PlainWeekDate::weeks_in_year(2025).unwrap(), 52);
+        assert_eq!(PlainWeekDate::weeks_in_year(2004).unwrap(), 53);
+        assert!(PlainWeekDate::weeks_in_year(i32::MAX).is_err());
+        assert!(PlainWeekDate::try_new(i32::MAX, 1, 1).is_err());
+        assert!(PlainWeekDate::try_new(i32::MIN, 1, 1).is_err());
+        assert!(PlainWeekDate::try_new(2020, 53, 1).is_ok());
+        assert!(PlainWeekDate::try_new(2025, 53, 1).is_err());
+        assert!(PlainWeekDate::try_new(2025, 0, 1).is_err());
+        assert!(PlainWeekDate::try_new(2025, 1, 8).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn week_date_strings() {
+        use alloc::format;
+        use writeable::{assert_writeable_eq, LengthHint, Writeable};
+
+        let week_date = PlainWeekDate::from_str("2025-W03-2").unwrap();
+        assert_eq!(week_date, PlainWeekDate::try_new(2025, 3, 2).unwrap());
+        assert_eq!(PlainWeekDate::from_str("2025W032").unwrap(), week_date);
+        assert_writeable_eq!(week_date, "2025-W03-2");
+        assert_eq!(week_date.to_week_date_string(), "2025-W03-2");
+        assert_eq!(week_date.to_basic_string(), "2025W032");
+
+        let expanded = PlainWeekDate::from_str("-000001-W52-7").unwrap();
+        assert_eq!(expanded.year_of_week(), -1);
+        assert_writeable_eq!(expanded, "-000001-W52-7");
+        let basic = expanded.to_basic_writeable();
+        assert_eq!(basic.write_to_string(), "-000001W527");
+        assert_eq!(basic.writeable_length_hint(), LengthHint::exact(11));
+        assert_eq!(
+            PlainWeekDate::from_str("+010000W011").unwrap().to_string(),
+            "+010000-W01-1"
+        );
+
+        for invalid in [
+            "2025-W3-2",
+            "2025-W03",
+            "2025W03-2",
+            "2025-W032",
+            "2025-w03-2",
+            "2025-W53-1",
+            "-000000-W01-1",
+            "2025-W03-2Z",
+        ] {
+            assert!(PlainWeekDate::from_str(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn week_date_arithmetic() {
+        let week_date = PlainWeekDate::try_new(2020, 53, 4).unwrap();
+        let one_year = Duration::from_str("P1Y").unwrap();
+        assert_eq!(
+            week_date.add(&one_year, None).unwrap(),
+            PlainWeekDate::try_new(2021, 52, 4).unwrap()
+        );
+        assert!(week_date
+            .add(&one_year, Some(ArithmeticOverflow::Reject))
+            .is_err());
+
+        let added = week_date
+            .add(&Duration::from_str("P2W3D").unwrap(), None)
+            .unwrap();
+        assert_eq!(added, PlainWeekDate::try_new(2021, 2, 7).unwrap());
+        assert_eq!(
+            added
+                .subtract(&Duration::from_str("P2W3D").unwrap(), None)
+                .unwrap(),
+            week_date
+        );
+        assert!(week_date
+            .add(&Duration::from_str("P1M").unwrap(), None)
+            .is_err());
+        assert!(week_date
+            .add(&Duration::from_str("PT1H").unwrap(), None)
+            .is_err());
+
+        let until = week_date.until(&added).unwrap();
+        assert_eq!((until.weeks(), until.days()), (2, 3));
+        let since = week_date.since(&added).unwrap();
+        assert_eq!((since.weeks(), since.days()), (-2, -3));
+    }
+}
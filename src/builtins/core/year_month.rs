@@ -1,12 +1,12 @@
 //! This module implements `YearMonth` and any directly related algorithms.
 
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 use core::{cmp::Ordering, str::FromStr};
 
 use tinystr::TinyAsciiStr;
 
 use crate::{
-    builtins::calendar::{CalendarFields, YearMonthCalendarFields},
+    builtins::calendar::{CalendarFields, CalendarMonth, YearMonthCalendarFields},
     iso::{year_month_within_limits, IsoDate, IsoDateTime, IsoTime},
     options::{
         ArithmeticOverflow, DifferenceOperation, DifferenceSettings, Disambiguation,
@@ -24,7 +24,7 @@ use crate::{
 use super::{
     duration::normalized::InternalDurationRecord, DateDuration, Duration, PlainDate, PlainDateTime,
 };
-use writeable::Writeable;
+use writeable::{impl_display_with_writeable, LengthHint, Writeable};
 
 /// A partial PlainYearMonth record
 #[derive(Debug, Default, Clone, PartialEq)]
@@ -159,12 +159,20 @@ pub struct PlainYearMonth {
     calendar: Calendar,
 }
 
-impl core::fmt::Display for PlainYearMonth {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str(&self.to_ixdtf_string(DisplayCalendar::Auto))
+impl Writeable for PlainYearMonth {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        self.to_ixdtf_writeable(DisplayCalendar::Auto)
+            .write_to(sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        self.to_ixdtf_writeable(DisplayCalendar::Auto)
+            .writeable_length_hint()
     }
 }
 
+impl_display_with_writeable!(PlainYearMonth);
+
 impl PlainYearMonth {
     /// Creates an unvalidated `YearMonth`.
     #[inline]
@@ -432,6 +440,12 @@ impl PlainYearMonth {
         Self::from_parsed(parsed)
     }
 
+    // Converts a UTF-16 encoded string into a `PlainYearMonth`.
+    pub fn from_utf16(s: &[u16]) -> TemporalResult<Self> {
+        let parsed = ParsedDate::year_month_from_utf16(s)?;
+        Self::from_parsed(parsed)
+    }
+
     /// Converts a ParsedDate into a `PlainYearMonth`.
     ///
     /// Be sure to parse this using [`ParsedDate::year_month_from_utf8()`]~
@@ -446,7 +460,7 @@ impl PlainYearMonth {
             return Err(TemporalError::range().with_message("Exceeded valid range."));
         }
 
-        let intermediate = Self::new_unchecked(iso, Calendar::new(parsed.calendar));
+        let intermediate = Self::new_unchecked(iso, parsed.calendar);
         // 12. Set result to ISODateToFields(calendar, isoDate, year-month).
         let fields = YearMonthCalendarFields::try_from_year_month(&intermediate)?;
         // 13. NOTE: The following operation is called with constrain regardless of the
@@ -538,6 +552,29 @@ impl PlainYearMonth {
     pub fn in_leap_year(&self) -> bool {
         self.calendar().in_leap_year(&self.iso)
     }
+
+    /// Returns the months of the calendar year of this `PlainYearMonth` in order,
+    /// including any leap months.
+    pub fn month_codes_in_year(&self) -> Vec<CalendarMonth> {
+        self.calendar().month_codes_in_year(&self.iso)
+    }
+
+    /// Returns an iterator over the `PlainYearMonth`s of the calendar year of
+    /// this `PlainYearMonth`.
+    ///
+    /// Months outside the valid range of `PlainYearMonth` are skipped.
+    pub fn months_of_year(&self) -> impl Iterator<Item = PlainYearMonth> + '_ {
+        self.month_codes_in_year().into_iter().filter_map(|month| {
+            Self::new_with_overflow(
+                month.start.year,
+                month.start.month,
+                Some(month.start.day),
+                self.calendar.clone(),
+                ArithmeticOverflow::Reject,
+            )
+            .ok()
+        })
+    }
 }
 
 impl PlainYearMonth {
@@ -574,7 +611,7 @@ impl PlainYearMonth {
         // 11. Return ! CreateTemporalYearMonth(isoDate, calendar).
         let overflow = overflow.unwrap_or(ArithmeticOverflow::Constrain);
         self.calendar.year_month_from_fields(
-            fields.with_fallback_year_month(self, self.calendar.kind(), overflow)?,
+            fields.with_fallback_year_month(self, &self.calendar, overflow)?,
             overflow,
         )
     }
@@ -696,6 +733,35 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn months_of_year() {
+        let year_month = PlainYearMonth::from_str("2024-03-15[u-ca=hebrew]").unwrap();
+        let months = year_month.months_of_year().collect::<Vec<_>>();
+        assert_eq!(months.len(), 13);
+        assert_eq!(months[5].month_code().as_str(), "M05L");
+        assert!(months.contains(&year_month));
+        assert!(months.iter().all(|m| m.year() == year_month.year()));
+
+        let iso = PlainYearMonth::try_new_iso(2025, 6, None).unwrap();
+        let months = iso.months_of_year().collect::<Vec<_>>();
+        assert_eq!(months.len(), 12);
+        assert_eq!(
+            months[0],
+            PlainYearMonth::try_new_iso(2025, 1, None).unwrap()
+        );
+        assert_eq!(months[5], iso);
+
+        // Months beyond the limits are skipped.
+        let max = PlainYearMonth::try_new_iso(275760, 9, None).unwrap();
+        let months = max.months_of_year().collect::<Vec<_>>();
+        assert_eq!(months.len(), 9);
+        assert_eq!(months[8], max);
+        let min = PlainYearMonth::try_new_iso(-271821, 4, None).unwrap();
+        let months = min.months_of_year().collect::<Vec<_>>();
+        assert_eq!(months.len(), 9);
+        assert_eq!(months[0], min);
+    }
+
     #[test]
     fn plain_year_month_since_until_diff_tests() {
         // Equal year-months
@@ -5,14 +5,15 @@ use crate::provider::EpochNanosecondsAndOffset;
 use alloc::string::String;
 use core::{cmp::Ordering, num::NonZeroU128};
 use tinystr::TinyAsciiStr;
+use writeable::Writeable;
 
 use crate::{
     builtins::{
-        calendar::CalendarFields,
+        calendar::{CalendarFields, WeekRules},
         core::{
             calendar::Calendar,
             duration::normalized::{InternalDurationRecord, TimeDuration},
-            timezone::{TimeZone, UtcOffset},
+            timezone::{TimeZone, TimeZoneTransition, UtcOffset},
             Duration, Instant, PlainDate, PlainDateTime, PlainTime,
         },
     },
@@ -25,7 +26,10 @@ use crate::{
         ToStringRoundingOptions, Unit, UnitGroup,
     },
     parsed_intermediates::ParsedZonedDateTime,
-    parsers::{FormattableOffset, FormattableTime, IxdtfStringBuilder, Precision},
+    parsers::{
+        parse_rfc2822, parse_rfc3339, parse_strptime, FormattableOffset, FormattableRfc2822,
+        FormattableStrftime, FormattableTime, IxdtfStringBuilder, Precision, Rfc2822Zone,
+    },
     partial::PartialTime,
     primitive::FiniteF64,
     provider::{TimeZoneProvider, TransitionDirection},
@@ -78,6 +82,34 @@ impl PartialZonedDateTime {
         self.timezone = timezone;
         self
     }
+
+    /// Parses `source` with a strftime-style pattern into ISO 8601 fields,
+    /// e.g. `2024-05-01 14:30 +0200` with `%F %H:%M %z`.
+    ///
+    /// `%Z` sets the time zone and `%z` the offset. When only an offset is
+    /// present, it is also used as the time zone. See
+    /// [`FormattableStrftime`] for the supported directives.
+    pub fn from_strptime_with_provider(
+        source: &str,
+        pattern: &str,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Self> {
+        let record = parse_strptime(source, pattern)?;
+        let timezone = match (record.time_zone, record.offset) {
+            (Some(id), _) => Some(TimeZone::try_from_str_with_provider(id, provider)?),
+            (None, Some(offset)) => Some(TimeZone::UtcOffset(offset)),
+            (None, None) => None,
+        };
+        Ok(Self {
+            fields: ZonedDateTimeFields {
+                calendar_fields: record.calendar_fields,
+                time: record.time,
+                offset: record.offset,
+            },
+            timezone,
+            calendar: Calendar::ISO,
+        })
+    }
 }
 
 #[derive(Debug, Default, Clone, PartialEq)]
@@ -722,7 +754,7 @@ impl ZonedDateTime {
         let result_date = self.calendar.date_from_fields(
             fields.calendar_fields.with_fallback_datetime(
                 &plain_date_time,
-                self.calendar.kind(),
+                &self.calendar,
                 overflow,
             )?,
             overflow,
@@ -850,6 +882,17 @@ impl ZonedDateTime {
         ))
     }
 
+    /// Returns the next or previous time zone transition as a [`TimeZoneTransition`] record,
+    /// which includes the offsets before and after the transition.
+    pub fn get_time_zone_transition_info_with_provider(
+        &self,
+        direction: TransitionDirection,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Option<TimeZoneTransition>> {
+        self.tz
+            .get_transition_with_provider(&self.instant, direction, provider)
+    }
+
     pub fn hours_in_day_with_provider(
         &self,
         provider: &impl TimeZoneProvider,
@@ -944,8 +987,13 @@ impl ZonedDateTime {
 
     /// Returns an offset string for the current `ZonedDateTime`.
     pub fn offset(&self) -> String {
+        self.offset_writeable().write_to_string().into()
+    }
+
+    /// Returns the offset string for the current `ZonedDateTime` as a [`Writeable`].
+    pub fn offset_writeable(&self) -> impl Writeable {
         let offset = self.cached_offset.nanoseconds();
-        nanoseconds_to_formattable_offset(offset as i128).to_string()
+        nanoseconds_to_formattable_offset(offset as i128)
     }
 
     /// Returns the offset nanoseconds for the current `ZonedDateTime`.
@@ -1030,6 +1078,18 @@ impl ZonedDateTime {
         Ok(self.calendar.year_of_week(&pdt.iso.date))
     }
 
+    /// Returns the calendar week of year value according to `rules`.
+    pub fn week_of_year_with_rules(&self, rules: WeekRules) -> TemporalResult<Option<u8>> {
+        let iso = self.get_iso_datetime();
+        Ok(self.calendar.week_of_year_with_rules(&iso.date, rules))
+    }
+
+    /// Returns the calendar year of week value according to `rules`.
+    pub fn year_of_week_with_rules(&self, rules: WeekRules) -> TemporalResult<Option<i32>> {
+        let iso = self.get_iso_datetime();
+        Ok(self.calendar.year_of_week_with_rules(&iso.date, rules))
+    }
+
     /// Returns the calendar days in week value.
     pub fn days_in_week(&self) -> TemporalResult<u16> {
         let iso = self.get_iso_datetime();
@@ -1334,6 +1394,26 @@ impl ZonedDateTime {
         options: ToStringRoundingOptions,
         provider: &impl TimeZoneProvider,
     ) -> TemporalResult<String> {
+        self.to_ixdtf_writeable_with_provider(
+            display_offset,
+            display_timezone,
+            display_calendar,
+            options,
+            provider,
+        )
+        .map(|x| x.write_to_string().into())
+    }
+
+    /// Creates an IXDTF (RFC 9557) date/time [`Writeable`] for the provided `ZonedDateTime`
+    /// according to the provided display options.
+    pub fn to_ixdtf_writeable_with_provider(
+        &self,
+        display_offset: DisplayOffset,
+        display_timezone: DisplayTimeZone,
+        display_calendar: DisplayCalendar,
+        options: ToStringRoundingOptions,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<impl Writeable + '_> {
         let resolved_options = options.resolve()?;
         let result =
             self.instant
@@ -1345,17 +1425,15 @@ impl ZonedDateTime {
         let offset = self.tz.get_offset_nanos_for(result, provider)?;
         let datetime = self.tz.get_iso_datetime_for(&rounded_instant, provider)?;
         let (sign, hour, minute) = nanoseconds_to_formattable_offset_minutes(offset)?;
-        let timezone_id = self.timezone().identifier();
 
-        let ixdtf_string = IxdtfStringBuilder::default()
+        let builder = IxdtfStringBuilder::default()
             .with_date(datetime.date)
             .with_time(datetime.time, resolved_options.precision)
             .with_minute_offset(sign, hour, minute, display_offset)
-            .with_timezone(&timezone_id, display_timezone)
-            .with_calendar(self.calendar.identifier(), display_calendar)
-            .build();
+            .with_timezone(&self.tz, display_timezone)
+            .with_calendar(self.calendar.identifier(), display_calendar);
 
-        Ok(ixdtf_string)
+        Ok(builder)
     }
 
     // TODO: Should IANA Identifier be prechecked or allow potentially invalid IANA Identifer values here?
@@ -1370,6 +1448,17 @@ impl ZonedDateTime {
         Self::from_parsed_with_provider(parsed, disambiguation, offset_option, provider)
     }
 
+    pub fn from_utf16_with_provider(
+        source: &[u16],
+        disambiguation: Disambiguation,
+        offset_option: OffsetDisambiguation,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Self> {
+        let parsed = ParsedZonedDateTime::from_utf16_with_provider(source, provider)?;
+
+        Self::from_parsed_with_provider(parsed, disambiguation, offset_option, provider)
+    }
+
     pub fn from_parsed_with_provider(
         parsed: ParsedZonedDateTime,
         disambiguation: Disambiguation,
@@ -1396,7 +1485,7 @@ impl ZonedDateTime {
         )?;
         Ok(Self::new_unchecked(
             Instant::from(epoch_nanos.ns),
-            Calendar::new(parsed.date.calendar),
+            parsed.date.calendar,
             parsed.timezone,
             epoch_nanos.offset,
         ))
@@ -1523,6 +1612,137 @@ pub(crate) fn interpret_isodatetime_offset(
     }
 }
 
+// ==== RFC 2822 and HTTP-date API ====
+
+impl ZonedDateTime {
+    /// Parses an RFC 5322 (RFC 2822) date, e.g. `Fri, 21 Nov 1997 09:55:06 -0600`.
+    ///
+    /// The result has an ISO 8601 calendar and an offset time zone with the
+    /// offset of the date. `-0000` and obsolete military zones are read as UTC.
+    pub fn from_rfc2822(source: &[u8]) -> TemporalResult<Self> {
+        let record = parse_rfc2822(source)?;
+        let offset = UtcOffset::from_minutes(record.offset_minutes);
+        let instant = Instant::from_rfc2822_record(record)?;
+        Ok(Self::new_unchecked(
+            instant,
+            Calendar::default(),
+            TimeZone::UtcOffset(offset),
+            offset,
+        ))
+    }
+
+    /// Returns this date-time as an RFC 5322 date with its local time and
+    /// offset, e.g. `Fri, 21 Nov 1997 09:55:06 -0600`.
+    ///
+    /// Subsecond fields are truncated.
+    pub fn to_rfc2822_string(&self) -> TemporalResult<String> {
+        self.to_rfc2822_writeable()
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Returns a [`Writeable`] for the RFC 5322 date of this date-time with
+    /// its local time and offset.
+    ///
+    /// See [`ZonedDateTime::to_rfc2822_string`].
+    pub fn to_rfc2822_writeable(&self) -> TemporalResult<impl Writeable> {
+        if self.cached_offset.is_sub_minute() {
+            return Err(TemporalError::range()
+                .with_message("RFC 2822 dates cannot represent sub-minute offsets."));
+        }
+        let iso = self.get_iso_datetime();
+        FormattableRfc2822::try_new(
+            iso.date,
+            iso.time,
+            Rfc2822Zone::Offset(self.cached_offset.minutes()),
+        )
+    }
+
+    /// Returns the exact time of this date-time as an RFC 9110 IMF-fixdate,
+    /// e.g. `Sun, 06 Nov 1994 08:49:37 GMT`.
+    pub fn to_http_date_string(&self) -> TemporalResult<String> {
+        self.instant.to_http_date_string()
+    }
+
+    /// Returns a [`Writeable`] for the RFC 9110 IMF-fixdate of the exact time
+    /// of this date-time.
+    ///
+    /// See [`ZonedDateTime::to_http_date_string`].
+    pub fn to_http_date_writeable(&self) -> TemporalResult<impl Writeable> {
+        self.instant.to_http_date_writeable()
+    }
+}
+
+// ==== RFC 3339 API ====
+
+impl ZonedDateTime {
+    /// Parses a strict RFC 3339 date-time, e.g. `1996-12-19T16:39:57-08:00`.
+    ///
+    /// The result has an ISO 8601 calendar. A `Z` or `-00:00` gives the `UTC`
+    /// time zone, and any other offset an offset time zone.
+    pub fn from_rfc3339(source: &[u8]) -> TemporalResult<Self> {
+        let record = parse_rfc3339(source)?;
+        let instant = Instant::from_rfc3339_record(record)?;
+        let (tz, offset) = match record.offset_minutes {
+            Some(minutes) => {
+                let offset = UtcOffset::from_minutes(minutes);
+                (TimeZone::UtcOffset(offset), offset)
+            }
+            None => (TimeZone::default(), UtcOffset::from_minutes(0)),
+        };
+        Ok(Self::new_unchecked(
+            instant,
+            Calendar::default(),
+            tz,
+            offset,
+        ))
+    }
+
+    /// Returns this date-time as an RFC 3339 date-time with its local time
+    /// and offset, dropping the time zone and calendar annotations.
+    ///
+    /// `options` must not round to minutes, and the offset must be a whole
+    /// number of minutes.
+    pub fn to_rfc3339_string_with_provider(
+        &self,
+        options: ToStringRoundingOptions,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<String> {
+        self.instant
+            .to_rfc3339_string_with_provider(Some(&self.tz), options, provider)
+    }
+}
+
+// ==== strftime API ====
+
+impl ZonedDateTime {
+    /// Formats this date-time with a strftime-style pattern, e.g.
+    /// `%d/%m/%Y %H:%M %z`.
+    ///
+    /// `%Z` writes the time zone identifier. See [`FormattableStrftime`] for
+    /// the supported directives.
+    pub fn to_strftime_string(&self, pattern: &str) -> TemporalResult<String> {
+        self.to_strftime_writeable(pattern)
+            .map(|x| x.write_to_string().into())
+    }
+
+    /// Returns a [`Writeable`] that formats this date-time with `pattern`.
+    ///
+    /// Fails like [`ZonedDateTime::to_strftime_string`] for an invalid pattern.
+    pub fn to_strftime_writeable<'a>(
+        &self,
+        pattern: &'a str,
+    ) -> TemporalResult<impl Writeable + 'a> {
+        let iso = self.get_iso_datetime();
+        FormattableStrftime::try_new(
+            pattern,
+            Some(iso.date),
+            Some(iso.time),
+            Some(self.cached_offset),
+            Some(self.tz.identifier()),
+        )
+    }
+}
+
 // Formatting utils
 const NS_PER_MINUTE: i128 = 60_000_000_000;
 // Once MSRV is 1.83 we can update this to just calling .unwrap()
@@ -1557,7 +1777,7 @@ mod tests {
         builtins::{calendar::CalendarFields, zoneddatetime::ZonedDateTimeFields},
         options::{
             ArithmeticOverflow, DifferenceSettings, Disambiguation, OffsetDisambiguation,
-            RoundingIncrement, RoundingMode, RoundingOptions, Unit,
+            RoundingIncrement, RoundingMode, RoundingOptions, ToStringRoundingOptions, Unit,
         },
         partial::{PartialTime, PartialZonedDateTime},
         tzdb::FsTzdbProvider,
@@ -1955,4 +2175,117 @@ mod tests {
         assert!(result_3.is_err());
         assert!(result_4.is_err());
     }
+
+    #[test]
+    fn rfc2822_dates() {
+        let zdt = ZonedDateTime::from_rfc2822(b"Fri, 21 Nov 1997 09:55:06 -0600").unwrap();
+        assert_eq!(zdt.timezone().identifier(), "-06:00");
+        assert_eq!((zdt.hour().unwrap(), zdt.minute().unwrap()), (9, 55));
+        assert_eq!(
+            zdt.to_rfc2822_string().unwrap(),
+            "Fri, 21 Nov 1997 09:55:06 -0600"
+        );
+        assert_eq!(
+            zdt.to_http_date_string().unwrap(),
+            "Fri, 21 Nov 1997 15:55:06 GMT"
+        );
+        assert!(ZonedDateTime::from_rfc2822(b"Fri, 21 Nov 1997 09:55:06 +9959").is_err());
+
+        let provider = &FsTzdbProvider::default();
+        let kolkata = ZonedDateTime::from_utf8_with_provider(
+            b"2024-05-01T12:00[Asia/Kolkata]",
+            Disambiguation::Compatible,
+            OffsetDisambiguation::Reject,
+            provider,
+        )
+        .unwrap();
+        assert_eq!(
+            kolkata.to_rfc2822_string().unwrap(),
+            "Wed, 01 May 2024 12:00:00 +0530"
+        );
+        let monrovia = ZonedDateTime::from_utf8_with_provider(
+            b"1970-01-01T00:00[Africa/Monrovia]",
+            Disambiguation::Compatible,
+            OffsetDisambiguation::Reject,
+            provider,
+        )
+        .unwrap();
+        assert!(monrovia.to_rfc2822_string().is_err());
+    }
+
+    #[test]
+    fn rfc3339_dates() {
+        let provider = &FsTzdbProvider::default();
+        let zdt = ZonedDateTime::from_rfc3339(b"1996-12-19T16:39:57-08:00").unwrap();
+        assert_eq!(zdt.timezone().identifier(), "-08:00");
+        assert_eq!(
+            zdt.to_rfc3339_string_with_provider(ToStringRoundingOptions::default(), provider)
+                .unwrap(),
+            "1996-12-19T16:39:57-08:00"
+        );
+        let utc = ZonedDateTime::from_rfc3339(b"1996-12-20T00:39:57z").unwrap();
+        assert_eq!(utc.timezone().identifier(), "UTC");
+        assert_eq!(utc.epoch_nanoseconds(), zdt.epoch_nanoseconds());
+
+        let kolkata = ZonedDateTime::from_utf8_with_provider(
+            b"2024-05-01T12:00:00.5[Asia/Kolkata][u-ca=gregory]",
+            Disambiguation::Compatible,
+            OffsetDisambiguation::Reject,
+            provider,
+        )
+        .unwrap();
+        assert_eq!(
+            kolkata
+                .to_rfc3339_string_with_provider(ToStringRoundingOptions::default(), provider)
+                .unwrap(),
+            "2024-05-01T12:00:00.5+05:30"
+        );
+        let monrovia = ZonedDateTime::from_utf8_with_provider(
+            b"1970-01-01T00:00[Africa/Monrovia]",
+            Disambiguation::Compatible,
+            OffsetDisambiguation::Reject,
+            provider,
+        )
+        .unwrap();
+        assert!(monrovia
+            .to_rfc3339_string_with_provider(ToStringRoundingOptions::default(), provider)
+            .is_err());
+    }
+
+    #[test]
+    fn strftime_round_trip() {
+        let provider = &FsTzdbProvider::default();
+        let zdt = ZonedDateTime::from_utf8_with_provider(
+            b"2024-05-01T14:30:00+05:30[Asia/Kolkata]",
+            Disambiguation::Compatible,
+            OffsetDisambiguation::Reject,
+            provider,
+        )
+        .unwrap();
+        let pattern = "%d/%m/%Y %H:%M %z %:z %Z";
+        let formatted = zdt.to_strftime_string(pattern).unwrap();
+        assert_eq!(formatted, "01/05/2024 14:30 +0530 +05:30 Asia/Kolkata");
+
+        let partial =
+            PartialZonedDateTime::from_strptime_with_provider(&formatted, pattern, provider)
+                .unwrap();
+        assert_eq!(
+            partial.timezone.as_ref().map(TimeZone::identifier),
+            Some("Asia/Kolkata".into())
+        );
+        let parsed =
+            ZonedDateTime::from_partial_with_provider(partial, None, None, None, provider).unwrap();
+        assert!(parsed.equals_with_provider(&zdt, provider).unwrap());
+
+        let partial = PartialZonedDateTime::from_strptime_with_provider(
+            "2024-05-01 14:30 -0700",
+            "%F %R %z",
+            provider,
+        )
+        .unwrap();
+        assert_eq!(
+            partial.timezone,
+            Some(TimeZone::UtcOffset(UtcOffset::from_minutes(-420)))
+        );
+    }
 }
@@ -1,7 +1,9 @@
 //! This module implements `TemporalError`.
 
 use alloc::borrow::Cow;
+use alloc::boxed::Box;
 use alloc::format;
+use alloc::string::String;
 use core::fmt;
 
 use icu_calendar::DateError;
@@ -35,11 +37,114 @@ impl fmt::Display for ErrorKind {
     }
 }
 
+/// The location and cause of a failed parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDiagnostic {
+    offset: usize,
+    production: &'static str,
+    found: Option<char>,
+    expected: &'static str,
+}
+
+impl ParseDiagnostic {
+    #[inline]
+    #[must_use]
+    pub(crate) const fn new(
+        offset: usize,
+        production: &'static str,
+        found: Option<char>,
+        expected: &'static str,
+    ) -> Self {
+        Self {
+            offset,
+            production,
+            found,
+            expected,
+        }
+    }
+
+    /// Returns the offset of the offending input, in bytes for UTF-8 sources
+    /// and in code units for UTF-16 sources.
+    #[inline]
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the grammar production that failed to parse, e.g. `DateMonth`.
+    #[inline]
+    #[must_use]
+    pub const fn production(&self) -> &'static str {
+        self.production
+    }
+
+    /// Returns the offending character, or `None` if the input ended early.
+    #[inline]
+    #[must_use]
+    pub const fn found(&self) -> Option<char> {
+        self.found
+    }
+
+    /// Returns a description of the input that was expected.
+    #[inline]
+    #[must_use]
+    pub const fn expected(&self) -> &'static str {
+        self.expected
+    }
+
+    /// Renders a UTF-8 `source` with a caret pointing at the offending input.
+    ///
+    /// ```text
+    /// 2024-13-01
+    ///       ^ DateMonth: expected a month from 01 to 12, found '3' at offset 6
+    /// ```
+    #[must_use]
+    pub fn render(&self, source: &str) -> String {
+        let column = source
+            .char_indices()
+            .take_while(|(index, _)| *index < self.offset)
+            .count();
+        format!("{source}\n{:column$}^ {self}", "")
+    }
+
+    /// Renders a UTF-16 `source` with a caret pointing at the offending input.
+    ///
+    /// Unpaired surrogates are rendered as U+FFFD REPLACEMENT CHARACTER.
+    #[must_use]
+    pub fn render_utf16(&self, source: &[u16]) -> String {
+        let mut rendered = String::with_capacity(source.len());
+        let mut column = 0;
+        let mut index = 0;
+        for c in char::decode_utf16(source.iter().copied()) {
+            let c = c.unwrap_or(char::REPLACEMENT_CHARACTER);
+            if index < self.offset {
+                column += 1;
+            }
+            // An unpaired surrogate and its replacement both span one code unit.
+            index += c.len_utf16();
+            rendered.push(c);
+        }
+        format!("{rendered}\n{:column$}^ {self}", "")
+    }
+}
+
+impl fmt::Display for ParseDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: expected {}, found ", self.production, self.expected)?;
+        match self.found {
+            Some(found) => write!(f, "{found:?}")?,
+            None => f.write_str("end of input")?,
+        }
+        write!(f, " at offset {}", self.offset)
+    }
+}
+
 /// The error type for `boa_temporal`.
 #[derive(Debug, Clone, PartialEq)]
 pub struct TemporalError {
     kind: ErrorKind,
     msg: Cow<'static, str>,
+    diagnostic: Option<Box<ParseDiagnostic>>,
 }
 
 impl TemporalError {
@@ -49,6 +154,7 @@ impl TemporalError {
         Self {
             kind,
             msg: Cow::Borrowed(""),
+            diagnostic: None,
         }
     }
 
@@ -96,6 +202,7 @@ impl TemporalError {
         Self {
             kind: ErrorKind::Assert,
             msg: Cow::Borrowed(core::panic::Location::caller().file()),
+            diagnostic: None,
         }
     }
 
@@ -125,6 +232,14 @@ impl TemporalError {
         self
     }
 
+    /// Add a parse diagnostic to the error.
+    #[inline]
+    #[must_use]
+    pub(crate) fn with_diagnostic(mut self, diagnostic: ParseDiagnostic) -> Self {
+        self.diagnostic = Some(Box::new(diagnostic));
+        self
+    }
+
     /// Returns this error's kind.
     #[inline]
     #[must_use]
@@ -139,6 +254,13 @@ impl TemporalError {
         &self.msg
     }
 
+    /// Returns where and why parsing failed, if this is a parse error.
+    #[inline]
+    #[must_use]
+    pub fn diagnostic(&self) -> Option<&ParseDiagnostic> {
+        self.diagnostic.as_deref()
+    }
+
     /// Extracts the error message.
     #[inline]
     #[must_use]
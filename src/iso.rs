@@ -268,6 +268,22 @@ impl IsoDate {
         Self { year, month, day }
     }
 
+    /// Creates a new regulated `IsoDate` within the ISO date limits.
+    pub fn new(
+        year: i32,
+        month: u8,
+        day: u8,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<Self> {
+        // Reject out of range years before any epoch day arithmetic.
+        if !(-271821..=275760).contains(&year) {
+            return Err(
+                TemporalError::range().with_message("Date is not within ISO date time limits.")
+            );
+        }
+        Self::new_with_overflow(year, month, day, overflow)
+    }
+
     pub(crate) fn regulate(
         year: i32,
         month: u8,
@@ -937,7 +953,7 @@ pub(crate) fn iso_date_to_epoch_days(year: i32, month: i32, day: i32) -> i64 {
 
 #[inline]
 // Determines if the month and day are valid for the given year.
-fn is_valid_date(year: i32, month: u8, day: u8) -> bool {
+pub(crate) fn is_valid_date(year: i32, month: u8, day: u8) -> bool {
     if !(1..=12).contains(&month) {
         return false;
     }
@@ -1068,6 +1084,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn checked_iso_date() {
+        use crate::options::ArithmeticOverflow;
+
+        let reject = ArithmeticOverflow::Reject;
+        assert_eq!(
+            IsoDate::new(2024, 2, 29, reject).unwrap(),
+            IsoDate::new_unchecked(2024, 2, 29)
+        );
+        assert!(IsoDate::new(2023, 2, 29, reject).is_err());
+        assert_eq!(
+            IsoDate::new(2023, 2, 29, ArithmeticOverflow::Constrain).unwrap(),
+            IsoDate::new_unchecked(2023, 2, 28)
+        );
+        assert!(IsoDate::new(275_760, 9, 14, reject).is_err());
+        assert!(IsoDate::new(i32::MAX, 1, 1, reject).is_err());
+        assert!(IsoDate::new(i32::MIN, 1, 1, reject).is_err());
+    }
+
     #[test]
     fn test_month_limits() {
         assert_eq!(iso_date_to_epoch_days(1970, 1, 1), 0);
@@ -177,6 +177,55 @@ pub mod partial {
     };
 }
 
+pub mod business {
+    //! Business-day arithmetic with configurable weekends and holidays.
+    pub use crate::builtins::core::business::{
+        BusinessCalendar, HolidayCalendar, HolidayRule, HolidaySet, RollConvention, Weekend,
+    };
+}
+
+#[cfg(feature = "locale")]
+pub mod locale {
+    //! Locale-aware formatting of dates, times, durations and relative times
+    //! through ICU4X.
+    //!
+    //! Duration formatting relies on `icu_experimental`, which has no stability
+    //! guarantees, so the exact output of [`LocaleFormattedDuration`] may change
+    //! in any release.
+    pub use crate::builtins::core::locale::{
+        DateFields, DurationDisplay, DurationFormatOptions, DurationStyle, DurationUnitOptions,
+        DurationUnitStyle, Length, LocaleFormatOptions, LocaleFormatted, LocaleFormattedDuration,
+        LocaleFormattedRelativeTime, RelativeTimeFormatOptions, RelativeTimeStyle, TimePrecision,
+        YearStyle, ZoneStyle,
+    };
+}
+
+pub mod relative_time {
+    //! Relative time phrases, such as "3 days ago" or "in 2 hours".
+    pub use crate::builtins::core::relative_time::{
+        RelativeTime, RelativeTimeOptions, RelativeTimeThresholds,
+    };
+}
+
+pub mod icalendar {
+    //! iCalendar value parsing and formatting, and `VTIMEZONE` generation.
+    pub use crate::builtins::core::icalendar::{Period, PeriodEnd};
+}
+
+pub mod interval {
+    //! ISO 8601 time intervals and repeating intervals.
+    pub use crate::builtins::core::interval::{
+        Interval, IntervalForm, IntervalOccurrences, IntervalPoint, RepeatingInterval,
+    };
+}
+
+pub mod recurrence {
+    //! RFC 5545 recurrence rules and their expansion over `ZonedDateTime`.
+    pub use crate::builtins::core::recurrence::{
+        Frequency, Occurrences, RecurrenceRule, RecurrenceSet, RecurrenceUntil, WeekdayNum,
+    };
+}
+
 pub mod parsed_intermediates;
 
 // TODO: Potentially bikeshed how `EpochNanoseconds` should be exported.
@@ -198,11 +247,17 @@ pub mod fields {
 }
 
 pub use crate::builtins::{
-    calendar::{Calendar, MonthCode},
-    core::timezone::{TimeZone, UtcOffset},
+    calendar::{
+        Calendar, CalendarEra, CalendarMonth, CustomCalendar, CustomCalendarResolver, EraDirection,
+        MonthCode, WeekRules,
+    },
+    core::timezone::{
+        PossibleInstants, TimeZone, TimeZoneConverter, TimeZoneEquivalence, TimeZoneTransition,
+        UtcOffset, WallClockShift,
+    },
     core::DateDuration,
-    Duration, Instant, PlainDate, PlainDateTime, PlainMonthDay, PlainTime, PlainYearMonth,
-    ZonedDateTime,
+    Duration, Instant, PlainDate, PlainDateTime, PlainMonthDay, PlainTime, PlainWeekDate,
+    PlainYearMonth, ZonedDateTime,
 };
 
 /// A library specific trait for unwrapping assertions.
@@ -9,6 +9,7 @@ use crate::{error::ErrorMessage, TemporalError, TemporalResult, MS_PER_DAY, NS_P
 use core::num::NonZeroU128;
 use core::ops::Add;
 use core::{fmt, str::FromStr};
+use writeable::{impl_display_with_writeable, LengthHint, Writeable};
 
 mod increment;
 mod relative_to;
@@ -16,6 +17,23 @@ mod relative_to;
 pub use increment::RoundingIncrement;
 pub use relative_to::RelativeTo;
 
+/// Implements `Writeable` and `Display` for an option from its `as_str` value.
+macro_rules! impl_option_writeable {
+    ($ty:ty) => {
+        impl Writeable for $ty {
+            fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+                sink.write_str(self.as_str())
+            }
+
+            fn writeable_length_hint(&self) -> LengthHint {
+                LengthHint::exact(self.as_str().len())
+            }
+        }
+
+        impl_display_with_writeable!($ty);
+    };
+}
+
 // ==== RoundingOptions / DifferenceSettings ====
 
 #[derive(Debug, Clone, Copy)]
@@ -567,8 +585,8 @@ impl FromStr for Unit {
     }
 }
 
-impl fmt::Display for Unit {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+impl Unit {
+    const fn as_str(self) -> &'static str {
         match self {
             Self::Auto => "auto",
             Self::Year => "year",
@@ -582,10 +600,11 @@ impl fmt::Display for Unit {
             Self::Microsecond => "microsecond",
             Self::Nanosecond => "nanosecond",
         }
-        .fmt(f)
     }
 }
 
+impl_option_writeable!(Unit);
+
 /// `ArithmeticOverflow` can also be used as an
 /// assignment overflow and consists of the "constrain"
 /// and "reject" options.
@@ -620,16 +639,17 @@ impl FromStr for ArithmeticOverflow {
     }
 }
 
-impl fmt::Display for ArithmeticOverflow {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+impl ArithmeticOverflow {
+    const fn as_str(self) -> &'static str {
         match self {
             Self::Constrain => "constrain",
             Self::Reject => "reject",
         }
-        .fmt(f)
     }
 }
 
+impl_option_writeable!(ArithmeticOverflow);
+
 /// `Duration` overflow options.
 #[derive(Debug, Clone, Copy)]
 pub enum DurationOverflow {
@@ -661,16 +681,17 @@ impl FromStr for DurationOverflow {
     }
 }
 
-impl fmt::Display for DurationOverflow {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+impl DurationOverflow {
+    const fn as_str(self) -> &'static str {
         match self {
             Self::Constrain => "constrain",
             Self::Balance => "balance",
         }
-        .fmt(f)
     }
 }
 
+impl_option_writeable!(DurationOverflow);
+
 /// The disambiguation options for an instant.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Disambiguation {
@@ -711,18 +732,19 @@ impl FromStr for Disambiguation {
     }
 }
 
-impl fmt::Display for Disambiguation {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+impl Disambiguation {
+    const fn as_str(self) -> &'static str {
         match self {
             Self::Compatible => "compatible",
             Self::Earlier => "earlier",
             Self::Later => "later",
             Self::Reject => "reject",
         }
-        .fmt(f)
     }
 }
 
+impl_option_writeable!(Disambiguation);
+
 /// Offset disambiguation options.
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub enum OffsetDisambiguation {
@@ -760,18 +782,19 @@ impl FromStr for OffsetDisambiguation {
     }
 }
 
-impl fmt::Display for OffsetDisambiguation {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+impl OffsetDisambiguation {
+    const fn as_str(self) -> &'static str {
         match self {
             Self::Use => "use",
             Self::Prefer => "prefer",
             Self::Ignore => "ignore",
             Self::Reject => "reject",
         }
-        .fmt(f)
     }
 }
 
+impl_option_writeable!(OffsetDisambiguation);
+
 // TODO: Figure out what to do with intl's RoundingMode
 
 /// Declares the specified `RoundingMode` for the operation.
@@ -876,8 +899,8 @@ impl FromStr for RoundingMode {
     }
 }
 
-impl fmt::Display for RoundingMode {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+impl RoundingMode {
+    const fn as_str(self) -> &'static str {
         match self {
             Self::Ceil => "ceil",
             Self::Floor => "floor",
@@ -889,10 +912,11 @@ impl fmt::Display for RoundingMode {
             Self::HalfTrunc => "halfTrunc",
             Self::HalfEven => "halfEven",
         }
-        .fmt(f)
     }
 }
 
+impl_option_writeable!(RoundingMode);
+
 /// values for `CalendarName`, whether to show the calendar in toString() methods
 /// <https://tc39.es/proposal-temporal/#sec-temporal-gettemporalshowcalendarnameoption>
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -908,18 +932,19 @@ pub enum DisplayCalendar {
     Critical,
 }
 
-impl fmt::Display for DisplayCalendar {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+impl DisplayCalendar {
+    const fn as_str(self) -> &'static str {
         match self {
             DisplayCalendar::Auto => "auto",
             DisplayCalendar::Always => "always",
             DisplayCalendar::Never => "never",
             DisplayCalendar::Critical => "critical",
         }
-        .fmt(f)
     }
 }
 
+impl_option_writeable!(DisplayCalendar);
+
 impl FromStr for DisplayCalendar {
     type Err = TemporalError;
 
@@ -941,16 +966,17 @@ pub enum DisplayOffset {
     Never,
 }
 
-impl fmt::Display for DisplayOffset {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+impl DisplayOffset {
+    const fn as_str(self) -> &'static str {
         match self {
             DisplayOffset::Auto => "auto",
             DisplayOffset::Never => "never",
         }
-        .fmt(f)
     }
 }
 
+impl_option_writeable!(DisplayOffset);
+
 impl FromStr for DisplayOffset {
     type Err = TemporalError;
 
@@ -974,17 +1000,18 @@ pub enum DisplayTimeZone {
     Critical,
 }
 
-impl fmt::Display for DisplayTimeZone {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+impl DisplayTimeZone {
+    const fn as_str(self) -> &'static str {
         match self {
             DisplayTimeZone::Auto => "auto",
             DisplayTimeZone::Never => "never",
             DisplayTimeZone::Critical => "critical",
         }
-        .fmt(f)
     }
 }
 
+impl_option_writeable!(DisplayTimeZone);
+
 impl FromStr for DisplayTimeZone {
     type Err = TemporalError;
 
@@ -1,10 +1,11 @@
 //! RelativeTo rounding option
 
 use crate::builtins::core::zoneddatetime::interpret_isodatetime_offset;
-use crate::builtins::core::{calendar::Calendar, timezone::TimeZone, PlainDate, ZonedDateTime};
+use crate::builtins::core::{timezone::TimeZone, PlainDate, ZonedDateTime};
 use crate::iso::{IsoDate, IsoTime};
 use crate::options::{ArithmeticOverflow, Disambiguation, OffsetDisambiguation};
-use crate::parsers::{parse_date_time, parse_zoned_date_time};
+use crate::parsed_intermediates::extract_calendar;
+use crate::parsers::{parse_date_time, parse_zoned_date_time, Encoded};
 use crate::provider::TimeZoneProvider;
 use crate::{TemporalResult, TemporalUnwrap};
 
@@ -39,19 +40,30 @@ impl RelativeTo {
     pub fn try_from_str_with_provider(
         source: &str,
         provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Self> {
+        Self::try_from_encoded(Encoded::Utf8(source.as_bytes()), provider)
+    }
+
+    /// Attempts to parse a UTF-16 encoded `ZonedDateTime` string falling back
+    /// to a `PlainDate` if possible.
+    pub fn try_from_utf16_with_provider(
+        source: &[u16],
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Self> {
+        Self::try_from_encoded(Encoded::Utf16(source), provider)
+    }
+
+    fn try_from_encoded(
+        source: Encoded<'_>,
+        provider: &impl TimeZoneProvider,
     ) -> TemporalResult<Self> {
         // b. Let result be ? ParseISODateTime(value, « TemporalDateTimeString[+Zoned], TemporalDateTimeString[~Zoned] »).
-        let bytes = source.as_bytes();
-        let result = parse_date_time(bytes).or_else(|_| parse_zoned_date_time(bytes))?;
+        let result = parse_date_time(source).or_else(|_| parse_zoned_date_time(source))?;
 
         let Some(annotation) = result.tz else {
             let date_record = result.date.temporal_unwrap()?;
 
-            let calendar = result
-                .calendar
-                .map(Calendar::try_from_utf8)
-                .transpose()?
-                .unwrap_or_default();
+            let calendar = extract_calendar(result.calendar)?;
 
             return Ok(PlainDate::try_new(
                 date_record.year,
@@ -65,7 +77,7 @@ impl RelativeTo {
         // iv. Set matchBehaviour to match-minutes.
         let mut match_minutes = true;
 
-        let timezone = TimeZone::from_time_zone_record(annotation.tz, provider)?;
+        let timezone = TimeZone::from_time_zone_record(annotation, provider)?;
 
         let (offset_nanos, is_exact) = result
             .offset
@@ -95,11 +107,7 @@ impl RelativeTo {
             })
             .unwrap_or((None, false));
 
-        let calendar = result
-            .calendar
-            .map(Calendar::try_from_utf8)
-            .transpose()?
-            .unwrap_or_default();
+        let calendar = extract_calendar(result.calendar)?;
 
         let time = result.time.map(IsoTime::from_time_record).transpose()?;
 
@@ -183,4 +191,38 @@ mod tests {
             "Rounding not accepted between ISO offset and timezone"
         );
     }
+    #[cfg(feature = "compiled_data")]
+    #[test]
+    fn relativeto_utf16_parse() {
+        use alloc::vec::Vec;
+
+        let provider = &*crate::builtins::TZ_PROVIDER;
+        let utf16 = |s: &str| s.encode_utf16().collect::<Vec<u16>>();
+
+        let source = "2024-03-10T12:00:00-04:00[America/New_York][u-ca=gregory]";
+        let RelativeTo::ZonedDateTime(zdt) =
+            RelativeTo::try_from_utf16_with_provider(&utf16(source), provider).unwrap()
+        else {
+            panic!("expected a ZonedDateTime");
+        };
+        let RelativeTo::ZonedDateTime(expected) =
+            RelativeTo::try_from_str_with_provider(source, provider).unwrap()
+        else {
+            panic!("expected a ZonedDateTime");
+        };
+        assert_eq!(zdt.epoch_nanoseconds(), expected.epoch_nanoseconds());
+        assert_eq!(zdt.timezone(), expected.timezone());
+        assert_eq!(zdt.calendar(), expected.calendar());
+
+        let RelativeTo::PlainDate(date) =
+            RelativeTo::try_from_utf16_with_provider(&utf16("2024-03-10"), provider).unwrap()
+        else {
+            panic!("expected a PlainDate");
+        };
+        assert_eq!(date.day(), 10);
+
+        let long_name =
+            "2024-03-10T12:00[America/Argentina/Buenos_Aires/Too/Long/To/Be/An/Iana/Name]";
+        assert!(RelativeTo::try_from_utf16_with_provider(&utf16(long_name), provider).is_err());
+    }
 }
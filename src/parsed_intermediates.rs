@@ -7,62 +7,109 @@
 //! since the specification performs observable operations
 //! between the parse and validate steps.
 
+use crate::builtins::core::calendar::NO_CUSTOM_CALENDARS;
 use crate::error::ErrorMessage;
 use crate::error::TemporalError;
 use crate::iso::IsoTime;
-use crate::parsers;
+use crate::parsers::{self, Encoded, ParseRecord};
 use crate::provider::TimeZoneProvider;
 use crate::Calendar;
+use crate::CustomCalendarResolver;
 use crate::TemporalResult;
 use crate::TemporalUnwrap;
 use crate::TimeZone;
 use crate::UtcOffset;
-use icu_calendar::AnyCalendarKind;
 use ixdtf::records::DateRecord;
 use ixdtf::records::UtcOffsetRecordOrZ;
 
-fn extract_kind(calendar: Option<&[u8]>) -> TemporalResult<AnyCalendarKind> {
+pub(crate) fn extract_calendar(calendar: Option<Encoded<'_>>) -> TemporalResult<Calendar> {
+    extract_calendar_with_resolver(calendar, NO_CUSTOM_CALENDARS)
+}
+
+pub(crate) fn extract_calendar_with_resolver(
+    calendar: Option<Encoded<'_>>,
+    resolver: &(impl CustomCalendarResolver + ?Sized),
+) -> TemporalResult<Calendar> {
     Ok(calendar
-        .map(Calendar::try_kind_from_utf8)
+        .map(|calendar| {
+            calendar.with_ascii(|bytes| Calendar::try_from_utf8_with_resolver(bytes, resolver))
+        })
         .transpose()?
-        .unwrap_or(AnyCalendarKind::Iso))
+        .unwrap_or_default())
 }
 
 /// A parsed-but-not-validated date
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct ParsedDate {
     pub record: DateRecord,
-    pub calendar: AnyCalendarKind,
+    pub calendar: Calendar,
 }
 
 impl ParsedDate {
     /// Converts a UTF-8 encoded string into a `ParsedDate`.
     pub fn from_utf8(s: &[u8]) -> TemporalResult<Self> {
-        let parse_record = parsers::parse_date_time(s)?;
-
-        let calendar = extract_kind(parse_record.calendar)?;
-
-        // Assertion: PlainDate must exist on a DateTime parse.
-        let record = parse_record.date.temporal_unwrap()?;
-
-        Ok(Self { record, calendar })
+        Self::from_record(
+            parsers::parse_date_time(Encoded::Utf8(s))?,
+            NO_CUSTOM_CALENDARS,
+        )
+    }
+    /// Converts a UTF-16 encoded string into a `ParsedDate`.
+    pub fn from_utf16(s: &[u16]) -> TemporalResult<Self> {
+        Self::from_record(
+            parsers::parse_date_time(Encoded::Utf16(s))?,
+            NO_CUSTOM_CALENDARS,
+        )
+    }
+    /// Converts a UTF-8 encoded string into a `ParsedDate`, resolving custom
+    /// calendar annotations with `resolver`.
+    pub fn from_utf8_with_resolver(
+        s: &[u8],
+        resolver: &(impl CustomCalendarResolver + ?Sized),
+    ) -> TemporalResult<Self> {
+        Self::from_record(parsers::parse_date_time(Encoded::Utf8(s))?, resolver)
+    }
+    /// Converts a UTF-16 encoded string into a `ParsedDate`, resolving custom
+    /// calendar annotations with `resolver`.
+    pub fn from_utf16_with_resolver(
+        s: &[u16],
+        resolver: &(impl CustomCalendarResolver + ?Sized),
+    ) -> TemporalResult<Self> {
+        Self::from_record(parsers::parse_date_time(Encoded::Utf16(s))?, resolver)
     }
     /// Converts a UTF-8 encoded YearMonth string into a `ParsedDate`.
     pub fn year_month_from_utf8(s: &[u8]) -> TemporalResult<Self> {
-        let parse_record = parsers::parse_year_month(s)?;
-
-        let calendar = extract_kind(parse_record.calendar)?;
-
-        // Assertion: PlainDate must exist on a DateTime parse.
-        let record = parse_record.date.temporal_unwrap()?;
-
-        Ok(Self { record, calendar })
+        Self::from_record(
+            parsers::parse_year_month(Encoded::Utf8(s))?,
+            NO_CUSTOM_CALENDARS,
+        )
+    }
+    /// Converts a UTF-16 encoded YearMonth string into a `ParsedDate`.
+    pub fn year_month_from_utf16(s: &[u16]) -> TemporalResult<Self> {
+        Self::from_record(
+            parsers::parse_year_month(Encoded::Utf16(s))?,
+            NO_CUSTOM_CALENDARS,
+        )
     }
     /// Converts a UTF-8 encoded MonthDay string into a `ParsedDate`.
     pub fn month_day_from_utf8(s: &[u8]) -> TemporalResult<Self> {
-        let parse_record = parsers::parse_month_day(s)?;
+        Self::from_record(
+            parsers::parse_month_day(Encoded::Utf8(s))?,
+            NO_CUSTOM_CALENDARS,
+        )
+    }
+    /// Converts a UTF-16 encoded MonthDay string into a `ParsedDate`.
+    pub fn month_day_from_utf16(s: &[u16]) -> TemporalResult<Self> {
+        Self::from_record(
+            parsers::parse_month_day(Encoded::Utf16(s))?,
+            NO_CUSTOM_CALENDARS,
+        )
+    }
 
-        let calendar = extract_kind(parse_record.calendar)?;
+    fn from_record(
+        parse_record: ParseRecord<'_>,
+        resolver: &(impl CustomCalendarResolver + ?Sized),
+    ) -> TemporalResult<Self> {
+        let calendar = extract_calendar_with_resolver(parse_record.calendar, resolver)?;
 
         // Assertion: PlainDate must exist on a DateTime parse.
         let record = parse_record.date.temporal_unwrap()?;
@@ -72,7 +119,7 @@ impl ParsedDate {
 }
 
 /// A parsed-but-not-validated datetime
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct ParsedDateTime {
     pub date: ParsedDate,
     pub time: IsoTime,
@@ -81,9 +128,16 @@ pub struct ParsedDateTime {
 impl ParsedDateTime {
     /// Converts a UTF-8 encoded string into a `ParsedDateTime`.
     pub fn from_utf8(s: &[u8]) -> TemporalResult<Self> {
-        let parse_record = parsers::parse_date_time(s)?;
+        Self::from_record(parsers::parse_date_time(Encoded::Utf8(s))?)
+    }
+
+    /// Converts a UTF-16 encoded string into a `ParsedDateTime`.
+    pub fn from_utf16(s: &[u16]) -> TemporalResult<Self> {
+        Self::from_record(parsers::parse_date_time(Encoded::Utf16(s))?)
+    }
 
-        let calendar = extract_kind(parse_record.calendar)?;
+    fn from_record(parse_record: ParseRecord<'_>) -> TemporalResult<Self> {
+        let calendar = extract_calendar(parse_record.calendar)?;
 
         let time = parse_record
             .time
@@ -131,6 +185,24 @@ impl ParsedZonedDateTime {
         source: &[u8],
         provider: &impl TimeZoneProvider,
     ) -> TemporalResult<Self> {
+        Self::from_encoded(Encoded::Utf8(source), provider)
+    }
+
+    /// Converts a UTF-16 encoded string into a `ParsedZonedDateTime`, using compiled data
+    #[cfg(feature = "compiled_data")]
+    pub fn from_utf16(source: &[u16]) -> TemporalResult<Self> {
+        Self::from_utf16_with_provider(source, &*crate::builtins::TZ_PROVIDER)
+    }
+
+    /// Converts a UTF-16 encoded string into a `ParsedZonedDateTime`.
+    pub fn from_utf16_with_provider(
+        source: &[u16],
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Self> {
+        Self::from_encoded(Encoded::Utf16(source), provider)
+    }
+
+    fn from_encoded(source: Encoded<'_>, provider: &impl TimeZoneProvider) -> TemporalResult<Self> {
         // Steps from the parse bits of of ToZonedDateTime
 
         // 3. Let matchBehaviour be match-minutes.
@@ -145,7 +217,7 @@ impl ParsedZonedDateTime {
         let annotation = parse_result.tz.temporal_unwrap()?;
 
         // e. Let timeZone be ? ToTemporalTimeZoneIdentifier(annotation).
-        let timezone = TimeZone::from_time_zone_record(annotation.tz, provider)?;
+        let timezone = TimeZone::from_time_zone_record(annotation, provider)?;
 
         // f. Let offsetString be result.[[TimeZone]].[[OffsetString]].
         let (offset, has_utc_designator) = match parse_result.offset {
@@ -165,7 +237,7 @@ impl ParsedZonedDateTime {
         // h. Let calendar be result.[[Calendar]].
         // i. If calendar is empty, set calendar to "iso8601".
         // j. Set calendar to ? CanonicalizeCalendar(calendar).
-        let calendar = extract_kind(parse_result.calendar)?;
+        let calendar = extract_calendar(parse_result.calendar)?;
 
         let Some(date) = parse_result.date else {
             return Err(TemporalError::range().with_enum(ErrorMessage::ParserNeedsDate));
@@ -1,20 +1,36 @@
 //! This module implements Temporal Date/Time parsing functionality.
 
 use crate::{
+    error::ParseDiagnostic,
     iso::{IsoDate, IsoTime},
     options::{DisplayCalendar, DisplayOffset, DisplayTimeZone},
-    Sign, TemporalError, TemporalResult,
+    Sign, TemporalError, TemporalResult, TimeZone,
 };
 use alloc::format;
+use alloc::vec::Vec;
 use ixdtf::{
-    encoding::Utf8,
+    encoding::{Utf16, Utf8},
     parsers::IxdtfParser,
-    records::{Annotation, DateRecord, IxdtfParseRecord, TimeRecord, UtcOffsetRecordOrZ},
+    records::{
+        Annotation, DateRecord, IxdtfParseRecord, MinutePrecisionOffset, TimeRecord,
+        TimeZoneRecord, UtcOffsetRecordOrZ,
+    },
+    ParseError,
 };
 use writeable::{impl_display_with_writeable, LengthHint, Writeable};
 
+mod cursor;
+mod rfc2822;
+mod rfc3339;
+mod strftime;
 mod timezone;
 
+pub(crate) use rfc2822::{parse_http_date, parse_rfc2822, Rfc2822Record};
+pub use rfc2822::{FormattableRfc2822, Rfc2822Zone};
+pub use rfc3339::FormattableRfc3339;
+pub(crate) use rfc3339::{parse_rfc3339, Rfc3339Record};
+pub(crate) use strftime::parse_strptime;
+pub use strftime::FormattableStrftime;
 pub(crate) use timezone::{parse_allowed_timezone_formats, parse_identifier};
 
 // TODO: Move `Writeable` functionality to `ixdtf` crate
@@ -77,7 +93,7 @@ impl<'a> IxdtfStringBuilder<'a> {
         self
     }
 
-    pub fn with_timezone(mut self, timezone: &'a str, show: DisplayTimeZone) -> Self {
+    pub fn with_timezone(mut self, timezone: &'a TimeZone, show: DisplayTimeZone) -> Self {
         self.inner.timezone = Some(FormattableTimeZone { show, timezone });
         self
     }
@@ -149,13 +165,14 @@ impl Writeable for FormattableTime {
             return LengthHint::exact(4 + sep);
         }
         let time_base = 6 + (sep * 2);
-        if self.nanosecond == 0 || self.precision == Precision::Digit(0) {
+        let digits = match self.precision {
+            Precision::Digit(digit) if digit <= 9 => digit as usize,
+            _ => u32_to_digits(self.nanosecond).1,
+        };
+        if digits == 0 {
             return LengthHint::exact(time_base);
         }
-        if let Precision::Digit(d) = self.precision {
-            return LengthHint::exact(time_base + 1 + d as usize);
-        }
-        LengthHint::between(time_base + 2, time_base + 10)
+        LengthHint::exact(time_base + 1 + digits)
     }
 }
 
@@ -183,6 +200,9 @@ impl Writeable for FormattableUtcOffset {
     }
 
     fn writeable_length_hint(&self) -> LengthHint {
+        if self.show == DisplayOffset::Never {
+            return LengthHint::exact(0);
+        }
         match &self.offset {
             UtcOffset::Z => LengthHint::exact(1),
             UtcOffset::Offset(o) => o.writeable_length_hint(),
@@ -190,7 +210,10 @@ impl Writeable for FormattableUtcOffset {
     }
 }
 
-fn write_padded_u8<W: core::fmt::Write + ?Sized>(num: u8, sink: &mut W) -> core::fmt::Result {
+pub(crate) fn write_padded_u8<W: core::fmt::Write + ?Sized>(
+    num: u8,
+    sink: &mut W,
+) -> core::fmt::Result {
     if num < 10 {
         sink.write_char('0')?;
     }
@@ -288,7 +311,10 @@ impl Writeable for FormattableDate {
     }
 }
 
-fn write_year<W: core::fmt::Write + ?Sized>(year: i32, sink: &mut W) -> core::fmt::Result {
+pub(crate) fn write_year<W: core::fmt::Write + ?Sized>(
+    year: i32,
+    sink: &mut W,
+) -> core::fmt::Result {
     if (0..=9999).contains(&year) {
         write_four_digit_year(year, sink)
     } else {
@@ -320,7 +346,7 @@ fn write_extended_year<W: core::fmt::Write + ?Sized>(y: i32, sink: &mut W) -> co
 #[derive(Debug)]
 pub struct FormattableTimeZone<'a> {
     pub show: DisplayTimeZone,
-    pub timezone: &'a str,
+    pub timezone: &'a TimeZone,
 }
 
 impl Writeable for FormattableTimeZone<'_> {
@@ -332,7 +358,7 @@ impl Writeable for FormattableTimeZone<'_> {
         if self.show == DisplayTimeZone::Critical {
             sink.write_char('!')?;
         }
-        sink.write_str(self.timezone)?;
+        self.timezone.write_to(sink)?;
         sink.write_char(']')
     }
 
@@ -341,7 +367,7 @@ impl Writeable for FormattableTimeZone<'_> {
             return LengthHint::exact(0);
         }
         let critical = (self.show == DisplayTimeZone::Critical) as usize;
-        LengthHint::exact(2 + critical + self.timezone.len())
+        self.timezone.writeable_length_hint() + 2 + critical
     }
 }
 
@@ -621,6 +647,75 @@ impl Writeable for FormattableDuration {
         }
         Ok(())
     }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        let mut length = LengthHint::exact(1 + (self.sign == Sign::Negative) as usize);
+        if let Some(date) = self.date {
+            length += checked_suffix_length(date.years.into())
+                + checked_suffix_length(date.months.into())
+                + checked_suffix_length(date.weeks.into())
+                + checked_suffix_length(date.days);
+        }
+        let fraction_length = |ns: u32| {
+            if ns == 0 {
+                LengthHint::exact(0)
+            } else {
+                ns.writeable_length_hint() + 1
+            }
+        };
+        match self.time {
+            Some(FormattableTimeDuration::Hours(hours, fraction)) => {
+                if hours != 0 {
+                    let ns = fraction.unwrap_or(0);
+                    length += hours.writeable_length_hint() + fraction_length(ns) + 2;
+                } else if fraction.unwrap_or(0) != 0 {
+                    length += 1;
+                }
+            }
+            Some(FormattableTimeDuration::Minutes(hours, minutes, fraction)) => {
+                let ns = fraction.unwrap_or(0);
+                if hours + minutes + u64::from(ns) != 0 {
+                    length += 1;
+                }
+                length += checked_suffix_length(hours);
+                if minutes != 0 {
+                    length += minutes.writeable_length_hint() + fraction_length(ns) + 1;
+                }
+            }
+            Some(FormattableTimeDuration::Seconds(hours, minutes, seconds, fraction)) => {
+                let ns = fraction.unwrap_or(0);
+                let unit_below_minute = self.date.is_none() && hours == 0 && minutes == 0;
+                let write_second = seconds != 0
+                    || unit_below_minute
+                    || matches!(self.precision, Precision::Digit(_));
+                if hours != 0 || minutes != 0 || write_second {
+                    length += 1;
+                }
+                length += checked_suffix_length(hours) + checked_suffix_length(minutes);
+                if write_second {
+                    length += seconds.writeable_length_hint() + 1;
+                    if self.precision != Precision::Digit(0)
+                        && (self.precision != Precision::Auto || ns != 0)
+                    {
+                        let digits = match self.precision {
+                            Precision::Digit(digit) if digit <= 9 => digit as usize,
+                            _ => u32_to_digits(ns).1,
+                        };
+                        length += 1 + digits;
+                    }
+                }
+            }
+            None => {}
+        }
+        length
+    }
+}
+
+fn checked_suffix_length(val: u64) -> LengthHint {
+    if val == 0 {
+        return LengthHint::exact(0);
+    }
+    val.writeable_length_hint() + 1
 }
 
 fn checked_write_u32_with_suffix<W: core::fmt::Write + ?Sized>(
@@ -647,9 +742,242 @@ fn checked_write_u64_with_suffix<W: core::fmt::Write + ?Sized>(
     sink.write_char(suffix)
 }
 
+/// A string slice in one of the encodings accepted by the IXDTF parsers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Encoded<'a> {
+    Utf8(&'a [u8]),
+    Utf16(&'a [u16]),
+}
+
+/// The longest UTF-16 identifier that is narrowed to ASCII without allocating.
+const INLINE_UTF16_IDENTIFIER_LEN: usize = 64;
+
+impl<'a> Encoded<'a> {
+    /// Returns the length of this string in code units.
+    pub(crate) fn len(self) -> usize {
+        match self {
+            Self::Utf8(bytes) => bytes.len(),
+            Self::Utf16(units) => units.len(),
+        }
+    }
+
+    /// Returns the first `len` code units of this string.
+    pub(crate) fn prefix(self, len: usize) -> Self {
+        match self {
+            Self::Utf8(bytes) => Self::Utf8(bytes.get(..len).unwrap_or(bytes)),
+            Self::Utf16(units) => Self::Utf16(units.get(..len).unwrap_or(units)),
+        }
+    }
+
+    /// Returns the character starting at code unit `offset`, decoding
+    /// invalid sequences as U+FFFD.
+    fn char_at(self, offset: usize) -> Option<char> {
+        match self {
+            Self::Utf8(bytes) => {
+                let chunk = bytes.get(offset..)?.utf8_chunks().next()?;
+                chunk
+                    .valid()
+                    .chars()
+                    .next()
+                    .or(Some(char::REPLACEMENT_CHARACTER))
+            }
+            Self::Utf16(units) => char::decode_utf16(units.get(offset..)?.iter().copied())
+                .next()
+                .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER)),
+        }
+    }
+
+    /// Calls `f` with this identifier as ASCII bytes.
+    ///
+    /// Identifiers in IXDTF strings are always ASCII, so UTF-16 identifiers
+    /// are narrowed instead of being transcoded, on the stack unless they are
+    /// longer than any calendar or time zone identifier in use.
+    pub(crate) fn with_ascii<R>(
+        self,
+        f: impl FnOnce(&[u8]) -> TemporalResult<R>,
+    ) -> TemporalResult<R> {
+        match self {
+            Self::Utf8(bytes) => f(bytes),
+            Self::Utf16(units) => {
+                let mut inline = [0; INLINE_UTF16_IDENTIFIER_LEN];
+                let mut heap = Vec::new();
+                let bytes = match inline.get_mut(..units.len()) {
+                    Some(bytes) => bytes,
+                    None => {
+                        heap.resize(units.len(), 0);
+                        heap.as_mut_slice()
+                    }
+                };
+                for (byte, unit) in bytes.iter_mut().zip(units) {
+                    *byte = u8::try_from(*unit)
+                        .ok()
+                        .filter(u8::is_ascii)
+                        .ok_or_else(|| {
+                            TemporalError::range().with_message("Identifier must be ASCII.")
+                        })?;
+                }
+                f(bytes)
+            }
+        }
+    }
+
+    fn eq_ignore_ascii_case(self, other: &[u8]) -> bool {
+        match self {
+            Self::Utf8(bytes) => bytes.eq_ignore_ascii_case(other),
+            Self::Utf16(units) => {
+                units.len() == other.len()
+                    && units.iter().zip(other).all(|(unit, byte)| {
+                        u8::try_from(*unit).is_ok_and(|unit| unit.eq_ignore_ascii_case(byte))
+                    })
+            }
+        }
+    }
+}
+
+/// Converts an `ixdtf` error into a `RangeError` carrying a [`ParseDiagnostic`].
+///
+/// `ixdtf` does not report where parsing stopped, so the offending code unit
+/// is located by bisecting for the shortest prefix of `source` that `reparse`
+/// rejects with the same error. Every longer prefix fails the same way, which
+/// keeps the search at a logarithmic number of reparses.
+pub(crate) fn ixdtf_error(
+    error: ParseError,
+    source: Encoded<'_>,
+    reparse: impl Fn(Encoded<'_>) -> Result<(), ParseError>,
+) -> TemporalError {
+    let len = source.len();
+    let offset = match error {
+        ParseError::AbruptEnd { .. } | ParseError::DateUnexpectedEnd => len,
+        _ => {
+            let (mut lo, mut hi) = (0, len);
+            while hi - lo > 1 {
+                let mid = lo + (hi - lo) / 2;
+                if reparse(source.prefix(mid)) == Err(error) {
+                    hi = mid;
+                } else {
+                    lo = mid;
+                }
+            }
+            hi.saturating_sub(1)
+        }
+    };
+    let (production, expected) = describe_ixdtf_error(error);
+    let diagnostic = ParseDiagnostic::new(offset, production, source.char_at(offset), expected);
+    TemporalError::range()
+        .with_message(format!("{error}"))
+        .with_diagnostic(diagnostic)
+}
+
+/// Returns the grammar production and the expected input for an `ixdtf` error.
+fn describe_ixdtf_error(error: ParseError) -> (&'static str, &'static str) {
+    match error {
+        ParseError::NonAsciiCodePoint => ("TemporalString", "an ASCII character"),
+        ParseError::ParseFloat | ParseError::FractionPart => {
+            ("TemporalDecimalFraction", "one to nine fractional digits")
+        }
+        ParseError::AbruptEnd { location } => (location, "more input"),
+        ParseError::InvalidEnd => ("TemporalString", "the end of input"),
+        ParseError::InvalidMonthRange => ("DateMonth", "a month from 01 to 12"),
+        ParseError::InvalidDayRange => ("DateDay", "a day within the month"),
+        ParseError::DateYear => ("DateYear", "a four digit year"),
+        ParseError::DateExtendedYear => ("DateYear", "a signed six digit year"),
+        ParseError::DateMonth => ("DateMonth", "a two digit month"),
+        ParseError::DateDay => ("DateDay", "a two digit day"),
+        ParseError::DateUnexpectedEnd => ("Date", "more input"),
+        ParseError::TimeRequired => ("TimeSpec", "a time"),
+        ParseError::TimeHour => ("Hour", "an hour from 00 to 23"),
+        ParseError::TimeMinuteSecond => ("MinuteSecond", "a value from 00 to 59"),
+        ParseError::TimeSecond => ("TimeSecond", "a second from 00 to 60"),
+        ParseError::DateSeparator => ("DateSeparator", "'-'"),
+        ParseError::TimeSeparator => ("TimeSeparator", "':'"),
+        ParseError::DecimalSeparator => ("TemporalDecimalSeparator", "'.' or ','"),
+        ParseError::InvalidAnnotation => ("Annotation", "a valid annotation"),
+        ParseError::AnnotationOpen => ("Annotation", "'['"),
+        ParseError::AnnotationClose => ("Annotation", "']'"),
+        ParseError::AnnotationChar => ("Annotation", "an annotation character"),
+        ParseError::AnnotationKeyValueSeparator => ("Annotation", "'='"),
+        ParseError::AnnotationKeyLeadingChar => ("AnnotationKey", "a lowercase letter or '_'"),
+        ParseError::AnnotationKeyChar => ("AnnotationKey", "a lowercase letter, digit, '-' or '_'"),
+        ParseError::AnnotationValueCharPostHyphen | ParseError::AnnotationValueChar => {
+            ("AnnotationValue", "a letter or digit")
+        }
+        ParseError::InvalidMinutePrecisionOffset => ("UTCOffset", "a minute precision offset"),
+        ParseError::CriticalDuplicateCalendar => {
+            ("Annotations", "a single calendar when one is critical")
+        }
+        ParseError::UnrecognizedCritical => ("Annotation", "a known critical annotation"),
+        ParseError::TzLeadingChar => ("TimeZoneIdentifier", "a time zone name or offset"),
+        ParseError::IanaCharPostSeparator | ParseError::IanaChar => {
+            ("TimeZoneIANAName", "a letter, digit, '.', '_', '+' or '-'")
+        }
+        ParseError::UtcTimeSeparator => ("UTCOffset", "consistent ':' separators"),
+        ParseError::OffsetNeedsSign => ("UTCOffset", "'+' or '-'"),
+        ParseError::MonthDayHyphen => ("DateSpecMonthDay", "a month or '--'"),
+        ParseError::InvalidMonthDay => ("DateSpecMonthDay", "a valid month and day"),
+        ParseError::DurationDisgnator => ("Duration", "'P'"),
+        ParseError::DurationValueExceededRange => ("Duration", "a value in the supported range"),
+        ParseError::DateDurationPartOrder => ("DurationDate", "'Y', 'M', 'W' and 'D' in order"),
+        ParseError::TimeDurationPartOrder => ("DurationTime", "'H', 'M' and 'S' in order"),
+        ParseError::TimeDurationDesignator => ("DurationTime", "a time duration after 'T'"),
+        ParseError::AmbiguousTimeMonthDay => ("TimeSpec", "a time that is not a month-day"),
+        ParseError::AmbiguousTimeYearMonth => ("TimeSpec", "a time that is not a year-month"),
+        _ => ("TemporalString", "a valid string"),
+    }
+}
+
+/// A time zone annotation parsed from an IXDTF string.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum TimeZoneIdentifier<'a> {
+    Name(Encoded<'a>),
+    Offset(MinutePrecisionOffset),
+}
+
+/// The parts of an [`IxdtfParseRecord`] used by Temporal, independent of the
+/// encoding of the parsed string.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ParseRecord<'a> {
+    pub(crate) date: Option<DateRecord>,
+    pub(crate) time: Option<TimeRecord>,
+    pub(crate) offset: Option<UtcOffsetRecordOrZ>,
+    pub(crate) tz: Option<TimeZoneIdentifier<'a>>,
+    pub(crate) calendar: Option<Encoded<'a>>,
+}
+
+macro_rules! impl_parse_record_from {
+    ($encoding:ident) => {
+        impl<'a> TryFrom<IxdtfParseRecord<'a, $encoding>> for ParseRecord<'a> {
+            type Error = TemporalError;
+
+            fn try_from(record: IxdtfParseRecord<'a, $encoding>) -> TemporalResult<Self> {
+                let tz = match record.tz.map(|annotation| annotation.tz) {
+                    Some(TimeZoneRecord::Name(name)) => {
+                        Some(TimeZoneIdentifier::Name(Encoded::$encoding(name)))
+                    }
+                    Some(TimeZoneRecord::Offset(offset)) => {
+                        Some(TimeZoneIdentifier::Offset(offset))
+                    }
+                    None => None,
+                    // TimeZoneRecord is non_exhaustive, but all current branches are matching.
+                    Some(_) => return Err(TemporalError::assert()),
+                };
+                Ok(Self {
+                    date: record.date,
+                    time: record.time,
+                    offset: record.offset,
+                    tz,
+                    calendar: record.calendar.map(Encoded::$encoding),
+                })
+            }
+        }
+    };
+}
+
+impl_parse_record_from!(Utf8);
+impl_parse_record_from!(Utf16);
+
 // TODO: Determine if these should be separate structs, i.e. TemporalDateTimeParser/TemporalInstantParser, or
 // maybe on global `TemporalParser` around `IxdtfParser` that handles the Temporal idiosyncracies.
-#[derive(PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
 enum ParseVariant {
     YearMonth,
     MonthDay,
@@ -657,45 +985,110 @@ enum ParseVariant {
     Time,
 }
 
-#[inline]
-fn parse_ixdtf(source: &[u8], variant: ParseVariant) -> TemporalResult<IxdtfParseRecord<'_, Utf8>> {
-    fn cast_handler<'a>(
-        _: &mut IxdtfParser<'a, Utf8>,
-        handler: impl FnMut(Annotation<'a, Utf8>) -> Option<Annotation<'a, Utf8>>,
-    ) -> impl FnMut(Annotation<'a, Utf8>) -> Option<Annotation<'a, Utf8>> {
-        handler
-    }
-
-    let mut first_calendar: Option<Annotation<Utf8>> = None;
-    let mut critical_duplicate_calendar = false;
-    let mut parser = IxdtfParser::from_utf8(source);
-
-    let handler = cast_handler(&mut parser, |annotation: Annotation<Utf8>| {
-        if annotation.key == "u-ca".as_bytes() {
-            match first_calendar {
-                Some(ref cal) => {
-                    if cal.critical || annotation.critical {
-                        critical_duplicate_calendar = true
-                    }
+/// Tracks the `u-ca` annotations seen while parsing.
+#[derive(Default)]
+struct CalendarAnnotations<'a> {
+    first: Option<(bool, Encoded<'a>)>,
+    critical_duplicate: bool,
+}
+
+impl<'a> CalendarAnnotations<'a> {
+    fn push(&mut self, critical: bool, value: Encoded<'a>) {
+        match self.first {
+            Some((first_critical, _)) => {
+                if first_critical || critical {
+                    self.critical_duplicate = true
                 }
-                None => first_calendar = Some(annotation),
             }
-            return None;
+            None => self.first = Some((critical, value)),
+        }
+    }
+}
+
+const CALENDAR_KEY_UTF16: [u16; 4] = [0x75, 0x2D, 0x63, 0x61];
+
+// `ixdtf` does not export its encoding trait, so the parse is expanded once per
+// encoding.
+macro_rules! parse_variant {
+    ($parser:expr, $variant:expr, $handler:expr) => {{
+        let mut parser = $parser;
+        match $variant {
+            ParseVariant::YearMonth => parser.parse_year_month_with_annotation_handler($handler),
+            ParseVariant::MonthDay => parser.parse_month_day_with_annotation_handler($handler),
+            ParseVariant::DateTime => parser.parse_with_annotation_handler($handler),
+            ParseVariant::Time => parser.parse_time_with_annotation_handler($handler),
         }
+    }};
+}
+
+// Reparses a prefix of a failed `parse_ixdtf` source to locate the error.
+fn reparse_ixdtf<'a>(source: Encoded<'a>, variant: ParseVariant) -> Result<(), ParseError> {
+    match source {
+        Encoded::Utf8(s) => parse_variant!(
+            IxdtfParser::from_utf8(s),
+            variant,
+            |annotation: Annotation<'a, Utf8>| (annotation.key != b"u-ca").then_some(annotation)
+        )
+        .map(|_| ()),
+        Encoded::Utf16(s) => parse_variant!(
+            IxdtfParser::from_utf16(s),
+            variant,
+            |annotation: Annotation<'a, Utf16>| {
+                (annotation.key != CALENDAR_KEY_UTF16).then_some(annotation)
+            }
+        )
+        .map(|_| ()),
+    }
+}
 
-        // Make the parser handle any unknown annotation.
-        Some(annotation)
-    });
+#[inline]
+fn parse_ixdtf<'a>(source: Encoded<'a>, variant: ParseVariant) -> TemporalResult<ParseRecord<'a>> {
+    parse_ixdtf_with(source, variant, true)
+}
 
-    let mut record = match variant {
-        ParseVariant::YearMonth => parser.parse_year_month_with_annotation_handler(handler),
-        ParseVariant::MonthDay => parser.parse_month_day_with_annotation_handler(handler),
-        ParseVariant::DateTime => parser.parse_with_annotation_handler(handler),
-        ParseVariant::Time => parser.parse_time_with_annotation_handler(handler),
+// Parses `source` as `variant`, only locating syntax errors when `diagnose` is
+// set. Callers that discard the error skip the extra reparsing.
+fn parse_ixdtf_with<'a>(
+    source: Encoded<'a>,
+    variant: ParseVariant,
+    diagnose: bool,
+) -> TemporalResult<ParseRecord<'a>> {
+    // The parser handles any unknown annotation.
+    macro_rules! parse_with_calendars {
+        ($parser:expr, $encoding:ident, $calendar_key:expr, $calendars:expr) => {{
+            let handler = |annotation: Annotation<'a, $encoding>| {
+                if annotation.key != $calendar_key {
+                    return Some(annotation);
+                }
+                $calendars.push(annotation.critical, Encoded::$encoding(annotation.value));
+                None
+            };
+            parse_variant!($parser, variant, handler)
+                .map_err(|e| {
+                    if diagnose {
+                        ixdtf_error(e, source, |prefix| reparse_ixdtf(prefix, variant))
+                    } else {
+                        TemporalError::range().with_message(format!("{e}"))
+                    }
+                })
+                .and_then(ParseRecord::try_from)
+        }};
     }
-    .map_err(|e| TemporalError::range().with_message(format!("{e}")))?;
 
-    record.calendar = first_calendar.map(|v| v.value);
+    let mut calendars = CalendarAnnotations::default();
+    let mut record = match source {
+        Encoded::Utf8(s) => {
+            parse_with_calendars!(IxdtfParser::from_utf8(s), Utf8, b"u-ca", calendars)
+        }
+        Encoded::Utf16(s) => parse_with_calendars!(
+            IxdtfParser::from_utf16(s),
+            Utf16,
+            CALENDAR_KEY_UTF16,
+            calendars
+        ),
+    }?;
+
+    record.calendar = calendars.first.map(|(_, value)| value);
 
     // Note: this method only handles the specific AnnotatedFoo nonterminals;
     // so if we are parsing MonthDay/YearMonth we will never have a DateDay/DateYear parse node.
@@ -714,7 +1107,7 @@ fn parse_ixdtf(source: &[u8], variant: ParseVariant) -> TemporalResult<IxdtfPars
         }
     }
 
-    if critical_duplicate_calendar {
+    if calendars.critical_duplicate {
         // TODO: Add tests for the below.
         // Parser handles non-matching calendar, so the value thrown here should only be duplicates.
         return Err(TemporalError::range()
@@ -733,7 +1126,7 @@ fn parse_ixdtf(source: &[u8], variant: ParseVariant) -> TemporalResult<IxdtfPars
 
 /// A utility function for parsing a `DateTime` string
 #[inline]
-pub(crate) fn parse_date_time(source: &[u8]) -> TemporalResult<IxdtfParseRecord<'_, Utf8>> {
+pub(crate) fn parse_date_time(source: Encoded<'_>) -> TemporalResult<ParseRecord<'_>> {
     let record = parse_ixdtf(source, ParseVariant::DateTime)?;
 
     if record.offset == Some(UtcOffsetRecordOrZ::Z) {
@@ -745,7 +1138,7 @@ pub(crate) fn parse_date_time(source: &[u8]) -> TemporalResult<IxdtfParseRecord<
 }
 
 #[inline]
-pub(crate) fn parse_zoned_date_time(source: &[u8]) -> TemporalResult<IxdtfParseRecord<'_, Utf8>> {
+pub(crate) fn parse_zoned_date_time(source: Encoded<'_>) -> TemporalResult<ParseRecord<'_>> {
     let record = parse_ixdtf(source, ParseVariant::DateTime)?;
 
     // TODO: Support rejecting subminute precision in time zone annootations
@@ -765,10 +1158,10 @@ pub(crate) struct IxdtfParseInstantRecord {
 
 /// A utility function for parsing an `Instant` string
 #[inline]
-pub(crate) fn parse_instant(source: &[u8]) -> TemporalResult<IxdtfParseInstantRecord> {
+pub(crate) fn parse_instant(source: Encoded<'_>) -> TemporalResult<IxdtfParseInstantRecord> {
     let record = parse_ixdtf(source, ParseVariant::DateTime)?;
 
-    let IxdtfParseRecord {
+    let ParseRecord {
         date: Some(date),
         time: Some(time),
         offset: Some(offset),
@@ -783,10 +1176,20 @@ pub(crate) fn parse_instant(source: &[u8]) -> TemporalResult<IxdtfParseInstantRe
     Ok(IxdtfParseInstantRecord { date, time, offset })
 }
 
+// Parses a `DateTime` string whose error is never surfaced, as in the
+// fallbacks below, which report the error of their own variant instead.
+fn parse_fallback_date_time(source: Encoded<'_>) -> TemporalResult<ParseRecord<'_>> {
+    let record = parse_ixdtf_with(source, ParseVariant::DateTime, false)?;
+    if record.offset == Some(UtcOffsetRecordOrZ::Z) {
+        return Err(TemporalError::range());
+    }
+    Ok(record)
+}
+
 // Ensure that the record does not have an offset element.
 //
 // This handles the [~Zoned] in TemporalFooString productions
-fn check_offset(record: IxdtfParseRecord<'_, Utf8>) -> TemporalResult<IxdtfParseRecord<'_, Utf8>> {
+fn check_offset(record: ParseRecord<'_>) -> TemporalResult<ParseRecord<'_>> {
     if record.offset == Some(UtcOffsetRecordOrZ::Z) {
         return Err(TemporalError::range()
             .with_message("UTC designator is not valid for plain date/time parsing."));
@@ -796,40 +1199,40 @@ fn check_offset(record: IxdtfParseRecord<'_, Utf8>) -> TemporalResult<IxdtfParse
 
 /// A utility function for parsing a `YearMonth` string
 #[inline]
-pub(crate) fn parse_year_month(source: &[u8]) -> TemporalResult<IxdtfParseRecord<'_, Utf8>> {
+pub(crate) fn parse_year_month(source: Encoded<'_>) -> TemporalResult<ParseRecord<'_>> {
     let ym_record = parse_ixdtf(source, ParseVariant::YearMonth);
 
-    let Err(ref e) = ym_record else {
+    let Err(e) = ym_record else {
         return ym_record.and_then(check_offset);
     };
 
-    let dt_parse = parse_date_time(source);
+    let dt_parse = parse_fallback_date_time(source);
 
     match dt_parse {
         Ok(dt) => check_offset(dt),
-        // Format and return the error from parsing YearMonth.
-        _ => Err(TemporalError::range().with_message(format!("{e}"))),
+        // Return the error from parsing YearMonth.
+        _ => Err(e),
     }
 }
 
 /// A utilty function for parsing a `MonthDay` String.
-pub(crate) fn parse_month_day(source: &[u8]) -> TemporalResult<IxdtfParseRecord<'_, Utf8>> {
+pub(crate) fn parse_month_day(source: Encoded<'_>) -> TemporalResult<ParseRecord<'_>> {
     let md_record = parse_ixdtf(source, ParseVariant::MonthDay);
-    let Err(ref e) = md_record else {
+    let Err(e) = md_record else {
         return md_record.and_then(check_offset);
     };
 
-    let dt_parse = parse_date_time(source);
+    let dt_parse = parse_fallback_date_time(source);
 
     match dt_parse {
         Ok(dt) => check_offset(dt),
-        // Format and return the error from parsing MonthDay.
-        _ => Err(TemporalError::range().with_message(format!("{e}"))),
+        // Return the error from parsing MonthDay.
+        _ => Err(e),
     }
 }
 
 // Ensures that an IxdtfParseRecord was parsed with [~Zoned][+TimeRequired]
-fn check_time_record(record: IxdtfParseRecord<Utf8>) -> TemporalResult<TimeRecord> {
+fn check_time_record(record: ParseRecord) -> TemporalResult<TimeRecord> {
     // Handle [~Zoned]
     let record = check_offset(record)?;
     // Handle [+TimeRequired]
@@ -841,19 +1244,19 @@ fn check_time_record(record: IxdtfParseRecord<Utf8>) -> TemporalResult<TimeRecor
 }
 
 #[inline]
-pub(crate) fn parse_time(source: &[u8]) -> TemporalResult<TimeRecord> {
+pub(crate) fn parse_time(source: Encoded<'_>) -> TemporalResult<TimeRecord> {
     let time_record = parse_ixdtf(source, ParseVariant::Time);
 
-    let Err(ref e) = time_record else {
+    let Err(e) = time_record else {
         return time_record.and_then(check_time_record);
     };
 
-    let dt_parse = parse_date_time(source);
+    let dt_parse = parse_fallback_date_time(source);
 
     match dt_parse {
         Ok(dt) => check_time_record(dt),
-        // Format and return the error from parsing MonthDay.
-        _ => Err(TemporalError::range().with_message(format!("{e}"))),
+        // Return the error from parsing Time.
+        _ => Err(e),
     }
 }
 
@@ -861,18 +1264,58 @@ pub(crate) fn parse_time(source: &[u8]) -> TemporalResult<TimeRecord> {
 /// will likely be replaced with a proper TemporalParser API at some point.
 #[inline]
 pub fn parse_allowed_calendar_formats(s: &[u8]) -> Option<&[u8]> {
-    if let Ok(r) = parse_ixdtf(s, ParseVariant::DateTime).map(|r| r.calendar) {
+    fn calendar(record: ParseRecord<'_>) -> Option<&[u8]> {
+        match record.calendar {
+            Some(Encoded::Utf8(calendar)) => Some(calendar),
+            _ => None,
+        }
+    }
+    let source = Encoded::Utf8(s);
+    if let Ok(r) = parse_ixdtf_with(source, ParseVariant::DateTime, false).map(calendar) {
         return Some(r.unwrap_or(&[]));
     } else if let Ok(r) = IxdtfParser::from_utf8(s).parse_time().map(|r| r.calendar) {
         return Some(r.unwrap_or(&[]));
-    } else if let Ok(r) = parse_ixdtf(s, ParseVariant::YearMonth).map(|r| r.calendar) {
+    } else if let Ok(r) = parse_ixdtf_with(source, ParseVariant::YearMonth, false).map(calendar) {
         return Some(r.unwrap_or(&[]));
-    } else if let Ok(r) = parse_ixdtf(s, ParseVariant::MonthDay).map(|r| r.calendar) {
+    } else if let Ok(r) = parse_ixdtf_with(source, ParseVariant::MonthDay, false).map(calendar) {
         return Some(r.unwrap_or(&[]));
     }
     None
 }
 
+/// Parses a four digit year or a signed six digit year at the start of `s`,
+/// returning the year and the remaining bytes.
+pub(crate) fn parse_iso_year_prefix(s: &[u8]) -> Option<(i32, &[u8])> {
+    match s {
+        [sign @ (b'+' | b'-'), rest @ ..] => {
+            let (digits, rest) = rest.split_at_checked(6)?;
+            let year = parse_ascii_digits(digits)? as i32;
+            // `-000000` is not a valid year.
+            match sign {
+                b'-' if year == 0 => None,
+                b'-' => Some((-year, rest)),
+                _ => Some((year, rest)),
+            }
+        }
+        _ => {
+            let (digits, rest) = s.split_at_checked(4)?;
+            Some((parse_ascii_digits(digits)? as i32, rest))
+        }
+    }
+}
+
+/// Parses a non-empty run of ASCII digits of at most nine digits.
+pub(crate) fn parse_ascii_digits(digits: &[u8]) -> Option<u32> {
+    if digits.is_empty() || digits.len() > 9 {
+        return None;
+    }
+    digits.iter().try_fold(0u32, |acc, digit| {
+        digit
+            .is_ascii_digit()
+            .then(|| acc * 10 + u32::from(digit - b'0'))
+    })
+}
+
 // TODO: ParseTimeZoneString, ParseZonedDateTimeString
 
 #[cfg(test)]
@@ -980,6 +1423,183 @@ mod tests {
         assert_writeable_eq!(time, "05:00:00");
     }
 
+    #[test]
+    fn utf16_matches_utf8() {
+        use crate::{
+            parsed_intermediates::ParsedDate, Calendar, Duration, Instant, PlainDate,
+            PlainDateTime, PlainMonthDay, PlainTime, PlainYearMonth, UtcOffset,
+        };
+        use alloc::string::ToString;
+        use alloc::vec::Vec;
+        use core::str::FromStr;
+
+        let utf16 = |s: &str| s.encode_utf16().collect::<Vec<u16>>();
+
+        let s = "2024-03-15T12:30:45.123[u-ca=hebrew]";
+        assert_eq!(
+            PlainDate::from_utf16(&utf16(s)).unwrap(),
+            PlainDate::from_str(s).unwrap()
+        );
+        assert_eq!(
+            PlainDateTime::from_utf16(&utf16(s)).unwrap(),
+            PlainDateTime::from_str(s).unwrap()
+        );
+        assert_eq!(
+            PlainTime::from_utf16(&utf16(s)).unwrap(),
+            PlainTime::from_str(s).unwrap()
+        );
+        assert_eq!(
+            PlainYearMonth::from_utf16(&utf16("2024-03[u-ca=ISO8601]")).unwrap(),
+            PlainYearMonth::from_str("2024-03").unwrap()
+        );
+        assert_eq!(
+            PlainMonthDay::from_utf16(&utf16("--03-15")).unwrap(),
+            PlainMonthDay::from_str("--03-15").unwrap()
+        );
+        assert_eq!(
+            Instant::from_utf16(&utf16("2024-03-15T12:30:45Z")).unwrap(),
+            Instant::from_str("2024-03-15T12:30:45Z").unwrap()
+        );
+        assert_eq!(
+            Duration::from_utf16(&utf16("P1Y2M3DT4H5M6.5S")).unwrap(),
+            Duration::from_str("P1Y2M3DT4H5M6.5S").unwrap()
+        );
+
+        assert!(ParsedDate::year_month_from_utf16(&utf16("2024-03[u-ca=hebrew]")).is_err());
+        assert!(PlainDate::from_utf16(&utf16("2024-03-15[u-ca=hébrew]")).is_err());
+        assert!(PlainDate::from_utf16(&utf16("2024-03-15[!u-ca=iso8601][u-ca=gregory]")).is_err());
+
+        assert_eq!(
+            UtcOffset::from_utf16(&utf16("-05:30")).unwrap(),
+            UtcOffset::from_utf8(b"-05:30").unwrap()
+        );
+        assert_eq!(
+            Calendar::try_from_utf16(&utf16("Hebrew")).unwrap(),
+            Calendar::try_from_utf8(b"Hebrew").unwrap()
+        );
+
+        // Identifiers have the same length limits in both encodings.
+        let long_calendar = "2024-03-15[u-ca=".to_string() + &"a".repeat(100) + "]";
+        assert_eq!(
+            PlainDate::from_utf16(&utf16(&long_calendar)).unwrap_err(),
+            PlainDate::from_str(&long_calendar).unwrap_err()
+        );
+    }
+
+    #[cfg(feature = "compiled_data")]
+    #[test]
+    fn time_zone_utf16_matches_utf8() {
+        use crate::TimeZone;
+        use alloc::string::ToString;
+        use alloc::vec::Vec;
+
+        let utf16 = |s: &str| s.encode_utf16().collect::<Vec<u16>>();
+
+        for source in [
+            "America/New_York",
+            "+01:00",
+            "2024-03-15T12:30:45Z",
+            "2024-03-15T12:30:45+05:30[Asia/Kolkata]",
+            "12:30[Europe/Berlin]",
+        ] {
+            assert_eq!(
+                TimeZone::try_from_utf16(&utf16(source)).unwrap(),
+                TimeZone::try_from_str(source).unwrap(),
+                "{source}"
+            );
+        }
+
+        let long_name = "America/".to_string() + &"a".repeat(100);
+        assert_eq!(
+            TimeZone::try_from_utf16(&utf16(&long_name)).unwrap_err(),
+            TimeZone::try_from_str(&long_name).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn parse_diagnostics() {
+        use crate::{Duration, PlainDateTime, UtcOffset};
+        use alloc::vec::Vec;
+        use core::str::FromStr;
+
+        let err = PlainDateTime::from_str("2024-13-01").unwrap_err();
+        let diagnostic = err.diagnostic().unwrap();
+        assert_eq!(diagnostic.offset(), 6);
+        assert_eq!(diagnostic.production(), "DateMonth");
+        assert_eq!(diagnostic.found(), Some('3'));
+        assert_eq!(diagnostic.expected(), "a month from 01 to 12");
+        assert_eq!(
+            diagnostic.render("2024-13-01"),
+            "2024-13-01\n      ^ DateMonth: expected a month from 01 to 12, found '3' at offset 6"
+        );
+
+        let err = PlainDateTime::from_str("2024-01-01Tx").unwrap_err();
+        let diagnostic = err.diagnostic().unwrap();
+        assert_eq!(diagnostic.offset(), 11);
+        assert_eq!(diagnostic.production(), "Hour");
+        assert_eq!(diagnostic.found(), Some('x'));
+
+        // Offsets are in code units, but the caret is placed by character.
+        let err = PlainDateTime::from_str("2024é").unwrap_err();
+        let diagnostic = err.diagnostic().unwrap();
+        assert_eq!(diagnostic.offset(), 4);
+        assert_eq!(diagnostic.found(), Some('é'));
+        let utf16 = "é2024".encode_utf16().collect::<Vec<u16>>();
+        let err = PlainDateTime::from_utf16(&utf16).unwrap_err();
+        assert_eq!(err.diagnostic().unwrap().offset(), 0);
+        let err = PlainDateTime::from_str("2024-01-01[é]").unwrap_err();
+        let diagnostic = err.diagnostic().unwrap();
+        assert_eq!(diagnostic.offset(), 11);
+        assert!(diagnostic
+            .render("2024-01-01[é]")
+            .starts_with("2024-01-01[é]\n           ^ "));
+
+        let err = PlainDateTime::from_str("2024-0").unwrap_err();
+        let diagnostic = err.diagnostic().unwrap();
+        assert_eq!(diagnostic.offset(), 6);
+        assert_eq!(diagnostic.found(), None);
+
+        let err = Duration::from_str("P1D2Y").unwrap_err();
+        let diagnostic = err.diagnostic().unwrap();
+        assert_eq!(diagnostic.offset(), 4);
+        assert_eq!(diagnostic.production(), "DurationDate");
+
+        let err = UtcOffset::from_str("05:00").unwrap_err();
+        let diagnostic = err.diagnostic().unwrap();
+        assert_eq!(diagnostic.offset(), 0);
+        assert_eq!(diagnostic.expected(), "'+' or '-'");
+
+        let utf16 = "2024-01-é1".encode_utf16().collect::<Vec<u16>>();
+        let err = PlainDateTime::from_utf16(&utf16).unwrap_err();
+        let diagnostic = err.diagnostic().unwrap();
+        assert_eq!(diagnostic.offset(), 8);
+        assert!(diagnostic
+            .render_utf16(&utf16)
+            .starts_with("2024-01-é1\n        ^ "));
+    }
+
+    #[test]
+    fn parse_diagnostics_long_input() {
+        use crate::{PlainDate, PlainDateTime, PlainTime, PlainYearMonth};
+        use alloc::string::String;
+        use core::str::FromStr;
+
+        // Locating the error must not reparse every prefix of the input.
+        let mut source = String::from("2024-01-01T00:00[");
+        source.extend(core::iter::repeat_n('a', 100_000));
+        source.push_str("]x");
+        let offset = source.len() - 1;
+
+        let err = PlainDateTime::from_str(&source).unwrap_err();
+        assert_eq!(err.diagnostic().unwrap().offset(), offset);
+        let err = PlainDate::from_str(&source).unwrap_err();
+        assert_eq!(err.diagnostic().unwrap().offset(), offset);
+        let err = PlainTime::from_str(&source).unwrap_err();
+        assert!(err.diagnostic().is_some());
+        let err = PlainYearMonth::from_str(&source).unwrap_err();
+        assert!(err.diagnostic().is_some());
+    }
+
     #[test]
     fn date_string() {
         let date = FormattableDate(2024, 12, 8);
@@ -0,0 +1,121 @@
+//! A byte cursor shared by the hand-written parsers.
+
+use alloc::format;
+
+use crate::{error::ParseDiagnostic, TemporalError, TemporalResult};
+
+/// A cursor over the source with position-aware syntax errors.
+pub(super) struct Cursor<'a> {
+    source: &'a [u8],
+    pub(super) pos: usize,
+    format: &'static str,
+}
+
+impl<'a> Cursor<'a> {
+    pub(super) fn new(source: &'a [u8], format: &'static str) -> Self {
+        Self {
+            source,
+            pos: 0,
+            format,
+        }
+    }
+
+    pub(super) fn error(&self, expected: &'static str) -> TemporalError {
+        let found = super::Encoded::Utf8(self.source).char_at(self.pos);
+        TemporalError::syntax()
+            .with_message(format!(
+                "Invalid {}: expected {expected} at position {}.",
+                self.format, self.pos
+            ))
+            .with_diagnostic(ParseDiagnostic::new(self.pos, self.format, found, expected))
+    }
+
+    /// Returns a `RangeError` for a well-formed value at the cursor that does
+    /// not fit its field.
+    pub(super) fn range_error(&self, expected: &'static str) -> TemporalError {
+        let found = super::Encoded::Utf8(self.source).char_at(self.pos);
+        TemporalError::range()
+            .with_message(format!(
+                "Invalid {}: expected {expected} at position {}.",
+                self.format, self.pos
+            ))
+            .with_diagnostic(ParseDiagnostic::new(self.pos, self.format, found, expected))
+    }
+
+    pub(super) fn peek(&self) -> Option<u8> {
+        self.source.get(self.pos).copied()
+    }
+
+    pub(super) fn is_done(&self) -> bool {
+        self.pos >= self.source.len()
+    }
+
+    pub(super) fn expect(&mut self, byte: u8, expected: &'static str) -> TemporalResult<()> {
+        if self.peek() != Some(byte) {
+            return Err(self.error(expected));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    pub(super) fn expect_str(&mut self, s: &str, expected: &'static str) -> TemporalResult<()> {
+        if !self
+            .source
+            .get(self.pos..)
+            .unwrap_or_default()
+            .starts_with(s.as_bytes())
+        {
+            return Err(self.error(expected));
+        }
+        self.pos += s.len();
+        Ok(())
+    }
+
+    /// Consumes a run of ASCII letters.
+    pub(super) fn alpha(&mut self) -> &'a [u8] {
+        let start = self.pos;
+        while self.peek().is_some_and(|b| b.is_ascii_alphabetic()) {
+            self.pos += 1;
+        }
+        self.source.get(start..self.pos).unwrap_or_default()
+    }
+
+    /// Consumes between `min` and `max` ASCII digits.
+    pub(super) fn digits(
+        &mut self,
+        min: usize,
+        max: usize,
+        expected: &'static str,
+    ) -> TemporalResult<(u32, usize)> {
+        let start = self.pos;
+        while self.pos - start < max && self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let digits = self.source.get(start..self.pos).unwrap_or_default();
+        if digits.len() < min || self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            self.pos = start;
+            return Err(self.error(expected));
+        }
+        let value = super::parse_ascii_digits(digits).ok_or_else(|| self.error(expected))?;
+        Ok((value, digits.len()))
+    }
+
+    /// Consumes one or more ASCII digits and parses the first `max` of them.
+    pub(super) fn truncated_digits(
+        &mut self,
+        max: usize,
+        expected: &'static str,
+    ) -> TemporalResult<(u32, usize)> {
+        let start = self.pos;
+        while self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let len = (self.pos - start).min(max);
+        let digits = self.source.get(start..start + len).unwrap_or_default();
+        if digits.is_empty() {
+            return Err(self.error(expected));
+        }
+        let value = super::parse_ascii_digits(digits).ok_or_else(|| self.error(expected))?;
+        Ok((value, len))
+    }
+}
@@ -0,0 +1,474 @@
+//! Parsing and formatting of RFC 5322 (RFC 2822) dates and RFC 9110 HTTP-dates.
+
+use writeable::{impl_display_with_writeable, LengthHint, Writeable};
+
+use crate::{
+    iso::{IsoDate, IsoTime},
+    options::ArithmeticOverflow,
+    TemporalError, TemporalResult,
+};
+
+use super::{cursor::Cursor, write_four_digit_year, write_padded_u8};
+
+const DAY_NAMES: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+const LONG_DAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// The fields of a parsed RFC 5322 date or HTTP-date.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Rfc2822Record {
+    pub(crate) date: IsoDate,
+    pub(crate) time: IsoTime,
+    /// The UTC offset in minutes, always below 24 hours.
+    pub(crate) offset_minutes: i16,
+}
+
+impl Cursor<'_> {
+    /// Skips folding white space and nested comments (`CFWS`).
+    fn skip_cfws(&mut self) -> TemporalResult<()> {
+        let mut depth = 0usize;
+        while let Some(byte) = self.peek() {
+            match byte {
+                b'(' => depth += 1,
+                b')' if depth > 0 => depth -= 1,
+                b'\\' if depth > 0 => self.pos += 1,
+                b' ' | b'\t' | b'\r' | b'\n' => {}
+                _ if depth > 0 => {}
+                _ => break,
+            }
+            self.pos += 1;
+        }
+        if depth > 0 {
+            return Err(self.error("the end of a comment"));
+        }
+        Ok(())
+    }
+}
+
+fn month_from_name(name: &[u8], case_sensitive: bool) -> Option<u8> {
+    MONTH_NAMES
+        .iter()
+        .position(|m| {
+            if case_sensitive {
+                m.as_bytes() == name
+            } else {
+                m.as_bytes().eq_ignore_ascii_case(name)
+            }
+        })
+        .map(|i| i as u8 + 1)
+}
+
+fn weekday_from_name(names: &[&str], name: &[u8], case_sensitive: bool) -> Option<u8> {
+    names
+        .iter()
+        .position(|d| {
+            if case_sensitive {
+                d.as_bytes() == name
+            } else {
+                d.as_bytes().eq_ignore_ascii_case(name)
+            }
+        })
+        .map(|i| i as u8 + 1)
+}
+
+/// Validates the parsed fields and builds the record.
+#[allow(clippy::too_many_arguments)]
+fn to_record(
+    weekday: Option<u8>,
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    offset_minutes: i16,
+) -> TemporalResult<Rfc2822Record> {
+    let date = IsoDate::new_with_overflow(year, month, day, ArithmeticOverflow::Reject)?;
+    // A leap second is constrained to the preceding second, as in RFC 9557 strings.
+    let second = second.min(59);
+    let time = IsoTime::new(hour, minute, second, 0, 0, 0, ArithmeticOverflow::Reject)?;
+    if weekday.is_some_and(|w| w != date.to_icu4x().day_of_week() as u8) {
+        return Err(
+            TemporalError::range().with_message("The day of the week does not match the date.")
+        );
+    }
+    Ok(Rfc2822Record {
+        date,
+        time,
+        offset_minutes,
+    })
+}
+
+/// Parses `hour ":" minute [":" second]`, allowing `CFWS` around the colons
+/// when `cfws` is set.
+fn parse_time_of_day(cursor: &mut Cursor<'_>, cfws: bool) -> TemporalResult<(u8, u8, u8)> {
+    let skip = |cursor: &mut Cursor<'_>| if cfws { cursor.skip_cfws() } else { Ok(()) };
+    let (hour, _) = cursor.digits(2, 2, "a two-digit hour")?;
+    skip(cursor)?;
+    cursor.expect(b':', "':'")?;
+    skip(cursor)?;
+    let (minute, _) = cursor.digits(2, 2, "a two-digit minute")?;
+    skip(cursor)?;
+    let second = if cfws && cursor.peek() != Some(b':') {
+        0
+    } else {
+        cursor.expect(b':', "':'")?;
+        skip(cursor)?;
+        cursor.digits(2, 2, "a two-digit second")?.0
+    };
+    Ok((hour as u8, minute as u8, second as u8))
+}
+
+/// Parses an RFC 5322 `date-time`, including the obsolete syntax.
+///
+/// Two-digit years are read as 1950–2049 and three-digit years as offsets
+/// from 1900, as RFC 5322 specifies.
+pub(crate) fn parse_rfc2822(source: &[u8]) -> TemporalResult<Rfc2822Record> {
+    let mut cursor = Cursor::new(source, "RFC 2822 date");
+    cursor.skip_cfws()?;
+
+    let weekday = if cursor.peek().is_some_and(|b| b.is_ascii_alphabetic()) {
+        let name = cursor.alpha();
+        let weekday = weekday_from_name(&DAY_NAMES, name, false);
+        if weekday.is_none() {
+            cursor.pos -= name.len();
+            return Err(cursor.error("a day name"));
+        }
+        cursor.skip_cfws()?;
+        cursor.expect(b',', "','")?;
+        cursor.skip_cfws()?;
+        weekday
+    } else {
+        None
+    };
+
+    let (day, _) = cursor.digits(1, 2, "a day")?;
+    cursor.skip_cfws()?;
+    let start = cursor.pos;
+    let month = month_from_name(cursor.alpha(), false).ok_or_else(|| {
+        cursor.pos = start;
+        cursor.error("a month name")
+    });
+    let month = month?;
+    cursor.skip_cfws()?;
+    let (year, len) = cursor.digits(2, 9, "a year")?;
+    let year = match (len, year as i32) {
+        (2, year) if year < 50 => year + 2000,
+        (2 | 3, year) => year + 1900,
+        (_, year) => year,
+    };
+    cursor.skip_cfws()?;
+
+    let (hour, minute, second) = parse_time_of_day(&mut cursor, true)?;
+    cursor.skip_cfws()?;
+
+    let offset_minutes = match cursor.peek() {
+        Some(sign @ (b'+' | b'-')) => {
+            cursor.pos += 1;
+            let (offset, _) = cursor.digits(4, 4, "a four-digit zone offset")?;
+            let (hours, minutes) = (offset / 100, offset % 100);
+            if hours > 23 {
+                cursor.pos -= 4;
+                return Err(cursor.error("zone offset hours below 24"));
+            }
+            if minutes > 59 {
+                cursor.pos -= 2;
+                return Err(cursor.error("zone offset minutes below 60"));
+            }
+            let offset = (hours * 60 + minutes) as i16;
+            if sign == b'-' {
+                -offset
+            } else {
+                offset
+            }
+        }
+        _ => {
+            let start = cursor.pos;
+            let name = cursor.alpha().to_ascii_uppercase();
+            match name.as_slice() {
+                b"UT" | b"GMT" => 0,
+                b"EDT" => -4 * 60,
+                b"EST" | b"CDT" => -5 * 60,
+                b"CST" | b"MDT" => -6 * 60,
+                b"MST" | b"PDT" => -7 * 60,
+                b"PST" => -8 * 60,
+                // Military zones carry no reliable offset and are treated as `-0000`.
+                [letter] if *letter != b'J' => 0,
+                _ => {
+                    cursor.pos = start;
+                    return Err(cursor.error("a zone"));
+                }
+            }
+        }
+    };
+    cursor.skip_cfws()?;
+    if !cursor.is_done() {
+        return Err(cursor.error("the end of the date"));
+    }
+
+    to_record(
+        weekday,
+        year,
+        month,
+        day as u8,
+        hour,
+        minute,
+        second,
+        offset_minutes,
+    )
+}
+
+/// Parses an RFC 9110 `HTTP-date`: an IMF-fixdate, or the obsolete RFC 850
+/// or asctime formats.
+///
+/// Two-digit RFC 850 years are read as 1950–2049.
+pub(crate) fn parse_http_date(source: &[u8]) -> TemporalResult<Rfc2822Record> {
+    let mut cursor = Cursor::new(source, "HTTP-date");
+    let name = cursor.alpha();
+
+    // asctime-date = day-name SP date3 SP time-of-day SP year
+    if cursor.peek() == Some(b' ') {
+        let weekday = weekday_from_name(&DAY_NAMES, name, true);
+        if weekday.is_none() {
+            cursor.pos = 0;
+            return Err(cursor.error("a day name"));
+        }
+        cursor.pos += 1;
+        let start = cursor.pos;
+        let month = month_from_name(cursor.alpha(), true).ok_or_else(|| {
+            cursor.pos = start;
+            cursor.error("a month name")
+        })?;
+        cursor.expect(b' ', "' '")?;
+        let (day, _) = if cursor.peek() == Some(b' ') {
+            cursor.pos += 1;
+            cursor.digits(1, 1, "a one-digit day")?
+        } else {
+            cursor.digits(2, 2, "a two-digit day")?
+        };
+        cursor.expect(b' ', "' '")?;
+        let (hour, minute, second) = parse_time_of_day(&mut cursor, false)?;
+        cursor.expect(b' ', "' '")?;
+        let (year, _) = cursor.digits(4, 4, "a four-digit year")?;
+        if !cursor.is_done() {
+            return Err(cursor.error("the end of the date"));
+        }
+        return to_record(
+            weekday,
+            year as i32,
+            month,
+            day as u8,
+            hour,
+            minute,
+            second,
+            0,
+        );
+    }
+
+    cursor.expect(b',', "',' or ' '")?;
+    cursor.expect(b' ', "' '")?;
+    let (day, _) = cursor.digits(2, 2, "a two-digit day")?;
+    let rfc850 = cursor.peek() == Some(b'-');
+    let separator = if rfc850 { b'-' } else { b' ' };
+    let weekday = if rfc850 {
+        weekday_from_name(&LONG_DAY_NAMES, name, true)
+    } else {
+        weekday_from_name(&DAY_NAMES, name, true)
+    };
+    if weekday.is_none() {
+        cursor.pos = 0;
+        return Err(cursor.error("a day name"));
+    }
+    cursor.expect(separator, "a date separator")?;
+    let start = cursor.pos;
+    let month = month_from_name(cursor.alpha(), true).ok_or_else(|| {
+        cursor.pos = start;
+        cursor.error("a month name")
+    })?;
+    cursor.expect(separator, "a date separator")?;
+    let year = if rfc850 {
+        match cursor.digits(2, 2, "a two-digit year")?.0 as i32 {
+            year if year < 50 => year + 2000,
+            year => year + 1900,
+        }
+    } else {
+        cursor.digits(4, 4, "a four-digit year")?.0 as i32
+    };
+    cursor.expect(b' ', "' '")?;
+    let (hour, minute, second) = parse_time_of_day(&mut cursor, false)?;
+    cursor.expect(b' ', "' '")?;
+    cursor.expect_str("GMT", "'GMT'")?;
+    if !cursor.is_done() {
+        return Err(cursor.error("the end of the date"));
+    }
+    to_record(weekday, year, month, day as u8, hour, minute, second, 0)
+}
+
+/// The zone of a [`FormattableRfc2822`] date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rfc2822Zone {
+    /// `GMT`, as used by HTTP-dates.
+    Gmt,
+    /// A numeric offset in minutes, e.g. `-0500`, below 24 hours.
+    Offset(i16),
+}
+
+/// An RFC 5322 date, e.g. `Fri, 21 Nov 1997 09:55:06 -0600`, or an
+/// IMF-fixdate with [`Rfc2822Zone::Gmt`].
+#[derive(Debug)]
+pub struct FormattableRfc2822 {
+    date: IsoDate,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    zone: Rfc2822Zone,
+}
+
+impl FormattableRfc2822 {
+    /// Creates a formattable date, rejecting years outside 0..=9999 and
+    /// offsets of 24 hours or more.
+    pub(crate) fn try_new(date: IsoDate, time: IsoTime, zone: Rfc2822Zone) -> TemporalResult<Self> {
+        if !(0..=9999).contains(&date.year) {
+            return Err(TemporalError::range()
+                .with_message("RFC 2822 dates require a year between 0 and 9999."));
+        }
+        if matches!(zone, Rfc2822Zone::Offset(minutes) if minutes.unsigned_abs() >= 24 * 60) {
+            return Err(
+                TemporalError::range().with_message("RFC 2822 offsets must be below 24 hours.")
+            );
+        }
+        Ok(Self {
+            date,
+            hour: time.hour,
+            minute: time.minute,
+            second: time.second,
+            zone,
+        })
+    }
+}
+
+impl Writeable for FormattableRfc2822 {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        let weekday = self.date.to_icu4x().day_of_week() as usize;
+        sink.write_str(DAY_NAMES.get(weekday - 1).copied().unwrap_or_default())?;
+        sink.write_str(", ")?;
+        write_padded_u8(self.date.day, sink)?;
+        sink.write_char(' ')?;
+        let month = usize::from(self.date.month);
+        sink.write_str(MONTH_NAMES.get(month - 1).copied().unwrap_or_default())?;
+        sink.write_char(' ')?;
+        write_four_digit_year(self.date.year, sink)?;
+        sink.write_char(' ')?;
+        write_padded_u8(self.hour, sink)?;
+        sink.write_char(':')?;
+        write_padded_u8(self.minute, sink)?;
+        sink.write_char(':')?;
+        write_padded_u8(self.second, sink)?;
+        sink.write_char(' ')?;
+        match self.zone {
+            Rfc2822Zone::Gmt => sink.write_str("GMT"),
+            Rfc2822Zone::Offset(minutes) => {
+                sink.write_char(if minutes < 0 { '-' } else { '+' })?;
+                let (hours, minutes) = (minutes.unsigned_abs() / 60, minutes.unsigned_abs() % 60);
+                write_padded_u8(hours as u8, sink)?;
+                write_padded_u8(minutes as u8, sink)
+            }
+        }
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        match self.zone {
+            Rfc2822Zone::Gmt => LengthHint::exact(29),
+            Rfc2822Zone::Offset(_) => LengthHint::exact(31),
+        }
+    }
+}
+
+impl_display_with_writeable!(FormattableRfc2822);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_http_date, parse_rfc2822};
+    use crate::error::ErrorKind;
+
+    #[test]
+    fn rfc2822_syntax_errors() {
+        for (source, position) in [
+            ("Fri 21 Nov 1997 09:55:06 -0600", 4),
+            ("Fri, 21 Nvm 1997 09:55:06 -0600", 8),
+            ("Fri, 21 Nov 1997 09:55 +060", 24),
+            ("Fri, 21 Nov 1997 09:55 -0600 x", 29),
+            ("Fri, 21 Nov 1997 09:55:06 +9959", 27),
+            ("Fri, 21 Nov 1997 09:55 J", 23),
+            ("Fri, 21 Nov 1997 09:55 -0600 (comment", 37),
+            ("Fry, 21 Nov 1997 09:55 -0600", 0),
+        ] {
+            let error = parse_rfc2822(source.as_bytes()).unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::Syntax, "{source}");
+            assert!(
+                error
+                    .message()
+                    .ends_with(&alloc::format!("at position {position}.")),
+                "{source}: {}",
+                error.message()
+            );
+        }
+        let error = parse_rfc2822(b"Sat, 21 Nov 1997 09:55:06 -0600").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Range);
+        let error = parse_rfc2822(b"31 Nov 1997 09:55:06 -0600").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Range);
+    }
+
+    #[test]
+    fn http_date_syntax_errors() {
+        for (source, position) in [
+            ("Sun, 06 Nov 1994 08:49:37 UTC", 26),
+            ("sun, 06 Nov 1994 08:49:37 GMT", 0),
+            ("Sun, 6 Nov 1994 08:49:37 GMT", 5),
+            ("Sun, 06-Nov-94 08:49:37 GMT", 0),
+            ("Sun Nov 6 08:49:37 1994", 8),
+            ("Sun, 06 Nov 1994 08:49 GMT", 22),
+        ] {
+            let error = parse_http_date(source.as_bytes()).unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::Syntax, "{source}");
+            assert!(
+                error
+                    .message()
+                    .ends_with(&alloc::format!("at position {position}.")),
+                "{source}: {}",
+                error.message()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn format_offsets() {
+        use super::{FormattableRfc2822, Rfc2822Zone};
+        use crate::iso::{IsoDate, IsoTime};
+        use alloc::format;
+        use writeable::assert_writeable_eq;
+
+        let date = IsoDate::new_unchecked(1997, 11, 21);
+        let time = IsoTime::new_unchecked(9, 55, 6, 0, 0, 0);
+        let format =
+            |minutes| FormattableRfc2822::try_new(date, time, Rfc2822Zone::Offset(minutes));
+        assert_writeable_eq!(format(-360).unwrap(), "Fri, 21 Nov 1997 09:55:06 -0600");
+        assert_writeable_eq!(
+            format(23 * 60 + 59).unwrap(),
+            "Fri, 21 Nov 1997 09:55:06 +2359"
+        );
+        assert!(format(24 * 60).is_err());
+        assert!(format(i16::MIN).is_err());
+    }
+}
@@ -0,0 +1,265 @@
+//! Strict parsing and formatting of RFC 3339 date-times.
+
+use writeable::{impl_display_with_writeable, LengthHint, Writeable};
+
+use crate::{
+    iso::{IsoDate, IsoTime},
+    options::{ArithmeticOverflow, DisplayOffset},
+    Sign, TemporalError, TemporalResult,
+};
+
+use super::{cursor::Cursor, IxdtfStringBuilder, Precision};
+
+/// The fields of a parsed RFC 3339 date-time.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Rfc3339Record {
+    pub(crate) date: IsoDate,
+    pub(crate) time: IsoTime,
+    /// The UTC offset in minutes, or `None` for `Z` and the unknown local
+    /// offset `-00:00`.
+    pub(crate) offset_minutes: Option<i16>,
+}
+
+/// Parses an RFC 3339 `date-time`, e.g. `1985-04-12T23:20:50.52Z`.
+///
+/// Only the ABNF of RFC 3339 section 5.6 is accepted: a four-digit year,
+/// seconds, fractional digits after a `.` (truncated to nanoseconds), and a
+/// `Z` or `±HH:MM` offset. The `T` and `Z` may be lowercase. Annotations, the
+/// space separator, expanded years and other RFC 9557 extensions are rejected.
+pub(crate) fn parse_rfc3339(source: &[u8]) -> TemporalResult<Rfc3339Record> {
+    let mut cursor = Cursor::new(source, "RFC 3339 date-time");
+    let (year, _) = cursor.digits(4, 4, "a four-digit year")?;
+    cursor.expect(b'-', "'-'")?;
+    let (month, _) = cursor.digits(2, 2, "a two-digit month")?;
+    cursor.expect(b'-', "'-'")?;
+    let (day, _) = cursor.digits(2, 2, "a two-digit day")?;
+    if !matches!(cursor.peek(), Some(b'T' | b't')) {
+        return Err(cursor.error("'T'"));
+    }
+    cursor.pos += 1;
+
+    let (hour, _) = cursor.digits(2, 2, "a two-digit hour")?;
+    cursor.expect(b':', "':'")?;
+    let (minute, _) = cursor.digits(2, 2, "a two-digit minute")?;
+    cursor.expect(b':', "':'")?;
+    let (second, _) = cursor.digits(2, 2, "a two-digit second")?;
+    let nanosecond = if cursor.peek() == Some(b'.') {
+        cursor.pos += 1;
+        // Digits past nanoseconds are truncated.
+        let (fraction, len) = cursor.truncated_digits(9, "fractional digits")?;
+        fraction * 10u32.pow(9 - len as u32)
+    } else {
+        0
+    };
+
+    let offset_minutes = match cursor.peek() {
+        Some(b'Z' | b'z') => {
+            cursor.pos += 1;
+            None
+        }
+        Some(sign @ (b'+' | b'-')) => {
+            cursor.pos += 1;
+            let (hours, _) = cursor.digits(2, 2, "a two-digit offset hour")?;
+            if hours > 23 {
+                cursor.pos -= 2;
+                return Err(cursor.error("offset hours below 24"));
+            }
+            cursor.expect(b':', "':'")?;
+            let (minutes, _) = cursor.digits(2, 2, "a two-digit offset minute")?;
+            if minutes > 59 {
+                cursor.pos -= 2;
+                return Err(cursor.error("offset minutes below 60"));
+            }
+            let offset = (hours * 60 + minutes) as i16;
+            match (sign, offset) {
+                (b'-', 0) => None,
+                (b'-', offset) => Some(-offset),
+                (_, offset) => Some(offset),
+            }
+        }
+        _ => return Err(cursor.error("'Z' or a UTC offset")),
+    };
+    if !cursor.is_done() {
+        return Err(cursor.error("the end of the date-time"));
+    }
+
+    let date = IsoDate::new_with_overflow(
+        year as i32,
+        month as u8,
+        day as u8,
+        ArithmeticOverflow::Reject,
+    )?;
+    // A leap second is constrained to the preceding second, as in RFC 9557 strings.
+    let second = (second as u8).min(59);
+    let time = IsoTime::new(
+        hour as u8,
+        minute as u8,
+        second,
+        (nanosecond / 1_000_000) as u16,
+        (nanosecond / 1_000 % 1_000) as u16,
+        (nanosecond % 1_000) as u16,
+        ArithmeticOverflow::Reject,
+    )?;
+    Ok(Rfc3339Record {
+        date,
+        time,
+        offset_minutes,
+    })
+}
+
+/// A writeable RFC 3339 date-time, e.g. `1985-04-12T23:20:50.52Z`.
+///
+/// This is the RFC 3339 subset of [`IxdtfStringBuilder`]: a four-digit year,
+/// an uppercase `T`, seconds, and a `Z` or minute-precision offset, without
+/// annotations.
+#[derive(Debug)]
+pub struct FormattableRfc3339(IxdtfStringBuilder<'static>);
+
+impl FormattableRfc3339 {
+    /// Creates a new date-time, written with a `Z` when `offset_minutes` is `None`.
+    ///
+    /// Years outside 0000–9999 and [`Precision::Minute`] cannot be represented.
+    pub fn try_new(
+        date: IsoDate,
+        time: IsoTime,
+        offset_minutes: Option<i16>,
+        precision: Precision,
+    ) -> TemporalResult<Self> {
+        if !(0..=9999).contains(&date.year) {
+            return Err(TemporalError::range()
+                .with_message("RFC 3339 date-times require a year from 0000 to 9999."));
+        }
+        if precision == Precision::Minute {
+            return Err(TemporalError::range().with_message("RFC 3339 date-times require seconds."));
+        }
+        let builder = IxdtfStringBuilder::default()
+            .with_date(date)
+            .with_time(time, precision);
+        let builder = match offset_minutes {
+            None => builder.with_z(DisplayOffset::Auto),
+            Some(offset) => {
+                let sign = if offset < 0 {
+                    Sign::Negative
+                } else {
+                    Sign::Positive
+                };
+                let offset = offset.unsigned_abs();
+                if offset >= 24 * 60 {
+                    return Err(TemporalError::range()
+                        .with_message("RFC 3339 offsets must be less than 24 hours."));
+                }
+                builder.with_minute_offset(
+                    sign,
+                    (offset / 60) as u8,
+                    (offset % 60) as u8,
+                    DisplayOffset::Auto,
+                )
+            }
+        };
+        Ok(Self(builder))
+    }
+}
+
+impl Writeable for FormattableRfc3339 {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        self.0.write_to(sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        self.0.writeable_length_hint()
+    }
+}
+
+impl_display_with_writeable!(FormattableRfc3339);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_rfc3339, FormattableRfc3339};
+    use crate::{
+        error::ErrorKind,
+        iso::{IsoDate, IsoTime},
+        options::ArithmeticOverflow,
+        parsers::Precision,
+    };
+
+    #[test]
+    fn strict_grammar() {
+        let record = parse_rfc3339(b"1985-04-12t23:20:50.52z").unwrap();
+        assert_eq!(
+            (record.date.year, record.date.month, record.date.day),
+            (1985, 4, 12)
+        );
+        assert_eq!(record.time.millisecond, 520);
+        assert_eq!(record.offset_minutes, None);
+        let time = parse_rfc3339(b"1985-04-12T23:20:50.1234567891Z")
+            .unwrap()
+            .time;
+        assert_eq!(
+            (time.millisecond, time.microsecond, time.nanosecond),
+            (123, 456, 789)
+        );
+        assert_eq!(
+            parse_rfc3339(b"1996-12-19T16:39:57-08:00")
+                .unwrap()
+                .offset_minutes,
+            Some(-480)
+        );
+        assert_eq!(
+            parse_rfc3339(b"1990-12-31T15:59:60-00:00")
+                .unwrap()
+                .offset_minutes,
+            None
+        );
+
+        for (source, position) in [
+            ("1985-04-12T23:20:50Z[UTC]", 20),
+            ("1985-04-12T23:20:50Z[u-ca=iso8601]", 20),
+            ("1985-04-12 23:20:50Z", 10),
+            ("1985-04-12T23:20Z", 16),
+            ("1985-04-12T23:20:50", 19),
+            ("1985-04-12T23:20:50+0100", 20),
+            ("1985-04-12T23:20:50,5Z", 19),
+            ("1985-04-12T23:20:50.Z", 20),
+            ("+001985-04-12T23:20:50Z", 0),
+            ("19850412T232050Z", 0),
+        ] {
+            let err = parse_rfc3339(source.as_bytes()).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Syntax, "{source}");
+            assert!(
+                err.message()
+                    .ends_with(&alloc::format!("position {position}.")),
+                "{source}: {}",
+                err.message()
+            );
+            assert_eq!(err.diagnostic().unwrap().offset(), position, "{source}");
+        }
+        assert_eq!(
+            parse_rfc3339(b"1985-02-29T00:00:00Z").unwrap_err().kind(),
+            ErrorKind::Range
+        );
+    }
+
+    #[test]
+    fn format_subset() {
+        let date = IsoDate::new_with_overflow(1985, 4, 12, ArithmeticOverflow::Reject).unwrap();
+        let time = IsoTime::new(23, 20, 50, 520, 0, 0, ArithmeticOverflow::Reject).unwrap();
+        let format = |offset, precision| {
+            FormattableRfc3339::try_new(date, time, offset, precision).map(|x| x.to_string())
+        };
+        assert_eq!(
+            format(None, Precision::Auto).unwrap(),
+            "1985-04-12T23:20:50.52Z"
+        );
+        assert_eq!(
+            format(Some(-330), Precision::Digit(0)).unwrap(),
+            "1985-04-12T23:20:50-05:30"
+        );
+        assert_eq!(
+            format(Some(0), Precision::Digit(3)).unwrap(),
+            "1985-04-12T23:20:50.520+00:00"
+        );
+        assert!(format(None, Precision::Minute).is_err());
+        let date = IsoDate::new_with_overflow(10_000, 1, 1, ArithmeticOverflow::Reject).unwrap();
+        assert!(FormattableRfc3339::try_new(date, time, None, Precision::Auto).is_err());
+    }
+}
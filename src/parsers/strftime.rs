@@ -0,0 +1,1016 @@
+//! strftime-style format patterns.
+//!
+//! Patterns are made of literal text and `%` directives. A directive may
+//! start with a padding flag (`-` for none, `_` for spaces, `0` for zeros)
+//! and a width, e.g. `%_3j`. The supported directives are:
+//!
+//! | Directive | Meaning |
+//! | --- | --- |
+//! | `%Y` | Year, at least four digits |
+//! | `%C`, `%y` | Century and two-digit year (`00`–`68` are 20xx when parsing) |
+//! | `%m`, `%B`, `%b`/`%h` | Month number, name and abbreviated name |
+//! | `%d`, `%e` | Day of the month, zero- or space-padded |
+//! | `%j` | Day of the year |
+//! | `%A`, `%a`, `%u`, `%w` | Weekday name, abbreviated name, number from Monday (1) and from Sunday (0) |
+//! | `%H`, `%I`, `%p`, `%P` | Hour, 12-hour clock hour, `AM`/`PM` and `am`/`pm` |
+//! | `%M`, `%S` | Minute and second |
+//! | `%f` | Fractional seconds, nine digits unless a width is given |
+//! | `%z`, `%:z` | UTC offset as `+hhmm` or `+hh:mm` |
+//! | `%Z` | Time zone identifier |
+//! | `%F`, `%T`, `%D`, `%R` | `%Y-%m-%d`, `%H:%M:%S`, `%m/%d/%y` and `%H:%M` |
+//! | `%%`, `%n`, `%t` | `%`, newline and tab (any white space when parsing) |
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+use writeable::{impl_display_with_writeable, Writeable};
+
+use crate::{
+    builtins::core::calendar::CalendarFields,
+    iso::{IsoDate, IsoTime},
+    options::ArithmeticOverflow,
+    partial::PartialTime,
+    utils, Calendar, TemporalError, TemporalResult, UtcOffset,
+};
+
+use super::cursor::Cursor;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const DAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Padding {
+    Default,
+    None,
+    Space,
+    Zero,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Directive {
+    Year,
+    Century,
+    YearOfCentury,
+    Month,
+    MonthName { abbreviated: bool },
+    Day,
+    DaySpacePadded,
+    DayOfYear,
+    WeekdayName { abbreviated: bool },
+    WeekdayFromMonday,
+    WeekdayFromSunday,
+    Hour,
+    Hour12,
+    Meridiem { lowercase: bool },
+    Minute,
+    Second,
+    Fraction,
+    Offset { colon: bool },
+    TimeZone,
+    Percent,
+    Whitespace(char),
+}
+
+/// The part of a date-time that a directive reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Component {
+    Date,
+    Time,
+    Offset,
+    TimeZone,
+}
+
+impl Directive {
+    fn component(self) -> Option<Component> {
+        match self {
+            Self::Year
+            | Self::Century
+            | Self::YearOfCentury
+            | Self::Month
+            | Self::MonthName { .. }
+            | Self::Day
+            | Self::DaySpacePadded
+            | Self::DayOfYear
+            | Self::WeekdayName { .. }
+            | Self::WeekdayFromMonday
+            | Self::WeekdayFromSunday => Some(Component::Date),
+            Self::Hour
+            | Self::Hour12
+            | Self::Meridiem { .. }
+            | Self::Minute
+            | Self::Second
+            | Self::Fraction => Some(Component::Time),
+            Self::Offset { .. } => Some(Component::Offset),
+            Self::TimeZone => Some(Component::TimeZone),
+            Self::Percent | Self::Whitespace(_) => None,
+        }
+    }
+
+    /// The natural width and padding of a numeric directive.
+    fn numeric_width(self) -> Option<(usize, char)> {
+        match self {
+            Self::Year => Some((4, '0')),
+            Self::DayOfYear => Some((3, '0')),
+            Self::DaySpacePadded => Some((2, ' ')),
+            Self::Century
+            | Self::YearOfCentury
+            | Self::Month
+            | Self::Day
+            | Self::Hour
+            | Self::Hour12
+            | Self::Minute
+            | Self::Second => Some((2, '0')),
+            Self::WeekdayFromMonday | Self::WeekdayFromSunday => Some((1, '0')),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Item<'a> {
+    Literal(&'a str),
+    Directive {
+        directive: Directive,
+        padding: Padding,
+        width: Option<usize>,
+    },
+}
+
+const fn directive(directive: Directive) -> Item<'static> {
+    Item::Directive {
+        directive,
+        padding: Padding::Default,
+        width: None,
+    }
+}
+
+const DATE_ITEMS: [Item<'static>; 5] = [
+    directive(Directive::Year),
+    Item::Literal("-"),
+    directive(Directive::Month),
+    Item::Literal("-"),
+    directive(Directive::Day),
+];
+const US_DATE_ITEMS: [Item<'static>; 5] = [
+    directive(Directive::Month),
+    Item::Literal("/"),
+    directive(Directive::Day),
+    Item::Literal("/"),
+    directive(Directive::YearOfCentury),
+];
+const TIME_ITEMS: [Item<'static>; 5] = [
+    directive(Directive::Hour),
+    Item::Literal(":"),
+    directive(Directive::Minute),
+    Item::Literal(":"),
+    directive(Directive::Second),
+];
+
+/// Splits a pattern into literals and directives.
+fn parse_pattern(pattern: &str) -> TemporalResult<Vec<Item<'_>>> {
+    let mut cursor = Cursor::new(pattern.as_bytes(), "format pattern");
+    let mut items = Vec::new();
+    let mut literal_start = 0;
+    while let Some(byte) = cursor.peek() {
+        if byte != b'%' {
+            cursor.pos += 1;
+            continue;
+        }
+        if let Some(literal) = pattern
+            .get(literal_start..cursor.pos)
+            .filter(|s| !s.is_empty())
+        {
+            items.push(Item::Literal(literal));
+        }
+        cursor.pos += 1;
+
+        let padding = match cursor.peek() {
+            Some(b'-') => Padding::None,
+            Some(b'_') => Padding::Space,
+            Some(b'0') => Padding::Zero,
+            _ => Padding::Default,
+        };
+        if padding != Padding::Default {
+            cursor.pos += 1;
+        }
+        let width = if cursor.peek().is_some_and(|b| b.is_ascii_digit()) {
+            let (width, _) = cursor.digits(1, 2, "a width of at most two digits")?;
+            Some(width as usize)
+        } else {
+            None
+        };
+        let colon = cursor.peek() == Some(b':');
+        if colon {
+            cursor.pos += 1;
+        }
+        let directive_start = cursor.pos;
+        let directive = match (cursor.peek(), colon) {
+            (Some(b'z'), colon) => Directive::Offset { colon },
+            (_, true) => return Err(cursor.error("'z' after '%:'")),
+            (Some(b'Y'), _) => Directive::Year,
+            (Some(b'C'), _) => Directive::Century,
+            (Some(b'y'), _) => Directive::YearOfCentury,
+            (Some(b'm'), _) => Directive::Month,
+            (Some(b'B'), _) => Directive::MonthName { abbreviated: false },
+            (Some(b'b' | b'h'), _) => Directive::MonthName { abbreviated: true },
+            (Some(b'd'), _) => Directive::Day,
+            (Some(b'e'), _) => Directive::DaySpacePadded,
+            (Some(b'j'), _) => Directive::DayOfYear,
+            (Some(b'A'), _) => Directive::WeekdayName { abbreviated: false },
+            (Some(b'a'), _) => Directive::WeekdayName { abbreviated: true },
+            (Some(b'u'), _) => Directive::WeekdayFromMonday,
+            (Some(b'w'), _) => Directive::WeekdayFromSunday,
+            (Some(b'H'), _) => Directive::Hour,
+            (Some(b'I'), _) => Directive::Hour12,
+            (Some(b'p'), _) => Directive::Meridiem { lowercase: false },
+            (Some(b'P'), _) => Directive::Meridiem { lowercase: true },
+            (Some(b'M'), _) => Directive::Minute,
+            (Some(b'S'), _) => Directive::Second,
+            (Some(b'f'), _) => Directive::Fraction,
+            (Some(b'Z'), _) => Directive::TimeZone,
+            (Some(b'%'), _) => Directive::Percent,
+            (Some(b'n'), _) => Directive::Whitespace('\n'),
+            (Some(b't'), _) => Directive::Whitespace('\t'),
+            (Some(composite @ (b'F' | b'T' | b'D' | b'R')), _) => {
+                if padding != Padding::Default || width.is_some() {
+                    return Err(cursor.error("a directive that accepts flags"));
+                }
+                match composite {
+                    b'F' => items.extend_from_slice(&DATE_ITEMS),
+                    b'T' => items.extend_from_slice(&TIME_ITEMS),
+                    b'D' => items.extend_from_slice(&US_DATE_ITEMS),
+                    _ => items.extend_from_slice(TIME_ITEMS.get(..3).unwrap_or_default()),
+                }
+                cursor.pos += 1;
+                literal_start = cursor.pos;
+                continue;
+            }
+            _ => return Err(cursor.error("a directive")),
+        };
+        let flags_allowed = match directive {
+            Directive::Fraction => {
+                padding == Padding::Default && width.is_none_or(|w| (1..=9).contains(&w))
+            }
+            directive => {
+                directive.numeric_width().is_some()
+                    || (padding == Padding::Default && width.is_none())
+            }
+        };
+        if !flags_allowed {
+            cursor.pos = directive_start;
+            return Err(cursor.error("a directive that accepts the given flags"));
+        }
+        items.push(Item::Directive {
+            directive,
+            padding,
+            width,
+        });
+        cursor.pos += 1;
+        literal_start = cursor.pos;
+    }
+    if let Some(literal) = pattern.get(literal_start..).filter(|s| !s.is_empty()) {
+        items.push(Item::Literal(literal));
+    }
+    Ok(items)
+}
+
+// ==== Formatting ====
+
+/// A writeable date-time formatted with a strftime-style pattern.
+///
+/// Fields are written in the ISO 8601 calendar, with English month and
+/// weekday names.
+#[derive(Debug)]
+pub struct FormattableStrftime<'a> {
+    items: Vec<Item<'a>>,
+    date: Option<IsoDate>,
+    time: Option<IsoTime>,
+    offset: Option<UtcOffset>,
+    time_zone: Option<String>,
+}
+
+impl<'a> FormattableStrftime<'a> {
+    /// Parses `pattern`, rejecting directives for fields that are not provided.
+    pub(crate) fn try_new(
+        pattern: &'a str,
+        date: Option<IsoDate>,
+        time: Option<IsoTime>,
+        offset: Option<UtcOffset>,
+        time_zone: Option<String>,
+    ) -> TemporalResult<Self> {
+        let items = parse_pattern(pattern)?;
+        for item in &items {
+            let Item::Directive { directive, .. } = item else {
+                continue;
+            };
+            let (available, name) = match directive.component() {
+                Some(Component::Date) => (date.is_some(), "date"),
+                Some(Component::Time) => (time.is_some(), "time"),
+                Some(Component::Offset) => (offset.is_some(), "UTC offset"),
+                Some(Component::TimeZone) => (time_zone.is_some(), "time zone"),
+                None => (true, ""),
+            };
+            if !available {
+                return Err(TemporalError::r#type().with_message(alloc::format!(
+                    "The format pattern requires a {name}, which this value does not have."
+                )));
+            }
+        }
+        Ok(Self {
+            items,
+            date,
+            time,
+            offset,
+            time_zone,
+        })
+    }
+}
+
+fn write_number<W: Write + ?Sized>(
+    sink: &mut W,
+    value: i64,
+    width: usize,
+    pad: Option<char>,
+) -> core::fmt::Result {
+    if value < 0 {
+        sink.write_char('-')?;
+    }
+    let value = value.unsigned_abs();
+    if let Some(pad) = pad {
+        let digits = value.checked_ilog10().unwrap_or(0) as usize + 1;
+        for _ in digits..width {
+            sink.write_char(pad)?;
+        }
+    }
+    write!(sink, "{value}")
+}
+
+fn write_offset<W: Write + ?Sized>(
+    sink: &mut W,
+    offset: UtcOffset,
+    colon: bool,
+) -> core::fmt::Result {
+    let seconds = offset.nanoseconds() / 1_000_000_000;
+    sink.write_char(if seconds < 0 { '-' } else { '+' })?;
+    let seconds = seconds.abs();
+    let separator = if colon { ":" } else { "" };
+    write!(
+        sink,
+        "{:02}{separator}{:02}",
+        seconds / 3600,
+        seconds / 60 % 60
+    )?;
+    if seconds % 60 != 0 {
+        write!(sink, "{separator}{:02}", seconds % 60)?;
+    }
+    Ok(())
+}
+
+fn abbreviation(name: &str) -> &str {
+    name.get(..3).unwrap_or(name)
+}
+
+impl Writeable for FormattableStrftime<'_> {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        // `try_new` checked that every directive has its component.
+        let date = self.date.unwrap_or_default();
+        let time = self.time.unwrap_or_default();
+        for item in &self.items {
+            let (directive, padding, width) = match *item {
+                Item::Literal(literal) => {
+                    sink.write_str(literal)?;
+                    continue;
+                }
+                Item::Directive {
+                    directive,
+                    padding,
+                    width,
+                } => (directive, padding, width),
+            };
+            let weekday = || date.to_icu4x().day_of_week() as u8;
+            let value = match directive {
+                Directive::Year => i64::from(date.year),
+                Directive::Century => i64::from(date.year.div_euclid(100)),
+                Directive::YearOfCentury => i64::from(date.year.rem_euclid(100)),
+                Directive::Month => i64::from(date.month),
+                Directive::Day | Directive::DaySpacePadded => i64::from(date.day),
+                Directive::DayOfYear => i64::from(Calendar::ISO.day_of_year(&date)),
+                Directive::WeekdayFromMonday => i64::from(weekday()),
+                Directive::WeekdayFromSunday => i64::from(weekday() % 7),
+                Directive::Hour => i64::from(time.hour),
+                Directive::Hour12 => i64::from((time.hour + 11) % 12 + 1),
+                Directive::Minute => i64::from(time.minute),
+                Directive::Second => i64::from(time.second),
+                Directive::MonthName { abbreviated } => {
+                    let name = MONTH_NAMES
+                        .get(usize::from(date.month) - 1)
+                        .copied()
+                        .unwrap_or_default();
+                    sink.write_str(if abbreviated {
+                        abbreviation(name)
+                    } else {
+                        name
+                    })?;
+                    continue;
+                }
+                Directive::WeekdayName { abbreviated } => {
+                    let name = DAY_NAMES
+                        .get(usize::from(weekday()) - 1)
+                        .copied()
+                        .unwrap_or_default();
+                    sink.write_str(if abbreviated {
+                        abbreviation(name)
+                    } else {
+                        name
+                    })?;
+                    continue;
+                }
+                Directive::Meridiem { lowercase } => {
+                    let meridiem = match (time.hour < 12, lowercase) {
+                        (true, false) => "AM",
+                        (false, false) => "PM",
+                        (true, true) => "am",
+                        (false, true) => "pm",
+                    };
+                    sink.write_str(meridiem)?;
+                    continue;
+                }
+                Directive::Fraction => {
+                    let digits = width.unwrap_or(9);
+                    let nanoseconds = u32::from(time.millisecond) * 1_000_000
+                        + u32::from(time.microsecond) * 1_000
+                        + u32::from(time.nanosecond);
+                    let value = nanoseconds / 10u32.pow(9 - digits as u32);
+                    write_number(sink, i64::from(value), digits, Some('0'))?;
+                    continue;
+                }
+                Directive::Offset { colon } => {
+                    write_offset(
+                        sink,
+                        self.offset.unwrap_or(UtcOffset::from_minutes(0)),
+                        colon,
+                    )?;
+                    continue;
+                }
+                Directive::TimeZone => {
+                    sink.write_str(self.time_zone.as_deref().unwrap_or_default())?;
+                    continue;
+                }
+                Directive::Percent => {
+                    sink.write_char('%')?;
+                    continue;
+                }
+                Directive::Whitespace(c) => {
+                    sink.write_char(c)?;
+                    continue;
+                }
+            };
+            let (natural_width, default_pad) = directive.numeric_width().unwrap_or((1, '0'));
+            let pad = match padding {
+                Padding::Default => Some(default_pad),
+                Padding::None => None,
+                Padding::Space => Some(' '),
+                Padding::Zero => Some('0'),
+            };
+            write_number(sink, value, width.unwrap_or(natural_width), pad)?;
+        }
+        Ok(())
+    }
+}
+
+impl_display_with_writeable!(FormattableStrftime<'_>);
+
+// ==== Parsing ====
+
+/// The fields read from a source by a strftime-style pattern.
+#[derive(Debug, Default)]
+pub(crate) struct StrptimeRecord<'a> {
+    pub(crate) calendar_fields: CalendarFields,
+    pub(crate) time: PartialTime,
+    pub(crate) offset: Option<UtcOffset>,
+    pub(crate) time_zone: Option<&'a str>,
+}
+
+/// The raw directive values, before they are combined into fields.
+#[derive(Debug, Default)]
+struct ParsedValues {
+    year: Option<i32>,
+    century: Option<i32>,
+    year_of_century: Option<i32>,
+    month: Option<u8>,
+    day: Option<u8>,
+    day_of_year: Option<u16>,
+    weekday: Option<u8>,
+    hour: Option<u8>,
+    hour12: Option<u8>,
+    pm: Option<bool>,
+    minute: Option<u8>,
+    second: Option<u8>,
+    nanosecond: Option<u32>,
+    offset: Option<UtcOffset>,
+}
+
+/// Sets a field, rejecting a second directive that disagrees with the first.
+fn set<T: PartialEq>(field: &mut Option<T>, value: T) -> TemporalResult<()> {
+    if field.as_ref().is_some_and(|f| *f != value) {
+        return Err(TemporalError::range()
+            .with_message("The input has conflicting values for the same field."));
+    }
+    *field = Some(value);
+    Ok(())
+}
+
+fn parse_name(
+    cursor: &mut Cursor<'_>,
+    names: &[&str],
+    expected: &'static str,
+) -> TemporalResult<u8> {
+    let start = cursor.pos;
+    let word = cursor.alpha();
+    names
+        .iter()
+        .position(|name| {
+            name.as_bytes().eq_ignore_ascii_case(word)
+                || abbreviation(name).as_bytes().eq_ignore_ascii_case(word)
+        })
+        .map(|i| i as u8 + 1)
+        .ok_or_else(|| {
+            cursor.pos = start;
+            cursor.error(expected)
+        })
+}
+
+/// Reads between `min` and `max` digits, stopping at `max` so that adjacent
+/// directives like `%Y%m%d` can be parsed.
+fn take_digits(
+    cursor: &mut Cursor<'_>,
+    min: usize,
+    max: usize,
+    expected: &'static str,
+) -> TemporalResult<u32> {
+    let start = cursor.pos;
+    let mut value = 0u32;
+    while let Some(digit) = cursor
+        .peek()
+        .filter(|b| cursor.pos - start < max && b.is_ascii_digit())
+    {
+        let Some(next) = value
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(u32::from(digit - b'0')))
+        else {
+            cursor.pos = start;
+            return Err(cursor.range_error(expected));
+        };
+        value = next;
+        cursor.pos += 1;
+    }
+    if cursor.pos - start < min {
+        cursor.pos = start;
+        return Err(cursor.error(expected));
+    }
+    Ok(value)
+}
+
+/// Narrows a value read at `start`, rejecting values that overflow the field.
+fn narrow<T: TryFrom<u32>>(
+    cursor: &mut Cursor<'_>,
+    start: usize,
+    value: u32,
+    expected: &'static str,
+) -> TemporalResult<T> {
+    T::try_from(value).map_err(|_| {
+        cursor.pos = start;
+        cursor.range_error(expected)
+    })
+}
+
+fn parse_offset(cursor: &mut Cursor<'_>, colon: bool) -> TemporalResult<UtcOffset> {
+    let sign = match cursor.peek() {
+        Some(b'Z' | b'z') => {
+            cursor.pos += 1;
+            return Ok(UtcOffset::from_minutes(0));
+        }
+        Some(b'+') => 1,
+        Some(b'-') => -1,
+        _ => return Err(cursor.error("a UTC offset")),
+    };
+    cursor.pos += 1;
+    let hours = take_digits(cursor, 2, 2, "a two-digit offset hour")?;
+    // `%z` accepts an optional colon, `%:z` requires one.
+    let separator = |cursor: &mut Cursor<'_>| {
+        if colon {
+            cursor.expect(b':', "':'")
+        } else {
+            if cursor.peek() == Some(b':') {
+                cursor.pos += 1;
+            }
+            Ok(())
+        }
+    };
+    separator(cursor)?;
+    let minutes = take_digits(cursor, 2, 2, "a two-digit offset minute")?;
+    let has_seconds = match cursor.peek() {
+        Some(b':') => true,
+        Some(b) => !colon && b.is_ascii_digit(),
+        None => false,
+    };
+    let seconds = if has_seconds {
+        separator(cursor)?;
+        take_digits(cursor, 2, 2, "two-digit offset seconds")?
+    } else {
+        0
+    };
+    if hours > 23 || minutes > 59 || seconds > 59 {
+        return Err(TemporalError::range().with_message("The UTC offset is out of range."));
+    }
+    Ok(UtcOffset::from_seconds(
+        sign * i64::from(hours * 3600 + minutes * 60 + seconds),
+    ))
+}
+
+/// Parses `source` with a strftime-style pattern.
+///
+/// Numeric fields accept between one digit and their width, names match
+/// case-insensitively, and `%j`, `%C`/`%y`, `%I`/`%p` and the weekday are
+/// combined into the calendar and time fields.
+pub(crate) fn parse_strptime<'a>(
+    source: &'a str,
+    pattern: &str,
+) -> TemporalResult<StrptimeRecord<'a>> {
+    let items = parse_pattern(pattern)?;
+    let mut cursor = Cursor::new(source.as_bytes(), "formatted date-time");
+    let mut values = ParsedValues::default();
+    let mut time_zone = None;
+
+    for item in items {
+        let (directive, padding, width) = match item {
+            Item::Literal(literal) => {
+                for &byte in literal.as_bytes() {
+                    cursor.expect(byte, "the pattern literal").map_err(|e| {
+                        e.with_message(alloc::format!(
+                            "Invalid formatted date-time: expected '{literal}' at position {}.",
+                            cursor.pos
+                        ))
+                    })?;
+                }
+                continue;
+            }
+            Item::Directive {
+                directive,
+                padding,
+                width,
+            } => (directive, padding, width),
+        };
+        if let Some((natural_width, default_pad)) = directive.numeric_width() {
+            if padding == Padding::Space || (padding == Padding::Default && default_pad == ' ') {
+                while cursor.peek() == Some(b' ') {
+                    cursor.pos += 1;
+                }
+            }
+            let max = width.unwrap_or(natural_width);
+            if directive == Directive::Year {
+                let sign = match cursor.peek() {
+                    Some(b'-') => -1,
+                    Some(b'+') => 1,
+                    _ => 0,
+                };
+                if sign != 0 {
+                    cursor.pos += 1;
+                }
+                let max = if sign == 0 { max } else { width.unwrap_or(6) };
+                let start = cursor.pos;
+                let year = take_digits(&mut cursor, 1, max, "a year")?;
+                let year: i32 = narrow(&mut cursor, start, year, "a year")?;
+                set(&mut values.year, if sign < 0 { -year } else { year })?;
+                continue;
+            }
+            let start = cursor.pos;
+            let value = take_digits(&mut cursor, 1, max, "a number")?;
+            let out_of_range = |cursor: &mut Cursor<'_>, expected: &'static str| {
+                cursor.pos = start;
+                Err(cursor.error(expected))
+            };
+            match directive {
+                Directive::Century => {
+                    let century = narrow(&mut cursor, start, value, "a century")?;
+                    set(&mut values.century, century)?
+                }
+                Directive::YearOfCentury => {
+                    let year = narrow(&mut cursor, start, value, "a year of the century")?;
+                    set(&mut values.year_of_century, year)?
+                }
+                Directive::Month => {
+                    let month = narrow(&mut cursor, start, value, "a month")?;
+                    set(&mut values.month, month)?
+                }
+                Directive::Day | Directive::DaySpacePadded => {
+                    let day = narrow(&mut cursor, start, value, "a day")?;
+                    set(&mut values.day, day)?
+                }
+                Directive::DayOfYear => {
+                    let day = narrow(&mut cursor, start, value, "a day of the year")?;
+                    set(&mut values.day_of_year, day)?
+                }
+                Directive::WeekdayFromMonday if (1..=7).contains(&value) => {
+                    set(&mut values.weekday, value as u8)?
+                }
+                Directive::WeekdayFromSunday if value <= 6 => set(
+                    &mut values.weekday,
+                    if value == 0 { 7 } else { value as u8 },
+                )?,
+                Directive::WeekdayFromMonday | Directive::WeekdayFromSunday => {
+                    return out_of_range(&mut cursor, "a weekday number")
+                }
+                Directive::Hour => {
+                    let hour = narrow(&mut cursor, start, value, "an hour")?;
+                    set(&mut values.hour, hour)?
+                }
+                Directive::Hour12 if (1..=12).contains(&value) => {
+                    set(&mut values.hour12, value as u8)?
+                }
+                Directive::Hour12 => return out_of_range(&mut cursor, "an hour from 1 to 12"),
+                Directive::Minute => {
+                    let minute = narrow(&mut cursor, start, value, "a minute")?;
+                    set(&mut values.minute, minute)?
+                }
+                _ => {
+                    let second = narrow(&mut cursor, start, value, "a second")?;
+                    set(&mut values.second, second)?
+                }
+            }
+            continue;
+        }
+        match directive {
+            Directive::MonthName { .. } => {
+                let month = parse_name(&mut cursor, &MONTH_NAMES, "a month name")?;
+                set(&mut values.month, month)?;
+            }
+            Directive::WeekdayName { .. } => {
+                let weekday = parse_name(&mut cursor, &DAY_NAMES, "a weekday name")?;
+                set(&mut values.weekday, weekday)?;
+            }
+            Directive::Meridiem { .. } => {
+                let start = cursor.pos;
+                let pm = match cursor.alpha().to_ascii_uppercase().as_slice() {
+                    b"AM" => false,
+                    b"PM" => true,
+                    _ => {
+                        cursor.pos = start;
+                        return Err(cursor.error("'AM' or 'PM'"));
+                    }
+                };
+                set(&mut values.pm, pm)?;
+            }
+            Directive::Fraction => {
+                let start = cursor.pos;
+                let value = take_digits(&mut cursor, 1, width.unwrap_or(9), "fractional seconds")?;
+                let digits = (cursor.pos - start) as u32;
+                set(&mut values.nanosecond, value * 10u32.pow(9 - digits))?;
+            }
+            Directive::Offset { colon } => {
+                let offset = parse_offset(&mut cursor, colon)?;
+                set(&mut values.offset, offset)?;
+            }
+            Directive::TimeZone => {
+                let start = cursor.pos;
+                while cursor.peek().is_some_and(|b| {
+                    b.is_ascii_alphanumeric() || matches!(b, b'/' | b'_' | b'-' | b'+' | b':')
+                }) {
+                    cursor.pos += 1;
+                }
+                let id = source.get(start..cursor.pos).filter(|id| !id.is_empty());
+                let Some(id) = id else {
+                    return Err(cursor.error("a time zone identifier"));
+                };
+                set(&mut time_zone, id)?;
+            }
+            Directive::Percent => cursor.expect(b'%', "'%'")?,
+            Directive::Whitespace(_) => {
+                while cursor.peek().is_some_and(|b| b.is_ascii_whitespace()) {
+                    cursor.pos += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    if !cursor.is_done() {
+        return Err(cursor.error("the end of the input"));
+    }
+
+    let mut record = values.resolve()?;
+    record.time_zone = time_zone;
+    Ok(record)
+}
+
+impl ParsedValues {
+    /// Combines the directive values into calendar and time fields.
+    fn resolve<'a>(mut self) -> TemporalResult<StrptimeRecord<'a>> {
+        match (self.century, self.year_of_century) {
+            (Some(century), Some(year)) => set(&mut self.year, century * 100 + year)?,
+            (None, Some(year)) if year < 69 => set(&mut self.year, 2000 + year)?,
+            (None, Some(year)) => set(&mut self.year, 1900 + year)?,
+            (Some(_), None) => {
+                return Err(TemporalError::range()
+                    .with_message("A century requires a two-digit year (%y)."));
+            }
+            (None, None) => {}
+        }
+
+        if let Some(day_of_year) = self.day_of_year {
+            let Some(year) = self.year else {
+                return Err(
+                    TemporalError::range().with_message("A day of the year requires a year.")
+                );
+            };
+            let days_in_year = if utils::is_leap(year) { 366 } else { 365 };
+            if !(1..=days_in_year).contains(&day_of_year) {
+                return Err(
+                    TemporalError::range().with_message("The day of the year is out of range.")
+                );
+            }
+            let date = IsoDate::balance(year, 1, i32::from(day_of_year));
+            set(&mut self.month, date.month)?;
+            set(&mut self.day, date.day)?;
+        }
+
+        if let (Some(year), Some(month), Some(day), Some(weekday)) =
+            (self.year, self.month, self.day, self.weekday)
+        {
+            let date = IsoDate::new_with_overflow(year, month, day, ArithmeticOverflow::Reject)?;
+            if date.to_icu4x().day_of_week() as u8 != weekday {
+                return Err(TemporalError::range()
+                    .with_message("The day of the week does not match the date."));
+            }
+        }
+
+        if let Some(hour12) = self.hour12 {
+            let pm = self.pm.unwrap_or(false);
+            set(&mut self.hour, hour12 % 12 + if pm { 12 } else { 0 })?;
+        } else if let (Some(pm), Some(hour)) = (self.pm, self.hour) {
+            if pm != (hour >= 12) {
+                return Err(TemporalError::range().with_message("The hour does not match AM/PM."));
+            }
+        }
+
+        let nanosecond = self.nanosecond;
+        Ok(StrptimeRecord {
+            calendar_fields: CalendarFields {
+                year: self.year,
+                month: self.month,
+                day: self.day,
+                ..CalendarFields::new()
+            },
+            time: PartialTime {
+                hour: self.hour,
+                minute: self.minute,
+                second: self.second,
+                millisecond: nanosecond.map(|ns| (ns / 1_000_000) as u16),
+                microsecond: nanosecond.map(|ns| (ns / 1_000 % 1_000) as u16),
+                nanosecond: nanosecond.map(|ns| (ns % 1_000) as u16),
+            },
+            offset: self.offset,
+            time_zone: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use crate::{
+        error::ErrorKind,
+        partial::{PartialDateTime, PartialTime},
+        PlainDate, PlainDateTime, PlainTime,
+    };
+
+    #[test]
+    fn format_directives() {
+        let dt = PlainDateTime::from_str("2024-03-05T07:08:09.012345678").unwrap();
+        for (pattern, expected) in [
+            ("%d/%m/%Y %H:%M", "05/03/2024 07:08"),
+            ("%F %T.%f", "2024-03-05 07:08:09.012345678"),
+            ("%D %R", "03/05/24 07:08"),
+            ("%A %a %B %b %h", "Tuesday Tue March Mar Mar"),
+            ("%e|%-d|%_m|%03e|%-j|%j", " 5|5| 3|005|65|065"),
+            ("%I:%M %p %P", "07:08 AM am"),
+            ("%u %w %C %y", "2 2 20 24"),
+            ("%3f %6f %1f", "012 012345 0"),
+            ("100%% %n%t", "100% \n\t"),
+        ] {
+            assert_eq!(
+                dt.to_strftime_string(pattern).unwrap(),
+                expected,
+                "{pattern}"
+            );
+        }
+
+        let date = PlainDate::from_str("-000044-03-15").unwrap();
+        assert_eq!(date.to_strftime_string("%Y %C %y").unwrap(), "-0044 -01 56");
+        let time = PlainTime::from_str("00:30").unwrap();
+        assert_eq!(time.to_strftime_string("%I %p").unwrap(), "12 AM");
+
+        assert_eq!(
+            date.to_strftime_string("%H").unwrap_err().kind(),
+            ErrorKind::Type
+        );
+        assert_eq!(
+            dt.to_strftime_string("%z").unwrap_err().kind(),
+            ErrorKind::Type
+        );
+        for pattern in ["%q", "%", "%-B", "%0f", "%10f", "%:m", "%_F"] {
+            assert_eq!(
+                dt.to_strftime_string(pattern).unwrap_err().kind(),
+                ErrorKind::Syntax,
+                "{pattern}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_directives() {
+        let parsed = PartialDateTime::from_strptime("05/03/2024 07:08", "%d/%m/%Y %H:%M").unwrap();
+        assert_eq!(
+            PlainDateTime::from_partial(parsed, None).unwrap(),
+            PlainDateTime::from_str("2024-03-05T07:08").unwrap()
+        );
+
+        for (source, pattern) in [
+            ("20240305070809", "%Y%m%d%H%M%S"),
+            ("tuesday, MAR  5 24 7:08:09 pm", "%A, %b %e %y %I:%M:%S %p"),
+            ("2024-065 19:08:09", "%Y-%j %T"),
+            ("Tue 2024 3 5 19.08.09", "%a %Y %-m %-d %H.%M.%S"),
+        ] {
+            let parsed = PartialDateTime::from_strptime(source, pattern).unwrap();
+            let hour = parsed.fields.time.hour.unwrap();
+            assert_eq!(
+                (
+                    parsed.fields.calendar_fields.year,
+                    parsed.fields.calendar_fields.month,
+                    parsed.fields.calendar_fields.day,
+                    hour % 12
+                ),
+                (Some(2024), Some(3), Some(5), 7),
+                "{source}"
+            );
+        }
+
+        let parsed = PartialDateTime::from_strptime("12:00:01.5", "%T.%f").unwrap();
+        assert_eq!(
+            parsed.fields.time,
+            PartialTime::new()
+                .with_hour(Some(12))
+                .with_minute(Some(0))
+                .with_second(Some(1))
+                .with_millisecond(Some(500))
+                .with_microsecond(Some(0))
+                .with_nanosecond(Some(0))
+        );
+        let parsed = PartialDateTime::from_strptime("69 12", "%y %C").unwrap();
+        assert_eq!(parsed.fields.calendar_fields.year, Some(1269));
+        let parsed = PartialDateTime::from_strptime("69", "%y").unwrap();
+        assert_eq!(parsed.fields.calendar_fields.year, Some(1969));
+
+        let err = PartialDateTime::from_strptime("2024-Mrz-05", "%Y-%b-%d").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Syntax);
+        assert!(err.message().ends_with("position 5."), "{}", err.message());
+        for (source, pattern) in [("99999999999", "%12Y"), ("2024-257-05", "%Y-%3m-%d")] {
+            let err = PartialDateTime::from_strptime(source, pattern).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Range, "{source}");
+        }
+        for (source, pattern) in [
+            ("Mon 2024-03-05", "%a %F"),
+            ("13 PM", "%I %p"),
+            ("10 PM", "%H %p"),
+            ("2023-366", "%Y-%j"),
+            ("2024-03-05 +0100", "%F %z"),
+            ("2024-257-05", "%Y-%3m-%d"),
+            ("2024-03-261", "%Y-%m-%3d"),
+        ] {
+            assert!(
+                PartialDateTime::from_strptime(source, pattern).is_err(),
+                "{source}"
+            );
+        }
+    }
+}
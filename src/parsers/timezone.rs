@@ -1,34 +1,43 @@
 use ixdtf::{
-    encoding::Utf8,
     parsers::{IxdtfParser, TimeZoneParser},
     records::{TimeZoneRecord, UtcOffsetRecord, UtcOffsetRecordOrZ},
+    ParseError,
 };
 
 use crate::provider::TimeZoneProvider;
 use crate::{builtins::timezone::UtcOffset, TemporalError, TemporalResult, TimeZone};
 
-use super::{parse_ixdtf, ParseVariant};
+use super::{
+    ixdtf_error, parse_ixdtf_with, Encoded, ParseRecord, ParseVariant, TimeZoneIdentifier,
+};
 
 #[inline]
 pub(crate) fn parse_allowed_timezone_formats(
-    s: &str,
+    source: Encoded<'_>,
     provider: &impl TimeZoneProvider,
 ) -> Option<TimeZone> {
+    let parse_time = || match source {
+        Encoded::Utf8(s) => IxdtfParser::from_utf8(s)
+            .parse_time()
+            .ok()
+            .and_then(|r| ParseRecord::try_from(r).ok()),
+        Encoded::Utf16(s) => IxdtfParser::from_utf16(s)
+            .parse_time()
+            .ok()
+            .and_then(|r| ParseRecord::try_from(r).ok()),
+    };
     let (offset, annotation) = if let Ok((offset, annotation)) =
-        parse_ixdtf(s.as_bytes(), ParseVariant::DateTime).map(|r| (r.offset, r.tz))
+        parse_ixdtf_with(source, ParseVariant::DateTime, false).map(|r| (r.offset, r.tz))
     {
         (offset, annotation)
-    } else if let Ok((offset, annotation)) = IxdtfParser::from_str(s)
-        .parse_time()
-        .map(|r| (r.offset, r.tz))
-    {
+    } else if let Some((offset, annotation)) = parse_time().map(|r| (r.offset, r.tz)) {
         (offset, annotation)
     } else if let Ok((offset, annotation)) =
-        parse_ixdtf(s.as_bytes(), ParseVariant::YearMonth).map(|r| (r.offset, r.tz))
+        parse_ixdtf_with(source, ParseVariant::YearMonth, false).map(|r| (r.offset, r.tz))
     {
         (offset, annotation)
     } else if let Ok((offset, annotation)) =
-        parse_ixdtf(s.as_bytes(), ParseVariant::MonthDay).map(|r| (r.offset, r.tz))
+        parse_ixdtf_with(source, ParseVariant::MonthDay, false).map(|r| (r.offset, r.tz))
     {
         (offset, annotation)
     } else {
@@ -36,7 +45,7 @@ pub(crate) fn parse_allowed_timezone_formats(
     };
 
     if let Some(annotation) = annotation {
-        return TimeZone::from_time_zone_record(annotation.tz, provider).ok();
+        return TimeZone::from_time_zone_record(annotation, provider).ok();
     };
 
     if let Some(offset) = offset {
@@ -58,9 +67,33 @@ pub(crate) fn parse_allowed_timezone_formats(
 }
 
 #[inline]
-pub(crate) fn parse_identifier(source: &str) -> TemporalResult<TimeZoneRecord<'_, Utf8>> {
-    let mut parser = TimeZoneParser::from_str(source);
-    parser.parse_identifier().or(Err(
-        TemporalError::range().with_message("Invalid TimeZone Identifier")
-    ))
+pub(crate) fn parse_identifier(source: Encoded<'_>) -> TemporalResult<TimeZoneIdentifier<'_>> {
+    macro_rules! identifier {
+        ($parser:expr, $encoding:ident) => {
+            $parser.parse_identifier().map(|record| match record {
+                TimeZoneRecord::Name(name) => {
+                    Some(TimeZoneIdentifier::Name(Encoded::$encoding(name)))
+                }
+                TimeZoneRecord::Offset(offset) => Some(TimeZoneIdentifier::Offset(offset)),
+                _ => None,
+            })
+        };
+    }
+
+    let identifier = match source {
+        Encoded::Utf8(s) => identifier!(TimeZoneParser::from_utf8(s), Utf8),
+        Encoded::Utf16(s) => identifier!(TimeZoneParser::from_utf16(s), Utf16),
+    };
+    identifier
+        .map_err(|e| ixdtf_error(e, source, reparse_identifier))
+        .and_then(|identifier| identifier.ok_or_else(TemporalError::range))
+        .map_err(|e| e.with_message("Invalid TimeZone Identifier"))
+}
+
+// Reparses a prefix of a failed time zone identifier to locate the error.
+fn reparse_identifier(source: Encoded<'_>) -> Result<(), ParseError> {
+    match source {
+        Encoded::Utf8(s) => TimeZoneParser::from_utf8(s).parse_identifier().map(|_| ()),
+        Encoded::Utf16(s) => TimeZoneParser::from_utf16(s).parse_identifier().map(|_| ()),
+    }
 }
@@ -5,6 +5,8 @@ use core::str::FromStr;
 use crate::UtcOffset;
 use crate::{iso::IsoDateTime, unix_time::EpochNanoseconds, TemporalResult};
 use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use tinystr::TinyAsciiStr;
 
 /// `UtcOffsetSeconds` represents the amount of seconds we need to add to the UTC to reach the local time.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -27,12 +29,43 @@ pub struct EpochNanosecondsAndOffset {
 }
 
 /// `TimeZoneTransitionInfo` represents information about a timezone transition.
+///
+/// Providers outside this crate construct it with [`TimeZoneTransitionInfo::new`].
+#[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TimeZoneTransitionInfo {
     /// The transition time epoch at which the offset needs to be applied.
     pub transition_epoch: Option<i64>,
     /// The time zone offset in seconds.
     pub offset: UtcOffsetSeconds,
+    /// Whether the offset is daylight saving time.
+    pub is_dst: bool,
+    /// The time zone abbreviation of the offset, e.g. `CEST`, if available.
+    pub abbreviation: Option<TinyAsciiStr<8>>,
+}
+
+impl TimeZoneTransitionInfo {
+    /// Creates a new `TimeZoneTransitionInfo` for a standard time offset with no abbreviation.
+    pub const fn new(transition_epoch: Option<i64>, offset: UtcOffsetSeconds) -> Self {
+        Self {
+            transition_epoch,
+            offset,
+            is_dst: false,
+            abbreviation: None,
+        }
+    }
+
+    /// Sets whether the offset is daylight saving time.
+    pub const fn with_dst(mut self, is_dst: bool) -> Self {
+        self.is_dst = is_dst;
+        self
+    }
+
+    /// Sets the time zone abbreviation of the offset.
+    pub const fn with_abbreviation(mut self, abbreviation: Option<TinyAsciiStr<8>>) -> Self {
+        self.abbreviation = abbreviation;
+        self
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -71,14 +104,27 @@ impl core::fmt::Display for TransitionDirection {
     }
 }
 
+/// `TimeZoneLink` represents the link metadata of a non-primary time zone identifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeZoneLink<'a> {
+    /// The primary identifier that the link resolves to.
+    pub target: Cow<'a, str>,
+    /// Whether the link is deprecated, i.e. it is only kept for
+    /// backward compatibility in the tzdb `backward` file.
+    pub deprecated: bool,
+}
+
 /// Used in disambiguate_possible_epoch_nanos
 ///
 /// When we have a LocalTimeRecordResult::Empty,
 /// it is useful to know the offsets before and after.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct GapEntryOffsets {
+    /// The offset in effect before the gap.
     pub offset_before: UtcOffsetSeconds,
+    /// The offset in effect after the gap.
     pub offset_after: UtcOffsetSeconds,
+    /// The exact time of the transition that created the gap.
     pub transition_epoch: EpochNanoseconds,
 }
 
@@ -91,7 +137,8 @@ pub enum CandidateEpochNanoseconds {
 }
 
 impl CandidateEpochNanoseconds {
-    pub(crate) fn as_slice(&self) -> &[EpochNanosecondsAndOffset] {
+    /// Returns the candidates as a slice, which is empty for a gap.
+    pub fn as_slice(&self) -> &[EpochNanosecondsAndOffset] {
         match *self {
             Self::Zero(..) => &[],
             Self::One(ref one) => core::slice::from_ref(one),
@@ -99,13 +146,13 @@ impl CandidateEpochNanoseconds {
         }
     }
 
-    #[allow(unused)] // Used in tests in some feature configurations
-    pub(crate) fn is_empty(&self) -> bool {
+    /// Returns whether there are no candidates.
+    pub fn is_empty(&self) -> bool {
         matches!(*self, Self::Zero(..))
     }
 
-    #[allow(unused)] // Used in tests in some feature configurations
-    pub(crate) fn len(&self) -> usize {
+    /// Returns the number of candidates.
+    pub fn len(&self) -> usize {
         match *self {
             Self::Zero(..) => 0,
             Self::One(..) => 1,
@@ -113,14 +160,16 @@ impl CandidateEpochNanoseconds {
         }
     }
 
-    pub(crate) fn first(&self) -> Option<EpochNanosecondsAndOffset> {
+    /// Returns the earliest candidate, if any.
+    pub fn first(&self) -> Option<EpochNanosecondsAndOffset> {
         match *self {
             Self::Zero(..) => None,
             Self::One(one) | Self::Two([one, _]) => Some(one),
         }
     }
 
-    pub(crate) fn last(&self) -> Option<EpochNanosecondsAndOffset> {
+    /// Returns the latest candidate, if any.
+    pub fn last(&self) -> Option<EpochNanosecondsAndOffset> {
         match *self {
             Self::Zero(..) => None,
             Self::One(last) | Self::Two([_, last]) => Some(last),
@@ -137,6 +186,21 @@ pub trait TimeZoneProvider {
 
     fn canonicalize_identifier(&self, ident: &'_ [u8]) -> TemporalResult<Cow<'_, str>>;
 
+    /// Returns the link metadata for an identifier, or `None` if the
+    /// identifier is a primary identifier.
+    ///
+    /// The default implementation has no link data and returns `None`.
+    fn get_identifier_link(&self, _ident: &'_ [u8]) -> TemporalResult<Option<TimeZoneLink<'_>>> {
+        Ok(None)
+    }
+
+    /// Returns all identifiers that link to the primary identifier of `ident`.
+    ///
+    /// The default implementation has no link data and returns no aliases.
+    fn get_identifier_aliases(&self, _ident: &'_ [u8]) -> TemporalResult<Vec<Cow<'_, str>>> {
+        Ok(Vec::new())
+    }
+
     fn get_named_tz_epoch_nanoseconds(
         &self,
         identifier: &str,
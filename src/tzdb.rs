@@ -36,6 +36,7 @@ use crate::TemporalUnwrap;
 use alloc::borrow::Cow;
 use alloc::collections::BTreeMap;
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use core::cmp::Ordering;
 use core::ops::Range;
 use std::sync::RwLock;
@@ -43,6 +44,7 @@ use std::sync::RwLock;
 use combine::Parser;
 
 use timezone_provider::prelude::*;
+use tinystr::TinyAsciiStr;
 
 use tzif::{
     self,
@@ -58,8 +60,8 @@ use tzif::{
 use crate::{
     iso::IsoDateTime,
     provider::{
-        CandidateEpochNanoseconds, GapEntryOffsets, TimeZoneProvider, TimeZoneTransitionInfo,
-        TransitionDirection, UtcOffsetSeconds,
+        CandidateEpochNanoseconds, GapEntryOffsets, TimeZoneLink, TimeZoneProvider,
+        TimeZoneTransitionInfo, TransitionDirection, UtcOffsetSeconds,
     },
     unix_time::EpochNanoseconds,
     utils, TemporalError, TemporalResult,
@@ -227,15 +229,12 @@ impl Tzif {
                 if let Some(posix_tz_string) = self.posix_tz_string() {
                     resolve_posix_tz_string_for_epoch_seconds(posix_tz_string, epoch_seconds.0)
                 } else {
-                    Ok(TimeZoneTransitionInfo {
-                        offset: db
-                            .local_time_type_records
-                            .first()
-                            .copied()
-                            .temporal_unwrap()?
-                            .into(),
-                        transition_epoch: None,
-                    })
+                    let record = db
+                        .local_time_type_records
+                        .first()
+                        .copied()
+                        .temporal_unwrap()?;
+                    Ok(transition_info_for_record(db, record, None))
                 }
             }
             // Our time is before the first transition.
@@ -653,6 +652,20 @@ impl Tzif {
     }
 }
 
+#[inline]
+fn transition_info_for_record(
+    db: &DataBlock,
+    record: LocalTimeTypeRecord,
+    transition_epoch: Option<i64>,
+) -> TimeZoneTransitionInfo {
+    TimeZoneTransitionInfo::new(transition_epoch, record.into())
+        .with_dst(record.is_dst)
+        .with_abbreviation(
+            db.time_zone_designation(record.idx)
+                .and_then(|designation| TinyAsciiStr::try_from_str(designation).ok()),
+        )
+}
+
 #[inline]
 fn get_timezone_offset(db: &DataBlock, idx: usize) -> TimeZoneTransitionInfo {
     // NOTE: Transition type can be empty. If no transition_type exists,
@@ -661,21 +674,19 @@ fn get_timezone_offset(db: &DataBlock, idx: usize) -> TimeZoneTransitionInfo {
         .local_time_type_records
         .get(db.transition_types.get(idx).copied().unwrap_or(0));
     debug_assert!(offset.is_some(), "tzif internal invariant violated");
-    TimeZoneTransitionInfo {
-        transition_epoch: db.transition_times.get(idx).map(|s| s.0),
-        offset: offset.copied().unwrap_or_default().into(),
-    }
+    transition_info_for_record(
+        db,
+        offset.copied().unwrap_or_default(),
+        db.transition_times.get(idx).map(|s| s.0),
+    )
 }
 
 #[inline]
 fn get_first_timezone_offset(db: &DataBlock) -> TimeZoneTransitionInfo {
     let offset = db.local_time_type_records.first();
     debug_assert!(offset.is_some(), "tzif internal invariant violated");
-    TimeZoneTransitionInfo {
-        // There was no transition into the first timezone
-        transition_epoch: None,
-        offset: offset.copied().unwrap_or_default().into(),
-    }
+    // There was no transition into the first timezone
+    transition_info_for_record(db, offset.copied().unwrap_or_default(), None)
 }
 
 #[inline]
@@ -845,10 +856,11 @@ fn resolve_posix_tz_string_for_epoch_seconds(
 ) -> TemporalResult<TimeZoneTransitionInfo> {
     let Some(dst_variant) = &posix_tz_string.dst_info else {
         // Regardless of the time, there is one variant and we can return it.
-        return Ok(TimeZoneTransitionInfo {
-            transition_epoch: None,
-            offset: UtcOffsetSeconds::from(&posix_tz_string.std_info),
-        });
+        return Ok(TimeZoneTransitionInfo::new(
+            None,
+            UtcOffsetSeconds::from(&posix_tz_string.std_info),
+        )
+        .with_abbreviation(TinyAsciiStr::try_from_str(&posix_tz_string.std_info.name).ok()));
     };
 
     let year = utils::epoch_time_to_iso_year(seconds * 1000);
@@ -882,7 +894,7 @@ fn resolve_posix_tz_string_for_epoch_seconds(
     // available rule. However, the northern hemisphere's first STD rule and the Southern hemisphere's
     // first DST rule will have different transition times that are based in the year prior, so if the
     // requested seconds falls in that range, we calculate the transition time for the prior year.
-    let (new_offset, transition_epoch) = if should_return_dst {
+    let (new_offset, transition_epoch, variant_info) = if should_return_dst {
         let transition_epoch = if dst_is_inversed && seconds < dst_end_seconds {
             Some(calculate_transition_seconds_for_year(
                 year - 1,
@@ -892,7 +904,11 @@ fn resolve_posix_tz_string_for_epoch_seconds(
         } else {
             Some(dst_start_seconds)
         };
-        (transition_info.dst_offset, transition_epoch)
+        (
+            transition_info.dst_offset,
+            transition_epoch,
+            &dst_variant.variant_info,
+        )
     } else {
         let transition_epoch = if !dst_is_inversed && seconds < dst_start_seconds {
             Some(calculate_transition_seconds_for_year(
@@ -903,12 +919,15 @@ fn resolve_posix_tz_string_for_epoch_seconds(
         } else {
             Some(dst_end_seconds)
         };
-        (transition_info.std_offset, transition_epoch)
+        (
+            transition_info.std_offset,
+            transition_epoch,
+            &posix_tz_string.std_info,
+        )
     };
-    Ok(TimeZoneTransitionInfo {
-        offset: new_offset,
-        transition_epoch,
-    })
+    Ok(TimeZoneTransitionInfo::new(transition_epoch, new_offset)
+        .with_dst(should_return_dst)
+        .with_abbreviation(TinyAsciiStr::try_from_str(&variant_info.name).ok()))
 }
 
 fn calculate_transition_seconds_for_year(
@@ -1024,10 +1043,13 @@ fn resolve_posix_tz_string(
         let offset = offset_range(transition_time.0 + transition_diff, transition_time.0);
         match offset.contains(&time) {
             true if is_dst == TransitionType::Dst => {
+                // The transition time is a local time of day in standard time, so
+                // rebase it onto the local day and convert it to UTC.
+                let transition_epoch = Seconds(seconds - time + transition_time.0 - std.0);
                 return Ok(LocalTimeRecordResult::Empty(GapEntryOffsets {
                     offset_before: std,
                     offset_after: dst,
-                    transition_epoch: transition_time.into(),
+                    transition_epoch: transition_epoch.into(),
                 }));
             }
             true => {
@@ -1107,7 +1129,8 @@ struct MwdForTime {
 impl MwdForTime {
     fn from_seconds(seconds: i64) -> Self {
         let (year, month, day_of_month) = utils::ymd_from_epoch_milliseconds(seconds * 1_000);
-        let week_of_month = day_of_month / 7 + 1;
+        // Days 1-7 contain the first occurrence of each weekday, days 8-14 the second, etc.
+        let week_of_month = (day_of_month - 1) / 7 + 1;
         let day_of_week = utils::epoch_seconds_to_day_of_week(seconds);
         let mut mwd = Mwd::from_u8(month, week_of_month, day_of_week);
         let days_in_month = utils::iso_days_in_month(year, month);
@@ -1286,6 +1309,54 @@ fn canonicalize_identifier_with_compiled(identifier: &[u8]) -> TemporalResult<Co
     Err(TemporalError::range().with_message("Unknown time zone identifier"))
 }
 
+fn identifier_link_with_compiled(
+    identifier: &[u8],
+) -> TemporalResult<Option<TimeZoneLink<'static>>> {
+    if SINGLETON_IANA_NORMALIZER
+        .available_id_index
+        .get(identifier)
+        .is_none()
+    {
+        return Err(TemporalError::range().with_message("Unknown time zone identifier"));
+    }
+
+    // Primary identifiers are not present in the links table.
+    let Some(index) = SINGLETON_IANA_NORMALIZER
+        .non_canonical_identifiers
+        .get(identifier)
+    else {
+        return Ok(None);
+    };
+
+    let target = SINGLETON_IANA_NORMALIZER
+        .normalized_identifiers
+        .get(index)
+        .ok_or(TemporalError::range().with_message("Unknown time zone identifier"))?;
+
+    Ok(Some(TimeZoneLink {
+        target: Cow::Borrowed(target),
+        deprecated: SINGLETON_IANA_NORMALIZER
+            .deprecated_identifiers
+            .get(identifier)
+            .is_some(),
+    }))
+}
+
+fn identifier_aliases_with_compiled(identifier: &[u8]) -> TemporalResult<Vec<Cow<'static, str>>> {
+    let primary = SINGLETON_IANA_NORMALIZER
+        .non_canonical_identifiers
+        .get(identifier)
+        .or(SINGLETON_IANA_NORMALIZER.available_id_index.get(identifier))
+        .ok_or(TemporalError::range().with_message("Unknown time zone identifier"))?;
+
+    Ok(SINGLETON_IANA_NORMALIZER
+        .normalized_identifiers
+        .iter()
+        .filter(|id| SINGLETON_IANA_NORMALIZER.non_canonical_identifiers.get(id) == Some(primary))
+        .map(Cow::Borrowed)
+        .collect())
+}
+
 /// Timezone provider that uses compiled data.
 ///
 /// Currently uses jiff_tzdb and performs parsing; will eventually
@@ -1333,6 +1404,12 @@ impl TimeZoneProvider for CompiledTzdbProvider {
     fn canonicalize_identifier(&self, ident: &'_ [u8]) -> TemporalResult<Cow<'_, str>> {
         canonicalize_identifier_with_compiled(ident)
     }
+    fn get_identifier_link(&self, ident: &'_ [u8]) -> TemporalResult<Option<TimeZoneLink<'_>>> {
+        identifier_link_with_compiled(ident)
+    }
+    fn get_identifier_aliases(&self, ident: &'_ [u8]) -> TemporalResult<Vec<Cow<'_, str>>> {
+        identifier_aliases_with_compiled(ident)
+    }
     fn get_named_tz_epoch_nanoseconds(
         &self,
         identifier: &str,
@@ -1407,6 +1484,12 @@ impl TimeZoneProvider for FsTzdbProvider {
     fn canonicalize_identifier(&self, ident: &'_ [u8]) -> TemporalResult<Cow<'_, str>> {
         canonicalize_identifier_with_compiled(ident)
     }
+    fn get_identifier_link(&self, ident: &'_ [u8]) -> TemporalResult<Option<TimeZoneLink<'_>>> {
+        identifier_link_with_compiled(ident)
+    }
+    fn get_identifier_aliases(&self, ident: &'_ [u8]) -> TemporalResult<Vec<Cow<'_, str>>> {
+        identifier_aliases_with_compiled(ident)
+    }
 
     fn get_named_tz_epoch_nanoseconds(
         &self,
@@ -1451,7 +1534,10 @@ mod tests {
         builtins::calendar::CalendarFields,
         iso::{IsoDate, IsoDateTime, IsoTime},
         partial::PartialZonedDateTime,
-        tzdb::{CompiledTzdbProvider, LocalTimeRecordResult, TimeZoneProvider, UtcOffsetSeconds},
+        tzdb::{
+            CandidateEpochNanoseconds, CompiledTzdbProvider, LocalTimeRecordResult,
+            TimeZoneProvider, UtcOffsetSeconds,
+        },
         TimeZone, ZonedDateTime,
     };
 
@@ -1993,6 +2079,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn posix_mwd_transition_on_seventh_day_of_week() {
+        let provider = CompiledTzdbProvider::default();
+
+        fn assert_gap(
+            id: &str,
+            date: IsoDate,
+            transition_epoch: i128,
+            provider: &CompiledTzdbProvider,
+        ) {
+            let before =
+                IsoDateTime::new_unchecked(date, IsoTime::new_unchecked(1, 30, 0, 0, 0, 0));
+            let gap = IsoDateTime::new_unchecked(date, IsoTime::new_unchecked(2, 30, 0, 0, 0, 0));
+
+            let before = provider.get_named_tz_epoch_nanoseconds(id, before).unwrap();
+            assert_eq!(before.len(), 1);
+            let gap = provider.get_named_tz_epoch_nanoseconds(id, gap).unwrap();
+            let CandidateEpochNanoseconds::Zero(offsets) = gap else {
+                panic!("{id} must have a gap on {date:?}, found {gap:?}");
+            };
+            assert_eq!(
+                offsets.offset_before.0 * 1_000_000_000,
+                before.as_slice()[0].offset.nanoseconds()
+            );
+            assert_eq!(offsets.transition_epoch.0, transition_epoch);
+        }
+
+        // M3.5.0: Sun, Mar 28 2027 is the last Sunday of the month and the 28th.
+        assert_gap(
+            "Europe/Berlin",
+            IsoDate::new_unchecked(2027, 3, 28),
+            1_806_195_600_000_000_000,
+            &provider,
+        );
+        // M3.2.0: Sun, Mar 14 2027 is the second Sunday of the month and the 14th.
+        assert_gap(
+            "America/New_York",
+            IsoDate::new_unchecked(2027, 3, 14),
+            1_805_007_600_000_000_000,
+            &provider,
+        );
+
+        // Sat, Mar 21 2026 is a week before the last Sunday of March.
+        let before = IsoDateTime::new_unchecked(
+            IsoDate::new_unchecked(2026, 3, 21),
+            IsoTime::new_unchecked(12, 0, 0, 0, 0, 0),
+        );
+        let before = provider
+            .get_named_tz_epoch_nanoseconds("Europe/Berlin", before)
+            .unwrap();
+        assert_eq!(before.as_slice()[0].offset.nanoseconds(), 3_600_000_000_000);
+    }
+
     // This test mimicks the operations present in `temporal_rs`'s `disambiguate_possible_epoch_nanoseconds`
     #[test]
     fn disambiguate_ambiguous_posix_time() {
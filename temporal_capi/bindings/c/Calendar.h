@@ -22,12 +22,20 @@ Calendar* temporal_rs_Calendar_try_new_constrain(AnyCalendarKind kind);
 typedef struct temporal_rs_Calendar_from_utf8_result {union {Calendar* ok; TemporalError err;}; bool is_ok;} temporal_rs_Calendar_from_utf8_result;
 temporal_rs_Calendar_from_utf8_result temporal_rs_Calendar_from_utf8(DiplomatStringView s);
 
+typedef struct temporal_rs_Calendar_from_utf16_result {union {Calendar* ok; TemporalError err;}; bool is_ok;} temporal_rs_Calendar_from_utf16_result;
+temporal_rs_Calendar_from_utf16_result temporal_rs_Calendar_from_utf16(DiplomatString16View s);
+
 bool temporal_rs_Calendar_is_iso(const Calendar* self);
 
 DiplomatStringView temporal_rs_Calendar_identifier(const Calendar* self);
 
+bool temporal_rs_Calendar_is_julian(const Calendar* self);
+
 AnyCalendarKind temporal_rs_Calendar_kind(const Calendar* self);
 
+typedef struct temporal_rs_Calendar_any_calendar_kind_result {union {AnyCalendarKind ok; }; bool is_ok;} temporal_rs_Calendar_any_calendar_kind_result;
+temporal_rs_Calendar_any_calendar_kind_result temporal_rs_Calendar_any_calendar_kind(const Calendar* self);
+
 void temporal_rs_Calendar_destroy(Calendar* self);
 
 
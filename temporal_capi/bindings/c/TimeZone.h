@@ -22,9 +22,15 @@ temporal_rs_TimeZone_try_from_identifier_str_result temporal_rs_TimeZone_try_fro
 typedef struct temporal_rs_TimeZone_try_from_offset_str_result {union {TimeZone* ok; TemporalError err;}; bool is_ok;} temporal_rs_TimeZone_try_from_offset_str_result;
 temporal_rs_TimeZone_try_from_offset_str_result temporal_rs_TimeZone_try_from_offset_str(DiplomatStringView ident);
 
+typedef struct temporal_rs_TimeZone_try_from_offset_utf16_result {union {TimeZone* ok; TemporalError err;}; bool is_ok;} temporal_rs_TimeZone_try_from_offset_utf16_result;
+temporal_rs_TimeZone_try_from_offset_utf16_result temporal_rs_TimeZone_try_from_offset_utf16(DiplomatString16View ident);
+
 typedef struct temporal_rs_TimeZone_try_from_str_result {union {TimeZone* ok; TemporalError err;}; bool is_ok;} temporal_rs_TimeZone_try_from_str_result;
 temporal_rs_TimeZone_try_from_str_result temporal_rs_TimeZone_try_from_str(DiplomatStringView ident);
 
+typedef struct temporal_rs_TimeZone_try_from_utf16_result {union {TimeZone* ok; TemporalError err;}; bool is_ok;} temporal_rs_TimeZone_try_from_utf16_result;
+temporal_rs_TimeZone_try_from_utf16_result temporal_rs_TimeZone_try_from_utf16(DiplomatString16View ident);
+
 void temporal_rs_TimeZone_identifier(const TimeZone* self, DiplomatWrite* write);
 
 TimeZone* temporal_rs_TimeZone_utc(void);
@@ -0,0 +1,19 @@
+#ifndef TimeZoneTransition_D_H
+#define TimeZoneTransition_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef struct TimeZoneTransition TimeZoneTransition;
+
+
+
+
+#endif // TimeZoneTransition_D_H
@@ -0,0 +1,45 @@
+#ifndef TimeZoneTransition_H
+#define TimeZoneTransition_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#include "Duration.d.h"
+#include "Instant.d.h"
+#include "WallClockShiftKind.d.h"
+
+#include "TimeZoneTransition.d.h"
+
+
+
+
+
+
+Instant* temporal_rs_TimeZoneTransition_instant(const TimeZoneTransition* self);
+
+int64_t temporal_rs_TimeZoneTransition_offset_before_nanoseconds(const TimeZoneTransition* self);
+
+int64_t temporal_rs_TimeZoneTransition_offset_after_nanoseconds(const TimeZoneTransition* self);
+
+bool temporal_rs_TimeZoneTransition_is_dst_before(const TimeZoneTransition* self);
+
+bool temporal_rs_TimeZoneTransition_is_dst_after(const TimeZoneTransition* self);
+
+void temporal_rs_TimeZoneTransition_abbreviation_before(const TimeZoneTransition* self, DiplomatWrite* write);
+
+void temporal_rs_TimeZoneTransition_abbreviation_after(const TimeZoneTransition* self, DiplomatWrite* write);
+
+WallClockShiftKind temporal_rs_TimeZoneTransition_shift_kind(const TimeZoneTransition* self);
+
+Duration* temporal_rs_TimeZoneTransition_shift_duration(const TimeZoneTransition* self);
+
+void temporal_rs_TimeZoneTransition_destroy(TimeZoneTransition* self);
+
+
+
+
+
+#endif // TimeZoneTransition_H
@@ -0,0 +1,23 @@
+#ifndef WallClockShiftKind_D_H
+#define WallClockShiftKind_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+
+
+
+typedef enum WallClockShiftKind {
+  WallClockShiftKind_Gap = 0,
+  WallClockShiftKind_Overlap = 1,
+} WallClockShiftKind;
+
+typedef struct WallClockShiftKind_option {union { WallClockShiftKind ok; }; bool is_ok; } WallClockShiftKind_option;
+
+
+
+#endif // WallClockShiftKind_D_H
@@ -0,0 +1,22 @@
+#ifndef WallClockShiftKind_H
+#define WallClockShiftKind_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+
+#include "WallClockShiftKind.d.h"
+
+
+
+
+
+
+
+
+
+
+#endif // WallClockShiftKind_H
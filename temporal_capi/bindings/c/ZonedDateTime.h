@@ -27,6 +27,7 @@
 #include "RoundingOptions.d.h"
 #include "TemporalError.d.h"
 #include "TimeZone.d.h"
+#include "TimeZoneTransition.d.h"
 #include "ToStringRoundingOptions.d.h"
 #include "TransitionDirection.d.h"
 
@@ -84,6 +85,9 @@ temporal_rs_ZonedDateTime_start_of_day_result temporal_rs_ZonedDateTime_start_of
 typedef struct temporal_rs_ZonedDateTime_get_time_zone_transition_result {union {ZonedDateTime* ok; TemporalError err;}; bool is_ok;} temporal_rs_ZonedDateTime_get_time_zone_transition_result;
 temporal_rs_ZonedDateTime_get_time_zone_transition_result temporal_rs_ZonedDateTime_get_time_zone_transition(const ZonedDateTime* self, TransitionDirection direction);
 
+typedef struct temporal_rs_ZonedDateTime_get_time_zone_transition_info_result {union {TimeZoneTransition* ok; TemporalError err;}; bool is_ok;} temporal_rs_ZonedDateTime_get_time_zone_transition_info_result;
+temporal_rs_ZonedDateTime_get_time_zone_transition_info_result temporal_rs_ZonedDateTime_get_time_zone_transition_info(const ZonedDateTime* self, TransitionDirection direction);
+
 typedef struct temporal_rs_ZonedDateTime_hours_in_day_result {union {uint8_t ok; TemporalError err;}; bool is_ok;} temporal_rs_ZonedDateTime_hours_in_day_result;
 temporal_rs_ZonedDateTime_hours_in_day_result temporal_rs_ZonedDateTime_hours_in_day(const ZonedDateTime* self);
 
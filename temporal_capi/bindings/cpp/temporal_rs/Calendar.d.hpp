@@ -37,15 +37,27 @@ public:
 
   inline static diplomat::result<std::unique_ptr<temporal_rs::Calendar>, temporal_rs::TemporalError> from_utf8(std::string_view s);
 
+  inline static diplomat::result<std::unique_ptr<temporal_rs::Calendar>, temporal_rs::TemporalError> from_utf16(std::u16string_view s);
+
   inline bool is_iso() const;
 
   inline std::string_view identifier() const;
 
+  inline bool is_julian() const;
+
   /**
    * Returns the kind of this calendar
+   *
+   * This is lossy: the Julian calendar reports the ISO kind. Use
+   * `any_calendar_kind` to tell it apart from the ISO calendar.
    */
   inline temporal_rs::AnyCalendarKind kind() const;
 
+  /**
+   * Returns the kind of this calendar, or none for the Julian calendar
+   */
+  inline std::optional<temporal_rs::AnyCalendarKind> any_calendar_kind() const;
+
   inline const temporal_rs::capi::Calendar* AsFFI() const;
   inline temporal_rs::capi::Calendar* AsFFI();
   inline static const temporal_rs::Calendar* FromFFI(const temporal_rs::capi::Calendar* ptr);
@@ -25,12 +25,20 @@ namespace capi {
     typedef struct temporal_rs_Calendar_from_utf8_result {union {temporal_rs::capi::Calendar* ok; temporal_rs::capi::TemporalError err;}; bool is_ok;} temporal_rs_Calendar_from_utf8_result;
     temporal_rs_Calendar_from_utf8_result temporal_rs_Calendar_from_utf8(diplomat::capi::DiplomatStringView s);
 
+    typedef struct temporal_rs_Calendar_from_utf16_result {union {temporal_rs::capi::Calendar* ok; temporal_rs::capi::TemporalError err;}; bool is_ok;} temporal_rs_Calendar_from_utf16_result;
+    temporal_rs_Calendar_from_utf16_result temporal_rs_Calendar_from_utf16(diplomat::capi::DiplomatString16View s);
+
     bool temporal_rs_Calendar_is_iso(const temporal_rs::capi::Calendar* self);
 
     diplomat::capi::DiplomatStringView temporal_rs_Calendar_identifier(const temporal_rs::capi::Calendar* self);
 
+    bool temporal_rs_Calendar_is_julian(const temporal_rs::capi::Calendar* self);
+
     temporal_rs::capi::AnyCalendarKind temporal_rs_Calendar_kind(const temporal_rs::capi::Calendar* self);
 
+    typedef struct temporal_rs_Calendar_any_calendar_kind_result {union {temporal_rs::capi::AnyCalendarKind ok; }; bool is_ok;} temporal_rs_Calendar_any_calendar_kind_result;
+    temporal_rs_Calendar_any_calendar_kind_result temporal_rs_Calendar_any_calendar_kind(const temporal_rs::capi::Calendar* self);
+
     void temporal_rs_Calendar_destroy(Calendar* self);
 
     } // extern "C"
@@ -47,6 +55,11 @@ inline diplomat::result<std::unique_ptr<temporal_rs::Calendar>, temporal_rs::Tem
   return result.is_ok ? diplomat::result<std::unique_ptr<temporal_rs::Calendar>, temporal_rs::TemporalError>(diplomat::Ok<std::unique_ptr<temporal_rs::Calendar>>(std::unique_ptr<temporal_rs::Calendar>(temporal_rs::Calendar::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<temporal_rs::Calendar>, temporal_rs::TemporalError>(diplomat::Err<temporal_rs::TemporalError>(temporal_rs::TemporalError::FromFFI(result.err)));
 }
 
+inline diplomat::result<std::unique_ptr<temporal_rs::Calendar>, temporal_rs::TemporalError> temporal_rs::Calendar::from_utf16(std::u16string_view s) {
+  auto result = temporal_rs::capi::temporal_rs_Calendar_from_utf16({s.data(), s.size()});
+  return result.is_ok ? diplomat::result<std::unique_ptr<temporal_rs::Calendar>, temporal_rs::TemporalError>(diplomat::Ok<std::unique_ptr<temporal_rs::Calendar>>(std::unique_ptr<temporal_rs::Calendar>(temporal_rs::Calendar::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<temporal_rs::Calendar>, temporal_rs::TemporalError>(diplomat::Err<temporal_rs::TemporalError>(temporal_rs::TemporalError::FromFFI(result.err)));
+}
+
 inline bool temporal_rs::Calendar::is_iso() const {
   auto result = temporal_rs::capi::temporal_rs_Calendar_is_iso(this->AsFFI());
   return result;
@@ -57,11 +70,21 @@ inline std::string_view temporal_rs::Calendar::identifier() const {
   return std::string_view(result.data, result.len);
 }
 
+inline bool temporal_rs::Calendar::is_julian() const {
+  auto result = temporal_rs::capi::temporal_rs_Calendar_is_julian(this->AsFFI());
+  return result;
+}
+
 inline temporal_rs::AnyCalendarKind temporal_rs::Calendar::kind() const {
   auto result = temporal_rs::capi::temporal_rs_Calendar_kind(this->AsFFI());
   return temporal_rs::AnyCalendarKind::FromFFI(result);
 }
 
+inline std::optional<temporal_rs::AnyCalendarKind> temporal_rs::Calendar::any_calendar_kind() const {
+  auto result = temporal_rs::capi::temporal_rs_Calendar_any_calendar_kind(this->AsFFI());
+  return result.is_ok ? std::optional<temporal_rs::AnyCalendarKind>(temporal_rs::AnyCalendarKind::FromFFI(result.ok)) : std::nullopt;
+}
+
 inline const temporal_rs::capi::Calendar* temporal_rs::Calendar::AsFFI() const {
   return reinterpret_cast<const temporal_rs::capi::Calendar*>(this);
 }
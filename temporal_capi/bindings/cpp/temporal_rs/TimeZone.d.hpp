@@ -32,8 +32,12 @@ public:
 
   inline static diplomat::result<std::unique_ptr<temporal_rs::TimeZone>, temporal_rs::TemporalError> try_from_offset_str(std::string_view ident);
 
+  inline static diplomat::result<std::unique_ptr<temporal_rs::TimeZone>, temporal_rs::TemporalError> try_from_offset_utf16(std::u16string_view ident);
+
   inline static diplomat::result<std::unique_ptr<temporal_rs::TimeZone>, temporal_rs::TemporalError> try_from_str(std::string_view ident);
 
+  inline static diplomat::result<std::unique_ptr<temporal_rs::TimeZone>, temporal_rs::TemporalError> try_from_utf16(std::u16string_view ident);
+
   inline std::string identifier() const;
   template<typename W>
   inline void identifier_write(W& writeable_output) const;
@@ -0,0 +1,82 @@
+#ifndef temporal_rs_TimeZoneTransition_D_HPP
+#define temporal_rs_TimeZoneTransition_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+namespace temporal_rs {
+namespace capi { struct Duration; }
+class Duration;
+namespace capi { struct Instant; }
+class Instant;
+class WallClockShiftKind;
+}
+
+
+namespace temporal_rs {
+namespace capi {
+    struct TimeZoneTransition;
+} // namespace capi
+} // namespace
+
+namespace temporal_rs {
+/**
+ * A time zone transition with the offsets in effect before and after it.
+ */
+class TimeZoneTransition {
+public:
+
+  inline std::unique_ptr<temporal_rs::Instant> instant() const;
+
+  inline int64_t offset_before_nanoseconds() const;
+
+  inline int64_t offset_after_nanoseconds() const;
+
+  inline bool is_dst_before() const;
+
+  inline bool is_dst_after() const;
+
+  /**
+   * Writes the abbreviation before the transition, writes nothing if unavailable.
+   */
+  inline std::string abbreviation_before() const;
+  template<typename W>
+  inline void abbreviation_before_write(W& writeable_output) const;
+
+  /**
+   * Writes the abbreviation after the transition, writes nothing if unavailable.
+   */
+  inline std::string abbreviation_after() const;
+  template<typename W>
+  inline void abbreviation_after_write(W& writeable_output) const;
+
+  inline temporal_rs::WallClockShiftKind shift_kind() const;
+
+  /**
+   * The duration of the gap or overlap in wall-clock time.
+   */
+  inline std::unique_ptr<temporal_rs::Duration> shift_duration() const;
+
+  inline const temporal_rs::capi::TimeZoneTransition* AsFFI() const;
+  inline temporal_rs::capi::TimeZoneTransition* AsFFI();
+  inline static const temporal_rs::TimeZoneTransition* FromFFI(const temporal_rs::capi::TimeZoneTransition* ptr);
+  inline static temporal_rs::TimeZoneTransition* FromFFI(temporal_rs::capi::TimeZoneTransition* ptr);
+  inline static void operator delete(void* ptr);
+private:
+  TimeZoneTransition() = delete;
+  TimeZoneTransition(const temporal_rs::TimeZoneTransition&) = delete;
+  TimeZoneTransition(temporal_rs::TimeZoneTransition&&) noexcept = delete;
+  TimeZoneTransition operator=(const temporal_rs::TimeZoneTransition&) = delete;
+  TimeZoneTransition operator=(temporal_rs::TimeZoneTransition&&) noexcept = delete;
+  static void operator delete[](void*, size_t) = delete;
+};
+
+} // namespace
+#endif // temporal_rs_TimeZoneTransition_D_HPP
@@ -0,0 +1,132 @@
+#ifndef temporal_rs_TimeZoneTransition_HPP
+#define temporal_rs_TimeZoneTransition_HPP
+
+#include "TimeZoneTransition.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+#include "Duration.hpp"
+#include "Instant.hpp"
+#include "WallClockShiftKind.hpp"
+
+
+namespace temporal_rs {
+namespace capi {
+    extern "C" {
+
+    temporal_rs::capi::Instant* temporal_rs_TimeZoneTransition_instant(const temporal_rs::capi::TimeZoneTransition* self);
+
+    int64_t temporal_rs_TimeZoneTransition_offset_before_nanoseconds(const temporal_rs::capi::TimeZoneTransition* self);
+
+    int64_t temporal_rs_TimeZoneTransition_offset_after_nanoseconds(const temporal_rs::capi::TimeZoneTransition* self);
+
+    bool temporal_rs_TimeZoneTransition_is_dst_before(const temporal_rs::capi::TimeZoneTransition* self);
+
+    bool temporal_rs_TimeZoneTransition_is_dst_after(const temporal_rs::capi::TimeZoneTransition* self);
+
+    void temporal_rs_TimeZoneTransition_abbreviation_before(const temporal_rs::capi::TimeZoneTransition* self, diplomat::capi::DiplomatWrite* write);
+
+    void temporal_rs_TimeZoneTransition_abbreviation_after(const temporal_rs::capi::TimeZoneTransition* self, diplomat::capi::DiplomatWrite* write);
+
+    temporal_rs::capi::WallClockShiftKind temporal_rs_TimeZoneTransition_shift_kind(const temporal_rs::capi::TimeZoneTransition* self);
+
+    temporal_rs::capi::Duration* temporal_rs_TimeZoneTransition_shift_duration(const temporal_rs::capi::TimeZoneTransition* self);
+
+    void temporal_rs_TimeZoneTransition_destroy(TimeZoneTransition* self);
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline std::unique_ptr<temporal_rs::Instant> temporal_rs::TimeZoneTransition::instant() const {
+  auto result = temporal_rs::capi::temporal_rs_TimeZoneTransition_instant(this->AsFFI());
+  return std::unique_ptr<temporal_rs::Instant>(temporal_rs::Instant::FromFFI(result));
+}
+
+inline int64_t temporal_rs::TimeZoneTransition::offset_before_nanoseconds() const {
+  auto result = temporal_rs::capi::temporal_rs_TimeZoneTransition_offset_before_nanoseconds(this->AsFFI());
+  return result;
+}
+
+inline int64_t temporal_rs::TimeZoneTransition::offset_after_nanoseconds() const {
+  auto result = temporal_rs::capi::temporal_rs_TimeZoneTransition_offset_after_nanoseconds(this->AsFFI());
+  return result;
+}
+
+inline bool temporal_rs::TimeZoneTransition::is_dst_before() const {
+  auto result = temporal_rs::capi::temporal_rs_TimeZoneTransition_is_dst_before(this->AsFFI());
+  return result;
+}
+
+inline bool temporal_rs::TimeZoneTransition::is_dst_after() const {
+  auto result = temporal_rs::capi::temporal_rs_TimeZoneTransition_is_dst_after(this->AsFFI());
+  return result;
+}
+
+inline std::string temporal_rs::TimeZoneTransition::abbreviation_before() const {
+  std::string output;
+  diplomat::capi::DiplomatWrite write = diplomat::WriteFromString(output);
+  temporal_rs::capi::temporal_rs_TimeZoneTransition_abbreviation_before(this->AsFFI(),
+    &write);
+  return output;
+}
+template<typename W>
+inline void temporal_rs::TimeZoneTransition::abbreviation_before_write(W& writeable) const {
+  diplomat::capi::DiplomatWrite write = diplomat::WriteTrait<W>::Construct(writeable);
+  temporal_rs::capi::temporal_rs_TimeZoneTransition_abbreviation_before(this->AsFFI(),
+    &write);
+}
+
+inline std::string temporal_rs::TimeZoneTransition::abbreviation_after() const {
+  std::string output;
+  diplomat::capi::DiplomatWrite write = diplomat::WriteFromString(output);
+  temporal_rs::capi::temporal_rs_TimeZoneTransition_abbreviation_after(this->AsFFI(),
+    &write);
+  return output;
+}
+template<typename W>
+inline void temporal_rs::TimeZoneTransition::abbreviation_after_write(W& writeable) const {
+  diplomat::capi::DiplomatWrite write = diplomat::WriteTrait<W>::Construct(writeable);
+  temporal_rs::capi::temporal_rs_TimeZoneTransition_abbreviation_after(this->AsFFI(),
+    &write);
+}
+
+inline temporal_rs::WallClockShiftKind temporal_rs::TimeZoneTransition::shift_kind() const {
+  auto result = temporal_rs::capi::temporal_rs_TimeZoneTransition_shift_kind(this->AsFFI());
+  return temporal_rs::WallClockShiftKind::FromFFI(result);
+}
+
+inline std::unique_ptr<temporal_rs::Duration> temporal_rs::TimeZoneTransition::shift_duration() const {
+  auto result = temporal_rs::capi::temporal_rs_TimeZoneTransition_shift_duration(this->AsFFI());
+  return std::unique_ptr<temporal_rs::Duration>(temporal_rs::Duration::FromFFI(result));
+}
+
+inline const temporal_rs::capi::TimeZoneTransition* temporal_rs::TimeZoneTransition::AsFFI() const {
+  return reinterpret_cast<const temporal_rs::capi::TimeZoneTransition*>(this);
+}
+
+inline temporal_rs::capi::TimeZoneTransition* temporal_rs::TimeZoneTransition::AsFFI() {
+  return reinterpret_cast<temporal_rs::capi::TimeZoneTransition*>(this);
+}
+
+inline const temporal_rs::TimeZoneTransition* temporal_rs::TimeZoneTransition::FromFFI(const temporal_rs::capi::TimeZoneTransition* ptr) {
+  return reinterpret_cast<const temporal_rs::TimeZoneTransition*>(ptr);
+}
+
+inline temporal_rs::TimeZoneTransition* temporal_rs::TimeZoneTransition::FromFFI(temporal_rs::capi::TimeZoneTransition* ptr) {
+  return reinterpret_cast<temporal_rs::TimeZoneTransition*>(ptr);
+}
+
+inline void temporal_rs::TimeZoneTransition::operator delete(void* ptr) {
+  temporal_rs::capi::temporal_rs_TimeZoneTransition_destroy(reinterpret_cast<temporal_rs::capi::TimeZoneTransition*>(ptr));
+}
+
+
+#endif // temporal_rs_TimeZoneTransition_HPP
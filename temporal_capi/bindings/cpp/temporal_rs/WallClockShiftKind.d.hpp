@@ -0,0 +1,49 @@
+#ifndef temporal_rs_WallClockShiftKind_D_HPP
+#define temporal_rs_WallClockShiftKind_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace temporal_rs {
+namespace capi {
+    enum WallClockShiftKind {
+      WallClockShiftKind_Gap = 0,
+      WallClockShiftKind_Overlap = 1,
+    };
+
+    typedef struct WallClockShiftKind_option {union { WallClockShiftKind ok; }; bool is_ok; } WallClockShiftKind_option;
+} // namespace capi
+} // namespace
+
+namespace temporal_rs {
+class WallClockShiftKind {
+public:
+  enum Value {
+    Gap = 0,
+    Overlap = 1,
+  };
+
+  WallClockShiftKind(): value(Value::Gap) {}
+
+  // Implicit conversions between enum and ::Value
+  constexpr WallClockShiftKind(Value v) : value(v) {}
+  constexpr operator Value() const { return value; }
+  // Prevent usage as boolean value
+  explicit operator bool() const = delete;
+
+  inline temporal_rs::capi::WallClockShiftKind AsFFI() const;
+  inline static temporal_rs::WallClockShiftKind FromFFI(temporal_rs::capi::WallClockShiftKind c_enum);
+private:
+    Value value;
+};
+
+} // namespace
+#endif // temporal_rs_WallClockShiftKind_D_HPP
@@ -0,0 +1,38 @@
+#ifndef temporal_rs_WallClockShiftKind_HPP
+#define temporal_rs_WallClockShiftKind_HPP
+
+#include "WallClockShiftKind.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <functional>
+#include <optional>
+#include <cstdlib>
+#include "../diplomat_runtime.hpp"
+
+
+namespace temporal_rs {
+namespace capi {
+    extern "C" {
+
+    } // extern "C"
+} // namespace capi
+} // namespace
+
+inline temporal_rs::capi::WallClockShiftKind temporal_rs::WallClockShiftKind::AsFFI() const {
+  return static_cast<temporal_rs::capi::WallClockShiftKind>(value);
+}
+
+inline temporal_rs::WallClockShiftKind temporal_rs::WallClockShiftKind::FromFFI(temporal_rs::capi::WallClockShiftKind c_enum) {
+  switch (c_enum) {
+    case temporal_rs::capi::WallClockShiftKind_Gap:
+    case temporal_rs::capi::WallClockShiftKind_Overlap:
+      return static_cast<temporal_rs::WallClockShiftKind::Value>(c_enum);
+    default:
+      std::abort();
+  }
+}
+#endif // temporal_rs_WallClockShiftKind_HPP
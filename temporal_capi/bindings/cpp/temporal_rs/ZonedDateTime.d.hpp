@@ -28,6 +28,8 @@ namespace capi { struct PlainTime; }
 class PlainTime;
 namespace capi { struct TimeZone; }
 class TimeZone;
+namespace capi { struct TimeZoneTransition; }
+class TimeZoneTransition;
 namespace capi { struct ZonedDateTime; }
 class ZonedDateTime;
 struct DifferenceSettings;
@@ -95,6 +97,8 @@ public:
 
   inline diplomat::result<std::unique_ptr<temporal_rs::ZonedDateTime>, temporal_rs::TemporalError> get_time_zone_transition(temporal_rs::TransitionDirection direction) const;
 
+  inline diplomat::result<std::unique_ptr<temporal_rs::TimeZoneTransition>, temporal_rs::TemporalError> get_time_zone_transition_info(temporal_rs::TransitionDirection direction) const;
+
   inline diplomat::result<uint8_t, temporal_rs::TemporalError> hours_in_day() const;
 
   inline diplomat::result<std::unique_ptr<temporal_rs::PlainDateTime>, temporal_rs::TemporalError> to_plain_datetime() const;
@@ -32,6 +32,7 @@
 #include "RoundingOptions.hpp"
 #include "TemporalError.hpp"
 #include "TimeZone.hpp"
+#include "TimeZoneTransition.hpp"
 #include "ToStringRoundingOptions.hpp"
 #include "TransitionDirection.hpp"
 
@@ -87,6 +88,9 @@ namespace capi {
     typedef struct temporal_rs_ZonedDateTime_get_time_zone_transition_result {union {temporal_rs::capi::ZonedDateTime* ok; temporal_rs::capi::TemporalError err;}; bool is_ok;} temporal_rs_ZonedDateTime_get_time_zone_transition_result;
     temporal_rs_ZonedDateTime_get_time_zone_transition_result temporal_rs_ZonedDateTime_get_time_zone_transition(const temporal_rs::capi::ZonedDateTime* self, temporal_rs::capi::TransitionDirection direction);
 
+    typedef struct temporal_rs_ZonedDateTime_get_time_zone_transition_info_result {union {temporal_rs::capi::TimeZoneTransition* ok; temporal_rs::capi::TemporalError err;}; bool is_ok;} temporal_rs_ZonedDateTime_get_time_zone_transition_info_result;
+    temporal_rs_ZonedDateTime_get_time_zone_transition_info_result temporal_rs_ZonedDateTime_get_time_zone_transition_info(const temporal_rs::capi::ZonedDateTime* self, temporal_rs::capi::TransitionDirection direction);
+
     typedef struct temporal_rs_ZonedDateTime_hours_in_day_result {union {uint8_t ok; temporal_rs::capi::TemporalError err;}; bool is_ok;} temporal_rs_ZonedDateTime_hours_in_day_result;
     temporal_rs_ZonedDateTime_hours_in_day_result temporal_rs_ZonedDateTime_hours_in_day(const temporal_rs::capi::ZonedDateTime* self);
 
@@ -300,6 +304,12 @@ inline diplomat::result<std::unique_ptr<temporal_rs::ZonedDateTime>, temporal_rs
   return result.is_ok ? diplomat::result<std::unique_ptr<temporal_rs::ZonedDateTime>, temporal_rs::TemporalError>(diplomat::Ok<std::unique_ptr<temporal_rs::ZonedDateTime>>(std::unique_ptr<temporal_rs::ZonedDateTime>(temporal_rs::ZonedDateTime::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<temporal_rs::ZonedDateTime>, temporal_rs::TemporalError>(diplomat::Err<temporal_rs::TemporalError>(temporal_rs::TemporalError::FromFFI(result.err)));
 }
 
+inline diplomat::result<std::unique_ptr<temporal_rs::TimeZoneTransition>, temporal_rs::TemporalError> temporal_rs::ZonedDateTime::get_time_zone_transition_info(temporal_rs::TransitionDirection direction) const {
+  auto result = temporal_rs::capi::temporal_rs_ZonedDateTime_get_time_zone_transition_info(this->AsFFI(),
+    direction.AsFFI());
+  return result.is_ok ? diplomat::result<std::unique_ptr<temporal_rs::TimeZoneTransition>, temporal_rs::TemporalError>(diplomat::Ok<std::unique_ptr<temporal_rs::TimeZoneTransition>>(std::unique_ptr<temporal_rs::TimeZoneTransition>(temporal_rs::TimeZoneTransition::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<temporal_rs::TimeZoneTransition>, temporal_rs::TemporalError>(diplomat::Err<temporal_rs::TemporalError>(temporal_rs::TemporalError::FromFFI(result.err)));
+}
+
 inline diplomat::result<uint8_t, temporal_rs::TemporalError> temporal_rs::ZonedDateTime::hours_in_day() const {
   auto result = temporal_rs::capi::temporal_rs_ZonedDateTime_hours_in_day(this->AsFFI());
   return result.is_ok ? diplomat::result<uint8_t, temporal_rs::TemporalError>(diplomat::Ok<uint8_t>(result.ok)) : diplomat::result<uint8_t, temporal_rs::TemporalError>(diplomat::Err<temporal_rs::TemporalError>(temporal_rs::TemporalError::FromFFI(result.err)));
@@ -4,7 +4,7 @@
 pub mod ffi {
     use crate::error::ffi::TemporalError;
     use alloc::boxed::Box;
-    use diplomat_runtime::DiplomatStr;
+    use diplomat_runtime::{DiplomatStr, DiplomatStr16};
     use icu_calendar::preferences::CalendarAlgorithm;
 
     #[diplomat::enum_convert(icu_calendar::AnyCalendarKind, needs_wildcard)]
@@ -69,6 +69,12 @@ pub mod ffi {
                 .map_err(Into::into)
         }
 
+        pub fn from_utf16(s: &DiplomatStr16) -> Result<Box<Self>, TemporalError> {
+            temporal_rs::Calendar::try_from_utf16(s)
+                .map(|c| Box::new(Calendar(c)))
+                .map_err(Into::into)
+        }
+
         pub fn is_iso(&self) -> bool {
             self.0.is_iso()
         }
@@ -76,10 +82,23 @@ pub mod ffi {
         pub fn identifier(&self) -> &'static str {
             self.0.identifier()
         }
+        pub fn is_julian(&self) -> bool {
+            self.0.is_julian()
+        }
+
         /// Returns the kind of this calendar
+        ///
+        /// This is lossy: the Julian calendar reports the ISO kind. Use
+        /// `any_calendar_kind` to tell it apart from the ISO calendar.
         #[inline]
         pub fn kind(&self) -> AnyCalendarKind {
             self.0.kind().into()
         }
+
+        /// Returns the kind of this calendar, or none for the Julian calendar
+        #[inline]
+        pub fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+            self.0.any_calendar_kind().map(Into::into)
+        }
     }
 }
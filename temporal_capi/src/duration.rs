@@ -11,8 +11,6 @@ pub mod ffi {
     #[cfg(feature = "compiled_data")]
     use crate::zoned_date_time::ffi::RelativeTo;
     use alloc::boxed::Box;
-    use alloc::string::String;
-    use core::str::FromStr;
     use diplomat_runtime::DiplomatOption;
     use diplomat_runtime::{DiplomatStr, DiplomatStr16};
     use num_traits::FromPrimitive;
@@ -144,9 +142,7 @@ pub mod ffi {
         }
 
         pub fn from_utf16(s: &DiplomatStr16) -> Result<Box<Self>, TemporalError> {
-            // TODO(#275) This should not need to convert
-            let s = String::from_utf16(s).map_err(|_| temporal_rs::TemporalError::range())?;
-            temporal_rs::Duration::from_str(&s)
+            temporal_rs::Duration::from_utf16(s)
                 .map(|c| Box::new(Self(c)))
                 .map_err(Into::into)
         }
@@ -231,10 +227,10 @@ pub mod ffi {
             options: ToStringRoundingOptions,
             write: &mut DiplomatWrite,
         ) -> Result<(), TemporalError> {
-            use core::fmt::Write;
-            let string = self.0.as_temporal_string(options.into())?;
+            use writeable::Writeable;
+            let writeable = self.0.as_temporal_writeable(options.into())?;
             // throw away the error, this should always succeed
-            let _ = write.write_str(&string);
+            let _ = writeable.write_to(write);
 
             Ok(())
         }
@@ -8,8 +8,6 @@ pub mod ffi {
     #[cfg(feature = "compiled_data")]
     use crate::zoned_date_time::ffi::ZonedDateTime;
     use alloc::boxed::Box;
-    use alloc::string::String;
-    use core::str::FromStr;
     use diplomat_runtime::{DiplomatStr, DiplomatStr16};
 
     #[cfg(feature = "compiled_data")]
@@ -63,9 +61,7 @@ pub mod ffi {
         }
 
         pub fn from_utf16(s: &DiplomatStr16) -> Result<Box<Self>, TemporalError> {
-            // TODO(#275) This should not need to convert
-            let s = String::from_utf16(s).map_err(|_| temporal_rs::TemporalError::range())?;
-            temporal_rs::Instant::from_str(&s)
+            temporal_rs::Instant::from_utf16(s)
                 .map(|c| Box::new(Self(c)))
                 .map_err(Into::into)
         }
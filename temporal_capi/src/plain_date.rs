@@ -19,14 +19,11 @@ pub mod ffi {
     #[cfg(feature = "compiled_data")]
     use crate::zoned_date_time::ffi::ZonedDateTime;
     use alloc::boxed::Box;
-    use alloc::string::String;
     use core::fmt::Write;
     use diplomat_runtime::{DiplomatOption, DiplomatStrSlice, DiplomatWrite};
     use diplomat_runtime::{DiplomatStr, DiplomatStr16};
     use writeable::Writeable;
 
-    use core::str::FromStr;
-
     #[diplomat::opaque]
     pub struct PlainDate(pub(crate) temporal_rs::PlainDate);
 
@@ -52,10 +49,7 @@ pub mod ffi {
                 .map_err(Into::<TemporalError>::into)
         }
         pub fn from_utf16(s: &DiplomatStr16) -> Result<Box<Self>, TemporalError> {
-            // TODO(#275) This should not need to convert
-            let s = String::from_utf16(s).map_err(|_| temporal_rs::TemporalError::range())?;
-
-            temporal_rs::parsed_intermediates::ParsedDate::from_utf8(s.as_bytes())
+            temporal_rs::parsed_intermediates::ParsedDate::from_utf16(s)
                 .map(|x| Box::new(ParsedDate(x)))
                 .map_err(Into::<TemporalError>::into)
         }
@@ -66,10 +60,7 @@ pub mod ffi {
                 .map_err(Into::<TemporalError>::into)
         }
         pub fn year_month_from_utf16(s: &DiplomatStr16) -> Result<Box<Self>, TemporalError> {
-            // TODO(#275) This should not need to convert
-            let s = String::from_utf16(s).map_err(|_| temporal_rs::TemporalError::range())?;
-
-            temporal_rs::parsed_intermediates::ParsedDate::year_month_from_utf8(s.as_bytes())
+            temporal_rs::parsed_intermediates::ParsedDate::year_month_from_utf16(s)
                 .map(|x| Box::new(ParsedDate(x)))
                 .map_err(Into::<TemporalError>::into)
         }
@@ -80,10 +71,7 @@ pub mod ffi {
                 .map_err(Into::<TemporalError>::into)
         }
         pub fn month_day_from_utf16(s: &DiplomatStr16) -> Result<Box<Self>, TemporalError> {
-            // TODO(#275) This should not need to convert
-            let s = String::from_utf16(s).map_err(|_| temporal_rs::TemporalError::range())?;
-
-            temporal_rs::parsed_intermediates::ParsedDate::month_day_from_utf8(s.as_bytes())
+            temporal_rs::parsed_intermediates::ParsedDate::month_day_from_utf16(s)
                 .map(|x| Box::new(ParsedDate(x)))
                 .map_err(Into::<TemporalError>::into)
         }
@@ -147,7 +135,7 @@ pub mod ffi {
         }
 
         pub fn from_parsed(parsed: &ParsedDate) -> Result<Box<Self>, TemporalError> {
-            temporal_rs::PlainDate::from_parsed(parsed.0)
+            temporal_rs::PlainDate::from_parsed(parsed.0.clone())
                 .map(|x| Box::new(PlainDate(x)))
                 .map_err(Into::into)
         }
@@ -187,9 +175,7 @@ pub mod ffi {
         }
 
         pub fn from_utf16(s: &DiplomatStr16) -> Result<Box<Self>, TemporalError> {
-            // TODO(#275) This should not need to convert
-            let s = String::from_utf16(s).map_err(|_| temporal_rs::TemporalError::range())?;
-            temporal_rs::PlainDate::from_str(&s)
+            temporal_rs::PlainDate::from_utf16(s)
                 .map(|c| Box::new(Self(c)))
                 .map_err(Into::into)
         }
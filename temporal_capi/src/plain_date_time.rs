@@ -22,9 +22,7 @@ pub mod ffi {
     use crate::options::ffi::Disambiguation;
     use crate::plain_date::ffi::{PartialDate, PlainDate};
     use crate::plain_time::ffi::{PartialTime, PlainTime};
-    use alloc::string::String;
     use core::fmt::Write;
-    use core::str::FromStr;
     use diplomat_runtime::DiplomatWrite;
     use diplomat_runtime::{DiplomatStr, DiplomatStr16};
     use writeable::Writeable;
@@ -47,10 +45,7 @@ pub mod ffi {
                 .map_err(Into::<TemporalError>::into)
         }
         pub fn from_utf16(s: &DiplomatStr16) -> Result<Box<Self>, TemporalError> {
-            // TODO(#275) This should not need to convert
-            let s = String::from_utf16(s).map_err(|_| temporal_rs::TemporalError::range())?;
-
-            temporal_rs::parsed_intermediates::ParsedDateTime::from_utf8(s.as_bytes())
+            temporal_rs::parsed_intermediates::ParsedDateTime::from_utf16(s)
                 .map(|x| Box::new(ParsedDateTime(x)))
                 .map_err(Into::<TemporalError>::into)
         }
@@ -122,7 +117,7 @@ pub mod ffi {
         }
 
         pub fn from_parsed(parsed: &ParsedDateTime) -> Result<Box<Self>, TemporalError> {
-            temporal_rs::PlainDateTime::from_parsed(parsed.0)
+            temporal_rs::PlainDateTime::from_parsed(parsed.0.clone())
                 .map(|x| Box::new(PlainDateTime(x)))
                 .map_err(Into::into)
         }
@@ -169,9 +164,7 @@ pub mod ffi {
         }
 
         pub fn from_utf16(s: &DiplomatStr16) -> Result<Box<Self>, TemporalError> {
-            // TODO(#275) This should not need to convert
-            let s = String::from_utf16(s).map_err(|_| temporal_rs::TemporalError::range())?;
-            temporal_rs::PlainDateTime::from_str(&s)
+            temporal_rs::PlainDateTime::from_utf16(s)
                 .map(|c| Box::new(Self(c)))
                 .map_err(Into::into)
         }
@@ -9,9 +9,7 @@ pub mod ffi {
     use crate::options::ffi::{ArithmeticOverflow, DisplayCalendar};
     use crate::plain_date::ffi::{PartialDate, PlainDate};
 
-    use alloc::string::String;
     use core::fmt::Write;
-    use core::str::FromStr;
     use diplomat_runtime::DiplomatWrite;
     use diplomat_runtime::{DiplomatStr, DiplomatStr16};
     use writeable::Writeable;
@@ -49,7 +47,7 @@ pub mod ffi {
         pub fn from_parsed(
             parsed: &crate::plain_date::ffi::ParsedDate,
         ) -> Result<Box<Self>, TemporalError> {
-            temporal_rs::PlainMonthDay::from_parsed(parsed.0)
+            temporal_rs::PlainMonthDay::from_parsed(parsed.0.clone())
                 .map(|x| Box::new(PlainMonthDay(x)))
                 .map_err(Into::into)
         }
@@ -80,9 +78,7 @@ pub mod ffi {
         }
 
         pub fn from_utf16(s: &DiplomatStr16) -> Result<Box<Self>, TemporalError> {
-            // TODO(#275) This should not need to convert
-            let s = String::from_utf16(s).map_err(|_| temporal_rs::TemporalError::range())?;
-            temporal_rs::PlainMonthDay::from_str(&s)
+            temporal_rs::PlainMonthDay::from_utf16(s)
                 .map(|c| Box::new(Self(c)))
                 .map_err(Into::into)
         }
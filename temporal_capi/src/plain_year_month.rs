@@ -9,14 +9,11 @@ pub mod ffi {
 
     use crate::options::ffi::{ArithmeticOverflow, DifferenceSettings, DisplayCalendar};
     use crate::plain_date::ffi::{PartialDate, PlainDate};
-    use alloc::string::String;
     use core::fmt::Write;
     use diplomat_runtime::DiplomatWrite;
     use diplomat_runtime::{DiplomatStr, DiplomatStr16};
     use writeable::Writeable;
 
-    use core::str::FromStr;
-
     #[diplomat::opaque]
     pub struct PlainYearMonth(pub(crate) temporal_rs::PlainYearMonth);
 
@@ -51,7 +48,7 @@ pub mod ffi {
         pub fn from_parsed(
             parsed: &crate::plain_date::ffi::ParsedDate,
         ) -> Result<Box<Self>, TemporalError> {
-            temporal_rs::PlainYearMonth::from_parsed(parsed.0)
+            temporal_rs::PlainYearMonth::from_parsed(parsed.0.clone())
                 .map(|x| Box::new(PlainYearMonth(x)))
                 .map_err(Into::into)
         }
@@ -75,9 +72,7 @@ pub mod ffi {
         }
 
         pub fn from_utf16(s: &DiplomatStr16) -> Result<Box<Self>, TemporalError> {
-            // TODO(#275) This should not need to convert
-            let s = String::from_utf16(s).map_err(|_| temporal_rs::TemporalError::range())?;
-            temporal_rs::PlainYearMonth::from_str(&s)
+            temporal_rs::PlainYearMonth::from_utf16(s)
                 .map(|c| Box::new(Self(c)))
                 .map_err(Into::into)
         }
@@ -87,10 +82,14 @@ pub mod ffi {
         }
 
         pub fn padded_iso_year_string(&self, write: &mut DiplomatWrite) {
-            // TODO this double-allocates, an API returning a Writeable or impl Write would be better
-            let string = self.0.padded_iso_year_string();
+            let year = self.0.iso_year();
             // throw away the error, the write itself should always succeed
-            let _ = write.write_str(&string);
+            let _ = if (0..9999).contains(&year) {
+                write!(write, "{year:04}")
+            } else {
+                let sign = if year > 0 { '+' } else { '-' };
+                write!(write, "{sign}{:06}", year.unsigned_abs())
+            };
         }
 
         pub fn iso_month(&self) -> u8 {
@@ -2,10 +2,12 @@
 #[diplomat::abi_rename = "temporal_rs_{0}"]
 #[diplomat::attr(auto, namespace = "temporal_rs")]
 pub mod ffi {
+    use crate::duration::ffi::Duration;
     use crate::error::ffi::TemporalError;
+    use crate::instant::ffi::Instant;
     use alloc::boxed::Box;
     use core::fmt::Write;
-    use diplomat_runtime::DiplomatWrite;
+    use diplomat_runtime::{DiplomatStr16, DiplomatWrite};
 
     #[diplomat::opaque]
     #[diplomat::transparent_convert]
@@ -26,6 +28,11 @@ pub mod ffi {
                 .map(|x| Box::new(TimeZone(temporal_rs::TimeZone::UtcOffset(x))))
                 .map_err(Into::into)
         }
+        pub fn try_from_offset_utf16(ident: &DiplomatStr16) -> Result<Box<Self>, TemporalError> {
+            temporal_rs::UtcOffset::from_utf16(ident)
+                .map(|x| Box::new(TimeZone(temporal_rs::TimeZone::UtcOffset(x))))
+                .map_err(Into::into)
+        }
         #[cfg(feature = "compiled_data")]
         pub fn try_from_str(ident: &DiplomatStr) -> Result<Box<Self>, TemporalError> {
             let Ok(ident) = core::str::from_utf8(ident) else {
@@ -35,14 +42,18 @@ pub mod ffi {
                 .map(|x| Box::new(TimeZone(x)))
                 .map_err(Into::into)
         }
+        #[cfg(feature = "compiled_data")]
+        pub fn try_from_utf16(ident: &DiplomatStr16) -> Result<Box<Self>, TemporalError> {
+            temporal_rs::TimeZone::try_from_utf16(ident)
+                .map(|x| Box::new(TimeZone(x)))
+                .map_err(Into::into)
+        }
 
         pub fn identifier(&self, write: &mut DiplomatWrite) {
-            // TODO ideally this would use Writeable instead of allocating
-            let s = self.0.identifier();
-
+            use writeable::Writeable;
             // This can only fail in cases where the DiplomatWriteable is capped, we
             // don't care about that.
-            let _ = write.write_str(&s);
+            let _ = self.0.write_to(write);
         }
 
         pub fn utc() -> Box<Self> {
@@ -69,4 +80,68 @@ pub mod ffi {
             true
         }
     }
+
+    pub enum WallClockShiftKind {
+        Gap,
+        Overlap,
+    }
+
+    /// A time zone transition with the offsets in effect before and after it.
+    #[diplomat::opaque]
+    pub struct TimeZoneTransition(pub temporal_rs::TimeZoneTransition);
+
+    impl TimeZoneTransition {
+        pub fn instant(&self) -> Box<Instant> {
+            Box::new(Instant(self.0.instant))
+        }
+
+        pub fn offset_before_nanoseconds(&self) -> i64 {
+            self.0.offset_before.nanoseconds()
+        }
+
+        pub fn offset_after_nanoseconds(&self) -> i64 {
+            self.0.offset_after.nanoseconds()
+        }
+
+        pub fn is_dst_before(&self) -> bool {
+            self.0.is_dst_before
+        }
+
+        pub fn is_dst_after(&self) -> bool {
+            self.0.is_dst_after
+        }
+
+        /// Writes the abbreviation before the transition, writes nothing if unavailable.
+        pub fn abbreviation_before(&self, write: &mut DiplomatWrite) {
+            if let Some(abbreviation) = self.0.abbreviation_before {
+                // This can only fail in cases where the DiplomatWriteable is capped, we
+                // don't care about that.
+                let _ = write.write_str(&abbreviation);
+            }
+        }
+
+        /// Writes the abbreviation after the transition, writes nothing if unavailable.
+        pub fn abbreviation_after(&self, write: &mut DiplomatWrite) {
+            if let Some(abbreviation) = self.0.abbreviation_after {
+                // This can only fail in cases where the DiplomatWriteable is capped, we
+                // don't care about that.
+                let _ = write.write_str(&abbreviation);
+            }
+        }
+
+        pub fn shift_kind(&self) -> WallClockShiftKind {
+            match self.0.shift {
+                temporal_rs::WallClockShift::Gap(_) => WallClockShiftKind::Gap,
+                temporal_rs::WallClockShift::Overlap(_) => WallClockShiftKind::Overlap,
+            }
+        }
+
+        /// The duration of the gap or overlap in wall-clock time.
+        pub fn shift_duration(&self) -> Box<Duration> {
+            match self.0.shift {
+                temporal_rs::WallClockShift::Gap(duration)
+                | temporal_rs::WallClockShift::Overlap(duration) => Box::new(Duration(duration)),
+            }
+        }
+    }
 }
@@ -25,9 +25,8 @@ pub mod ffi {
         TransitionDirection,
     };
 
-    use crate::time_zone::ffi::TimeZone;
+    use crate::time_zone::ffi::{TimeZone, TimeZoneTransition};
 
-    use alloc::string::String;
     use core::fmt::Write;
 
     use diplomat_runtime::DiplomatOption;
@@ -71,9 +70,7 @@ pub mod ffi {
         }
 
         pub fn from_utf16(s: &DiplomatStr16) -> Result<Self, TemporalError> {
-            // TODO(#275) This should not need to convert
-            let s = String::from_utf16(s).map_err(|_| temporal_rs::TemporalError::range())?;
-            super::RelativeTo::try_from_str(&s)
+            super::RelativeTo::try_from_utf16(s)
                 .map(Into::into)
                 .map_err(Into::<TemporalError>::into)
         }
@@ -96,10 +93,7 @@ pub mod ffi {
                 .map_err(Into::<TemporalError>::into)
         }
         pub fn from_utf16(s: &DiplomatStr16) -> Result<Box<Self>, TemporalError> {
-            // TODO(#275) This should not need to convert
-            let s = String::from_utf16(s).map_err(|_| temporal_rs::TemporalError::range())?;
-
-            temporal_rs::parsed_intermediates::ParsedZonedDateTime::from_utf8(s.as_bytes())
+            temporal_rs::parsed_intermediates::ParsedZonedDateTime::from_utf16(s)
                 .map(|x| Box::new(ParsedZonedDateTime(x)))
                 .map_err(Into::<TemporalError>::into)
         }
@@ -173,10 +167,8 @@ pub mod ffi {
             disambiguation: Disambiguation,
             offset_disambiguation: OffsetDisambiguation,
         ) -> Result<Box<Self>, TemporalError> {
-            // TODO(#275) This should not need to convert
-            let s = String::from_utf16(s).map_err(|_| temporal_rs::TemporalError::range())?;
-            temporal_rs::ZonedDateTime::from_utf8(
-                s.as_bytes(),
+            temporal_rs::ZonedDateTime::from_utf16(
+                s,
                 disambiguation.into(),
                 offset_disambiguation.into(),
             )
@@ -242,9 +234,9 @@ pub mod ffi {
         }
 
         pub fn offset(&self, write: &mut DiplomatWrite) -> Result<(), TemporalError> {
-            let string = self.0.offset();
+            use writeable::Writeable;
             // throw away the error, this should always succeed
-            let _ = write.write_str(&string);
+            let _ = self.0.offset_writeable().write_to(write);
             Ok(())
         }
 
@@ -265,6 +257,16 @@ pub mod ffi {
                 .map_err(Into::into)
         }
 
+        pub fn get_time_zone_transition_info(
+            &self,
+            direction: TransitionDirection,
+        ) -> Result<Option<Box<TimeZoneTransition>>, TemporalError> {
+            self.0
+                .get_time_zone_transition_info(direction.into())
+                .map(|x| x.map(|y| Box::new(TimeZoneTransition(y))))
+                .map_err(Into::into)
+        }
+
         pub fn hours_in_day(&self) -> Result<u8, TemporalError> {
             self.0.hours_in_day().map_err(Into::into)
         }
@@ -299,15 +301,15 @@ pub mod ffi {
 
             write: &mut DiplomatWrite,
         ) -> Result<(), TemporalError> {
-            // TODO this double-allocates, an API returning a Writeable or impl Write would be better
-            let string = self.0.to_ixdtf_string(
+            use writeable::Writeable;
+            let writeable = self.0.to_ixdtf_writeable(
                 display_offset.into(),
                 display_timezone.into(),
                 display_calendar.into(),
                 options.into(),
             )?;
             // throw away the error, this should always succeed
-            let _ = write.write_str(&string);
+            let _ = writeable.write_to(write);
             Ok(())
         }
 
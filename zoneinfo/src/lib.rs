@@ -39,7 +39,7 @@ use alloc::string::String;
 use parser::ZoneInfoParseError;
 use utils::epoch_seconds_for_year;
 
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 
 #[cfg(feature = "std")]
 extern crate std;
@@ -107,6 +107,8 @@ pub struct ZoneInfoData {
     pub zones: HashMap<String, ZoneRecord>,
     /// Data parsed from Link lines
     pub links: HashMap<String, String>,
+    /// Link names that were parsed from the `backward` file
+    backward_links: HashSet<String>,
     /// Data parsed from `#PACKRATLIST` lines
     pub pack_rat: HashMap<String, String>,
 }
@@ -123,7 +125,10 @@ impl ZoneInfoData {
         let mut zoneinfo = Self::default();
         for filename in ZONEINFO_FILES {
             let file_path = dir.as_ref().join(filename);
-            let parsed = Self::from_filepath(file_path)?;
+            let mut parsed = Self::from_filepath(file_path)?;
+            if filename == "backward" {
+                parsed.backward_links = parsed.links.keys().cloned().collect();
+            }
             zoneinfo.extend(parsed);
         }
         Ok(zoneinfo)
@@ -144,11 +149,17 @@ impl ZoneInfoData {
             .map_err(ZoneInfoError::Parse)
     }
 
+    /// Returns the link names that were parsed from the `backward` file.
+    pub fn backward_links(&self) -> &HashSet<String> {
+        &self.backward_links
+    }
+
     /// Extend the current `ZoneInfoCompiler` data from another `ZoneInfoCompiler`.
     pub fn extend(&mut self, other: Self) {
         self.rules.extend(other.rules);
         self.zones.extend(other.zones);
         self.links.extend(other.links);
+        self.backward_links.extend(other.backward_links);
         self.pack_rat.extend(other.pack_rat);
     }
 }